@@ -0,0 +1,65 @@
+//! Build script: bundles committed project images
+//!
+//! The browser cannot check for files at runtime, so the lists are fixed at
+//! build time as `asset!()` tables in `$OUT_DIR`:
+//!
+//! - `local_screenshots.rs`: every `assets/screenshots/<project-id>.webp`,
+//!   included by `utils::screenshots`
+//! - `project_image_variants.rs`: every `assets/projects/<project-id>-<width>.<avif|webp>`
+//!   written by the `image-pipeline` binary, included by `utils::responsive_images`
+
+use std::{env, fs, path::Path};
+
+const SCREENSHOT_DIR: &str = "assets/screenshots";
+const VARIANT_DIR: &str = "assets/projects";
+
+fn main() {
+    println!("cargo:rerun-if-changed={SCREENSHOT_DIR}");
+    println!("cargo:rerun-if-changed={VARIANT_DIR}");
+
+    let screenshots: String = file_names(SCREENSHOT_DIR)
+        .iter()
+        .filter_map(|name| name.strip_suffix(".webp"))
+        .filter(|id| is_project_id(id))
+        .map(|id| format!("    ({id:?}, asset!(\"/{SCREENSHOT_DIR}/{id}.webp\")),\n"))
+        .collect();
+    write_table("local_screenshots.rs", &screenshots);
+
+    let variants: String = file_names(VARIANT_DIR)
+        .iter()
+        .filter_map(|name| {
+            let (stem, format) = name.rsplit_once('.')?;
+            let (id, width) = stem.rsplit_once('-')?;
+            let width: u32 = width.parse().ok()?;
+            (matches!(format, "avif" | "webp") && is_project_id(id)).then(|| {
+                format!("    ({id:?}, {width}, {format:?}, asset!(\"/{VARIANT_DIR}/{name}\")),\n")
+            })
+        })
+        .collect();
+    write_table("project_image_variants.rs", &variants);
+}
+
+/// Sorted file names in `dir` (empty if it doesn't exist)
+fn file_names(dir: &str) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect();
+    names.sort();
+    names
+}
+
+/// Project ids are slugs; anything else would break the generated path literal
+fn is_project_id(id: &str) -> bool {
+    !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn write_table(file: &str, entries: &str) {
+    let out = Path::new(&env::var("OUT_DIR").expect("OUT_DIR is set by cargo")).join(file);
+    fs::write(out, format!("&[\n{entries}]\n")).expect("write generated asset table");
+}
@@ -0,0 +1,129 @@
+//! github-sync - Refresh the embedded GitHub snapshot
+//!
+//! Fetches every tracked repo, project `github_url` and GitHub repo tagged
+//! `portfolio` from its forge's REST API and writes `.cache/github_repos.json`,
+//! which the WASM build embeds. Each run also appends today's star, fork and
+//! open issue counts to `.cache/github_history.json`.
+//!
+//! Usage:
+//!   cargo run --bin github-sync -- [--base-url <url>] [--output <path>] [--history <path>]
+//!
+//! Set `GITHUB_TOKEN` to raise the API rate limit. Repos that fail to fetch keep
+//! their previous snapshot entry; the exit code is non-zero if any failed.
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::{path::PathBuf, process::ExitCode};
+
+#[cfg(not(target_arch = "wasm32"))]
+use enerby_dev::{
+    components::projects::PORTFOLIO_TOPIC,
+    utils::{
+        github_api::GitHubApiConfig,
+        github_cache::{CACHE_FILE_PATH, GitHubCache},
+        github_sync::{
+            GitHubClient, add_targets, discover_targets, portfolio_owner, sync_repos, sync_targets,
+        },
+        repo_history::{HISTORY_FILE_PATH, RepoHistory},
+    },
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() -> ExitCode {
+    run().unwrap_or_else(|err| {
+        eprintln!("❌ {}", err);
+        ExitCode::FAILURE
+    })
+}
+
+// The sync client is native-only; nothing to run in the browser
+#[cfg(target_arch = "wasm32")]
+fn main() {}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn run() -> Result<ExitCode, String> {
+    let mut config = GitHubApiConfig {
+        token: std::env::var("GITHUB_TOKEN").ok().filter(|t| !t.is_empty()),
+        ..GitHubApiConfig::default()
+    };
+    let mut output = PathBuf::from(CACHE_FILE_PATH);
+    let mut history_path = PathBuf::from(HISTORY_FILE_PATH);
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--base-url" => config.base_url = args.next().ok_or("--base-url needs a value")?,
+            "--output" => output = args.next().ok_or("--output needs a value")?.into(),
+            "--history" => history_path = args.next().ok_or("--history needs a value")?.into(),
+            "-h" | "--help" => {
+                println!(
+                    "Usage: github-sync [--base-url <url>] [--output <path>] [--history <path>]"
+                );
+                return Ok(ExitCode::SUCCESS);
+            }
+            other => return Err(format!("unknown argument: {}", other)),
+        }
+    }
+
+    let client = GitHubClient::new(config);
+    let owner = portfolio_owner();
+    let (targets, discovery_failed) = match discover_targets(&client, owner) {
+        Ok(discovered) => (add_targets(sync_targets(), discovered), false),
+        Err(err) => {
+            eprintln!(
+                "⚠️  {} topic search for {}: {}",
+                PORTFOLIO_TOPIC, owner, err
+            );
+            (sync_targets(), true)
+        }
+    };
+    println!(
+        "🔄 Syncing {} repos from {}",
+        targets.len(),
+        client.config().base_url
+    );
+
+    let report = sync_repos(&client, &targets);
+    for (repo, err) in &report.failures {
+        eprintln!("⚠️  {}: {}", repo, err);
+    }
+
+    // Only repos fetched this run get a new data point
+    let mut history = match std::fs::read_to_string(&history_path) {
+        Ok(json) => RepoHistory::from_json(&json)
+            .map_err(|e| format!("{}: {}", history_path.display(), e))?,
+        Err(_) => RepoHistory::default(),
+    };
+    let today = chrono::Utc::now().format("%Y-%m-%d").to_string();
+    let recorded = history.record_all(&today, report.cache.repos());
+
+    // Keep the previous snapshot for repos that failed this run
+    let mut snapshot = GitHubCache::load_from_file(&output).unwrap_or_default();
+    let fetched = report.cache.len();
+    snapshot.merge(report.cache);
+
+    snapshot
+        .save_to_file(&output)
+        .map_err(|e| format!("failed to write {}: {}", output.display(), e))?;
+    println!(
+        "✅ {} fetched, {} failed -> {}",
+        fetched,
+        report.failures.len(),
+        output.display()
+    );
+
+    let json = history.to_json().map_err(|e| e.to_string())?;
+    std::fs::write(&history_path, json)
+        .map_err(|e| format!("failed to write {}: {}", history_path.display(), e))?;
+    println!(
+        "📈 {} history points for {} -> {}",
+        recorded,
+        today,
+        history_path.display()
+    );
+
+    Ok(if report.failures.is_empty() && !discovery_failed {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
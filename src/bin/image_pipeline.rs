@@ -0,0 +1,161 @@
+//! image-pipeline - Build responsive project images
+//!
+//! Resolves every project's `ImageSource` the same way the cards do, then
+//! writes resized AVIF/WebP variants to `assets/projects/` and their sizes and
+//! blurred placeholders to `.cache/project_images.json`. Both are committed
+//! and embedded by the WASM build.
+//!
+//! Usage:
+//!   cargo run --bin image-pipeline -- [fetch] [--force]
+//!
+//! Without `fetch` it runs fully offline against `.cache/images/` and the
+//! committed screenshots. `fetch` first downloads remote sources that are
+//! missing or whose URL changed (`--force` downloads all of them again).
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::{path::PathBuf, process::ExitCode, time::Duration};
+
+#[cfg(not(target_arch = "wasm32"))]
+use enerby_dev::{
+    components::projects::get_projects_enriched,
+    utils::{
+        get_project_image_url,
+        image_pipeline::{
+            SOURCE_CACHE_DIR, SourceLocation, clear_variants, fetch_source, find_cached_source,
+            process_image, source_location,
+        },
+        responsive_images::{ImageManifest, MANIFEST_FILE_PATH, VARIANT_DIR},
+    },
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() -> ExitCode {
+    run().unwrap_or_else(|err| {
+        eprintln!("❌ {}", err);
+        ExitCode::FAILURE
+    })
+}
+
+// Image encoding is native-only; nothing to run in the browser
+#[cfg(target_arch = "wasm32")]
+fn main() {}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn run() -> Result<ExitCode, String> {
+    let mut fetch = false;
+    let mut force = false;
+
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "fetch" => fetch = true,
+            "--force" => force = true,
+            "-h" | "--help" => {
+                println!("Usage: image-pipeline [fetch] [--force]");
+                return Ok(ExitCode::SUCCESS);
+            }
+            other => return Err(format!("unknown argument: {}", other)),
+        }
+    }
+
+    let root = PathBuf::from(".");
+    let cache_dir = PathBuf::from(SOURCE_CACHE_DIR);
+    let out_dir = PathBuf::from(VARIANT_DIR);
+    let manifest_path = PathBuf::from(MANIFEST_FILE_PATH);
+
+    let previous = std::fs::read_to_string(&manifest_path)
+        .ok()
+        .and_then(|json| ImageManifest::from_json(&json).ok())
+        .unwrap_or_default();
+
+    let agent = ureq::AgentBuilder::new()
+        .timeout(Duration::from_secs(30))
+        .user_agent("enerby-dev-image-pipeline")
+        .build();
+
+    let mut sources: Vec<(String, String, PathBuf)> = Vec::new();
+    let mut failed = 0;
+
+    for project in get_projects_enriched() {
+        let image_source = get_project_image_url(&project);
+        let Some(location) = source_location(&project.id, &image_source, &root) else {
+            continue;
+        };
+        let source = image_source.to_string();
+
+        let path = match location {
+            SourceLocation::Local(path) => Some(path),
+            SourceLocation::Remote(url) => {
+                let cached = find_cached_source(&cache_dir, &project.id);
+                let changed = previous
+                    .images
+                    .get(&project.id)
+                    .is_some_and(|image| image.source != url);
+                if fetch && (force || changed || cached.is_none()) {
+                    match fetch_source(&agent, &url, &cache_dir, &project.id) {
+                        Ok(path) => {
+                            println!("⬇️  {}: {}", project.id, url);
+                            Some(path)
+                        }
+                        Err(err) => {
+                            eprintln!("⚠️  {}: {}: {}", project.id, url, err);
+                            failed += 1;
+                            cached
+                        }
+                    }
+                } else {
+                    cached
+                }
+            }
+        };
+
+        match path {
+            Some(path) if path.is_file() => sources.push((project.id.clone(), source, path)),
+            _ => eprintln!(
+                "⚠️  {}: no cached source image (run `image-pipeline fetch`)",
+                project.id
+            ),
+        }
+    }
+
+    let removed = clear_variants(&out_dir)?;
+    let mut manifest = ImageManifest {
+        generated_at: chrono::Utc::now().format("%Y-%m-%d").to_string(),
+        ..Default::default()
+    };
+
+    for (id, source, path) in &sources {
+        let entry = image::open(path)
+            .map_err(|e| e.to_string())
+            .and_then(|image| process_image(id, source, &image, &out_dir));
+        match entry {
+            Ok(entry) => {
+                println!(
+                    "🖼️  {}: {}×{} at {:?}px",
+                    id, entry.width, entry.height, entry.widths
+                );
+                manifest.images.insert(id.clone(), entry);
+            }
+            Err(err) => {
+                eprintln!("⚠️  {}: {}: {}", id, path.display(), err);
+                failed += 1;
+            }
+        }
+    }
+
+    let json = manifest.to_json().map_err(|e| e.to_string())?;
+    std::fs::write(&manifest_path, json)
+        .map_err(|e| format!("{}: {}", manifest_path.display(), e))?;
+
+    println!(
+        "✅ {} projects processed ({} old variants removed, {} failed) -> {}",
+        manifest.images.len(),
+        removed,
+        failed,
+        out_dir.display()
+    );
+    Ok(if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
@@ -0,0 +1,115 @@
+//! loc-stats - Count lines of code across tracked repositories
+//!
+//! Reads checkout paths from `loc_stats.toml`, counts code/comment/blank lines
+//! per language for every repo in `TRACKED_REPOS`, and writes
+//! `.cache/loc_stats.json`, which the WASM build embeds.
+//!
+//! Usage:
+//!   cargo run --bin loc-stats -- [--config <path>] [--output <path>]
+//!
+//! Repos without a usable checkout keep their entry from the previous snapshot.
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::{fs, path::PathBuf, process::ExitCode};
+
+#[cfg(not(target_arch = "wasm32"))]
+use enerby_dev::utils::{
+    github_stats::{LocSnapshot, STATS_FILE_PATH, TRACKED_REPOS},
+    loc_counter::{CONFIG_FILE_PATH, LocConfig, count_repo},
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() -> ExitCode {
+    run().unwrap_or_else(|err| {
+        eprintln!("❌ {}", err);
+        ExitCode::FAILURE
+    })
+}
+
+// The counter walks the filesystem; nothing to run in the browser
+#[cfg(target_arch = "wasm32")]
+fn main() {}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn run() -> Result<ExitCode, String> {
+    let mut config_path = PathBuf::from(CONFIG_FILE_PATH);
+    let mut output = PathBuf::from(STATS_FILE_PATH);
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--config" => config_path = args.next().ok_or("--config needs a value")?.into(),
+            "--output" => output = args.next().ok_or("--output needs a value")?.into(),
+            "-h" | "--help" => {
+                println!("Usage: loc-stats [--config <path>] [--output <path>]");
+                return Ok(ExitCode::SUCCESS);
+            }
+            other => return Err(format!("unknown argument: {}", other)),
+        }
+    }
+
+    let config = LocConfig::load(&config_path)?;
+    for repo in &config.repos {
+        if !TRACKED_REPOS
+            .iter()
+            .any(|t| t.eq_ignore_ascii_case(&repo.name))
+        {
+            eprintln!("⚠️  {} is not in TRACKED_REPOS, ignoring", repo.name);
+        }
+    }
+
+    let previous = fs::read_to_string(&output)
+        .ok()
+        .and_then(|json| LocSnapshot::from_json(&json).ok())
+        .unwrap_or_default();
+
+    let mut snapshot = LocSnapshot {
+        generated_at: chrono::Utc::now().format("%Y-%m-%d").to_string(),
+        repos: Vec::new(),
+    };
+    let mut skipped = 0;
+
+    for name in TRACKED_REPOS {
+        let counted = match config.checkout(name) {
+            Some(checkout) if checkout.path.is_dir() => {
+                count_repo(name, &checkout.path, &checkout.exclude)
+                    .map_err(|e| format!("{}: {}", checkout.path.display(), e))?
+            }
+            _ => {
+                skipped += 1;
+                match previous
+                    .repos
+                    .iter()
+                    .find(|r| r.repo.eq_ignore_ascii_case(name))
+                {
+                    Some(kept) => {
+                        eprintln!("⚠️  {}: no checkout, keeping previous counts", name);
+                        kept.clone()
+                    }
+                    None => {
+                        eprintln!("⚠️  {}: no checkout, skipping", name);
+                        continue;
+                    }
+                }
+            }
+        };
+
+        println!("📊 {}: {} lines of code", name, counted.totals().code);
+        snapshot.repos.push(counted);
+    }
+
+    let json = serde_json::to_string_pretty(&snapshot).map_err(|e| e.to_string())?;
+    if let Some(parent) = output.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    fs::write(&output, json + "\n").map_err(|e| format!("{}: {}", output.display(), e))?;
+
+    println!(
+        "✅ {} lines of code in {} repos ({} without checkout) -> {}",
+        snapshot.total_code(),
+        snapshot.repos.len(),
+        skipped,
+        output.display()
+    );
+    Ok(ExitCode::SUCCESS)
+}
@@ -2,9 +2,11 @@
 //! Biography, timeline, and interests
 
 use crate::components::layout_components::{Container, Section};
-use crate::components::molecules::{Card, SectionTitle};
-use crate::components::projects::get_projects;
-use crate::utils::{format_loc, get_github_stats};
+use crate::components::molecules::{Card, HighlightCard, ProgressBar, SectionTitle};
+use crate::components::projects::get_projects_enriched;
+use crate::i18n::I18N_CONFIG;
+use crate::i18n::format::format_integer;
+use crate::utils::{format_loc, get_github_stats, language_shares};
 use dioxus::prelude::*;
 
 /// About Section - Biography and experience
@@ -43,6 +45,8 @@ pub fn AboutSection() -> Element {
                             // Dynamic Lines of Code counter (F10)
                             DynamicLocCounter {}
                         }
+
+                        LanguageBreakdown {}
                     }
 
                     // Right: Timeline (P7-B)
@@ -98,32 +102,20 @@ pub fn TimelineItem(year: String, title: String, company: String, description: S
     }
 }
 
-/// Highlight Card for stats
-#[component]
-fn HighlightCard(number: String, label: String) -> Element {
-    rsx! {
-        Card {
-            div { class: "text-center py-2",
-                p { class: "text-3xl font-bold text-primary font-display", "{number}" }
-                p { class: "text-xs text-muted uppercase tracking-wider mt-1", "{label}" }
-            }
-        }
-    }
-}
-
 /// Dynamic Lines of Code Counter (F10)
 /// Displays real LOC from github_stats module
 #[component]
 fn DynamicLocCounter() -> Element {
     let stats = get_github_stats();
-    let loc_formatted = format_loc(stats.total_loc);
+    let lang = I18N_CONFIG.read().language;
+    let loc_formatted = format_loc(stats.total_loc, &lang);
     let i18n = crate::i18n::use_i18n();
 
     rsx! {
         Card {
             div {
                 class: "text-center py-2",
-                title: "Lines of code across {stats.repos.len()} repositories",
+                title: "{i18n.about.stat_loc_tooltip_start}{stats.repos.len()}{i18n.about.stat_loc_tooltip_end}",
                 p { class: "text-3xl font-bold text-primary font-display", "{loc_formatted}" }
                 p { class: "text-xs text-muted uppercase tracking-wider mt-1", "{i18n.about.stat_loc}" }
             }
@@ -131,19 +123,52 @@ fn DynamicLocCounter() -> Element {
     }
 }
 
+/// Number of languages shown before folding the rest into "Other"
+const LANGUAGE_BREAKDOWN_LIMIT: usize = 5;
+
+/// Lines of code per language across tracked repos
+#[component]
+pub fn LanguageBreakdown() -> Element {
+    let stats = get_github_stats();
+    let i18n = crate::i18n::use_i18n();
+    let shares = language_shares(&stats.languages, LANGUAGE_BREAKDOWN_LIMIT);
+
+    if shares.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div { class: "mt-8",
+            h3 { class: "text-xl font-bold text-white mb-4 flex items-center gap-2",
+                span { class: "text-primary", "▸" }
+                "{i18n.about.languages_title}"
+            }
+            for (i, (language, percentage)) in shares.into_iter().enumerate() {
+                ProgressBar {
+                    key: "{i}",
+                    label: language.unwrap_or(i18n.about.languages_other).to_string(),
+                    percentage,
+                    color: ["cyan", "pink", "purple"][i % 3].to_string()
+                }
+            }
+        }
+    }
+}
+
 /// Dynamic Living Projects Counter
-/// Counts projects dynamically from projects.rs
+/// Counts projects dynamically from projects.rs (including discovered repos)
 #[component]
 fn DynamicProjectsCounter() -> Element {
-    let projects = get_projects();
-    let count = projects.len();
+    let projects = get_projects_enriched();
+    let lang = I18N_CONFIG.read().language;
+    let count = format_integer(projects.len() as u64, &lang);
     let i18n = crate::i18n::use_i18n();
 
     rsx! {
         Card {
             div {
                 class: "text-center py-2",
-                title: "Active repositories on GitHub",
+                title: "{i18n.about.stat_projects_tooltip}",
                 p { class: "text-3xl font-bold text-primary font-display", "{count}" }
                 p { class: "text-xs text-muted uppercase tracking-wider mt-1", "{i18n.about.stat_projects}" }
             }
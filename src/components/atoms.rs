@@ -22,6 +22,7 @@ pub fn Button(
     to: Option<Route>,
     href: Option<String>,
     #[props(default = false)] new_tab: bool,
+    #[props(default = false)] disabled: bool,
     onclick: Option<EventHandler<MouseEvent>>,
 ) -> Element {
     let base_class = "btn transition-all duration-300 font-bold uppercase tracking-wider text-sm py-3 px-6 rounded-sm relative overflow-hidden group";
@@ -64,7 +65,8 @@ pub fn Button(
     } else {
         rsx! {
             button {
-                class: "{full_class}",
+                class: "{full_class} disabled:opacity-50 disabled:cursor-wait",
+                disabled,
                 onclick: move |evt| if let Some(handler) = onclick { handler.call(evt) },
                 {children}
 
@@ -4,12 +4,16 @@
 use crate::components::atoms::{Badge, Button, ButtonVariant};
 use crate::components::layout_components::{Container, Grid, Section};
 use crate::components::molecules::{Card, SectionTitle};
+use crate::domain::{BlogPost, LocalizedText, PostStatus, Project};
 use crate::routes::Route;
 use dioxus::prelude::*;
 
 use crate::utils::markdown_loader::{load_markdown_posts, PostLanguage, consolidate_posts_by_canonical_slug, get_processed_post_by_slug, ProcessedMarkdownPost};
-use crate::i18n::Language;
-use std::collections::HashMap;
+use crate::i18n::format::format_read_time;
+use crate::utils::slugify;
+use chrono::NaiveDate;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
 
 pub mod post_header;
 pub mod post_content;
@@ -19,59 +23,6 @@ pub use post_header::*;
 pub use post_content::*;
 pub use post_navigator::*;
 
-/// Post Status (P10-A4)
-#[derive(Clone, PartialEq, Copy, Debug)]
-pub enum PostStatus {
-    Published,
-    Draft,
-}
-
-/// Blog Post data structure (P10-A1, P10-A2)
-/// Updated for i18n (F18-D) and slug normalization
-#[derive(Clone, PartialEq, Debug)]
-pub struct BlogPost {
-    pub slug: String,  // Now uses canonical_slug from _en.md frontmatter
-    
-    // Internal localized fields
-    title_en: String,
-    title_es: String,
-    
-    excerpt_en: String,
-    excerpt_es: String,
-    
-    content_en: String,
-    content_es: String,
-    
-    pub date: String,
-    pub read_time: u8,
-    pub tags: Vec<String>,
-    pub status: PostStatus,
-    pub featured: bool,
-}
-
-impl BlogPost {
-    pub fn title(&self, lang: &Language) -> &str {
-        match lang {
-            Language::EN => &self.title_en,
-            Language::ES => &self.title_es,
-        }
-    }
-    
-    pub fn excerpt(&self, lang: &Language) -> &str {
-        match lang {
-            Language::EN => &self.excerpt_en,
-            Language::ES => &self.excerpt_es,
-        }
-    }
-    
-    pub fn content(&self, lang: &Language) -> &str {
-        match lang {
-            Language::EN => &self.content_en,
-            Language::ES => &self.content_es,
-        }
-    }
-}
-
 /// Get all blog posts - Dynamic from Markdown (F8)
 /// Updated to use canonical slug normalization
 pub fn get_blog_posts() -> Vec<BlogPost> {
@@ -100,15 +51,17 @@ pub fn get_blog_posts() -> Vec<BlogPost> {
                     (base.frontmatter.title.clone(), base.frontmatter.excerpt.clone(), base.content_html.clone())
                 };
 
+                let Ok(date) = NaiveDate::parse_from_str(&base.frontmatter.date, "%Y-%m-%d") else {
+                    eprintln!("Warning: Invalid date '{}' for slug: {}", base.frontmatter.date, canonical_slug);
+                    return None;
+                };
+
                 Some(BlogPost {
                     slug: canonical_slug, // Use canonical slug from _en.md
-                    title_en: base.frontmatter.title.clone(),
-                    title_es,
-                    excerpt_en: base.frontmatter.excerpt.clone(),
-                    excerpt_es,
-                    content_en: base.content_html.clone(),
-                    content_es,
-                    date: base.frontmatter.date.clone(),
+                    title: LocalizedText::new(base.frontmatter.title.clone(), title_es),
+                    excerpt: LocalizedText::new(base.frontmatter.excerpt.clone(), excerpt_es),
+                    content: LocalizedText::new(base.content_html.clone(), content_es),
+                    date,
                     read_time: base.read_time_minutes,
                     tags,
                     status: PostStatus::Published,
@@ -122,8 +75,8 @@ pub fn get_blog_posts() -> Vec<BlogPost> {
         })
         .collect();
         
-    // Sort by date
-    posts.sort_by(|a, b| b.date.cmp(&a.date));
+    // Sort by date (newest first)
+    posts.sort_by_key(|p| Reverse(p.date));
     
     posts
 }
@@ -141,6 +94,27 @@ pub fn get_post_by_slug(slug: &str) -> Option<BlogPost> {
     get_blog_posts().into_iter().find(|p| p.slug == slug)
 }
 
+/// Posts tagged with a project's technologies or id, most matching tags first
+/// (newest first on ties), at most `limit`
+pub fn related_posts(project: &Project, posts: &[BlogPost], limit: usize) -> Vec<BlogPost> {
+    let keys: HashSet<String> = project
+        .technologies
+        .iter()
+        .chain(std::iter::once(&project.id))
+        .map(|key| slugify(key))
+        .collect();
+
+    let mut related: Vec<(usize, &BlogPost)> = posts
+        .iter()
+        .filter(|post| post.status == PostStatus::Published)
+        .map(|post| (post.tags.iter().filter(|tag| keys.contains(&slugify(tag))).count(), post))
+        .filter(|(matches, _)| *matches > 0)
+        .collect();
+    related.sort_by_key(|(matches, post)| Reverse((*matches, post.date)));
+
+    related.into_iter().take(limit).map(|(_, post)| post.clone()).collect()
+}
+
 use crate::i18n::I18N_CONFIG;
 
 /// Blog Section for Home Page (P10-C1)
@@ -148,13 +122,14 @@ use crate::i18n::I18N_CONFIG;
 pub fn BlogSection() -> Element {
     let posts = get_published_posts();
     let recent_posts: Vec<_> = posts.into_iter().filter(|p| p.featured).take(3).collect();
+    let i18n = crate::i18n::use_i18n();
 
     rsx! {
         Section { id: "blog",
             Container {
                 SectionTitle {
-                    text: "Latest Posts".to_string(),
-                    subtitle: "From the Blog".to_string(),
+                    text: i18n.blog.section_title.to_string(),
+                    subtitle: i18n.blog.section_subtitle.to_string(),
                     center: true
                 }
 
@@ -170,7 +145,7 @@ pub fn BlogSection() -> Element {
                     Button {
                         variant: ButtonVariant::Ghost,
                         to: Route::BlogPage {},
-                        "View All Posts →"
+                        "{i18n.blog.view_all}"
                     }
                 }
             }
@@ -183,6 +158,7 @@ pub fn BlogSection() -> Element {
 pub fn BlogPostPreview(post: BlogPost) -> Element {
     let slug = post.slug.to_string();
     let lang = I18N_CONFIG.read().language;
+    let read_time = format_read_time(post.read_time as u32, &lang);
 
     rsx! {
         Link {
@@ -193,17 +169,17 @@ pub fn BlogPostPreview(post: BlogPost) -> Element {
                 // Date and read time
                 div { class: "flex items-center justify-between text-xs text-muted mb-3",
                     span { "{post.date}" }
-                    span { "{post.read_time} min read" }
+                    span { "{read_time}" }
                 }
 
                 // Title
                 h3 { class: "text-lg font-bold text-white mb-2 group-hover:text-primary transition-colors line-clamp-2",
-                    "{post.title(&lang)}"
+                    "{post.title.get(&lang)}"
                 }
 
                 // Excerpt
                 p { class: "text-muted text-sm mb-4 line-clamp-3 flex-grow",
-                    "{post.excerpt(&lang)}"
+                    "{post.excerpt.get(&lang)}"
                 }
 
                 // Tags (P10-C4)
@@ -221,13 +197,14 @@ pub fn BlogPostPreview(post: BlogPost) -> Element {
 #[component]
 pub fn BlogListSection() -> Element {
     let posts = get_published_posts();
+    let i18n = crate::i18n::use_i18n();
 
     rsx! {
         Section { id: "blog-list",
             Container {
                 SectionTitle {
-                    text: "All Posts".to_string(),
-                    subtitle: "Blog Archive".to_string()
+                    text: i18n.blog.page_title.to_string(),
+                    subtitle: i18n.blog.page_subtitle.to_string()
                 }
 
                 div { class: "space-y-6",
@@ -244,6 +221,7 @@ pub fn BlogListSection() -> Element {
 #[component]
 fn BlogPostRow(post: BlogPost) -> Element {
     let lang = I18N_CONFIG.read().language;
+    let i18n = crate::i18n::use_i18n();
     rsx! {
         Card { hover_effect: true,
             div { class: "flex flex-col md:flex-row md:items-center gap-4",
@@ -255,14 +233,14 @@ fn BlogPostRow(post: BlogPost) -> Element {
                 // Content
                 div { class: "flex-grow",
                     h3 { class: "text-lg font-bold text-white mb-1 group-hover:text-primary transition-colors",
-                        "{post.title(&lang)}"
+                        "{post.title.get(&lang)}"
                     }
-                    p { class: "text-muted text-sm line-clamp-1", "{post.excerpt(&lang)}" }
+                    p { class: "text-muted text-sm line-clamp-1", "{post.excerpt.get(&lang)}" }
                 }
 
                 // Meta
                 div { class: "flex items-center gap-4 text-xs text-muted",
-                    span { "{post.read_time} min" }
+                    span { "{post.read_time} {i18n.blog.min_short}" }
                     div { class: "flex gap-1",
                         for tag in post.tags.iter().take(2) {
                             Badge { "{tag}" }
@@ -1,6 +1,6 @@
 use dioxus::prelude::*;
 use crate::i18n::Language;
-use crate::components::blog::BlogPost;
+use crate::domain::BlogPost;
 
 #[component]
 pub fn BlogPostContent(post: BlogPost, lang: Language) -> Element {
@@ -18,7 +18,7 @@ pub fn BlogPostContent(post: BlogPost, lang: Language) -> Element {
         
         // Prose Container - Transparent, no borders, pure content.
         article { class: "prose prose-invert max-w-none",
-            div { dangerous_inner_html: "{post.content(&lang)}" }
+            div { dangerous_inner_html: "{post.content.get(&lang)}" }
         }
     }
 }
@@ -1,9 +1,12 @@
 use dioxus::prelude::*;
 use crate::i18n::Language;
-use crate::components::blog::BlogPost;
+use crate::i18n::format::format_read_time;
+use crate::domain::BlogPost;
 
 #[component]
 pub fn BlogPostHeader(post: BlogPost, lang: Language) -> Element {
+    let read_time = format_read_time(post.read_time as u32, &lang);
+
     rsx! {
         header { class: "mb-16 text-center max-w-4xl mx-auto",
             // Tags - Subtle, minimalist
@@ -15,14 +18,14 @@ pub fn BlogPostHeader(post: BlogPost, lang: Language) -> Element {
 
             // Title - Large, bold, but clean (No Shadows)
             h1 { class: "text-4xl md:text-5xl font-bold text-white mb-6 font-display leading-tight tracking-tight",
-                "{post.title(&lang)}"
+                "{post.title.get(&lang)}"
             }
             
             // Meta Row - Minimalist
             div { class: "flex items-center justify-center gap-4 text-muted font-sans text-sm",
                 span { "{post.date}" }
                 span { class: "w-1 h-1 rounded-full bg-muted/50" }
-                span { "{post.read_time} min read" }
+                span { "{read_time}" }
             }
         }
     }
@@ -4,12 +4,14 @@ use crate::routes::Route;
 
 #[component]
 pub fn BlogPostNavigator() -> Element {
+    let i18n = crate::i18n::use_i18n();
+
     rsx! {
         nav { class: "mb-12 flex justify-start",
             Button {
                 variant: ButtonVariant::Ghost,
                 to: Route::BlogPage {},
-                "← Back to Blog"
+                "{i18n.blog.back_to_blog}"
             }
         }
     }
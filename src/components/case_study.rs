@@ -0,0 +1,88 @@
+//! Case Study Components
+//! Challenge → architecture → outcome write-up and metrics of a project
+
+use crate::components::molecules::HighlightCard;
+use crate::domain::{CaseStudy, CaseStudySection, Metric};
+use crate::i18n::I18N_CONFIG;
+use dioxus::prelude::*;
+
+/// Every section the case study has, in reading order: problem → approach →
+/// results, with the metrics closing the results
+#[component]
+pub fn CaseStudyView(case_study: CaseStudy) -> Element {
+    rsx! {
+        div { class: "space-y-10 mb-12",
+            if let Some(html) = case_study.challenge.clone() {
+                ChallengeSection { html }
+            }
+            if let Some(html) = case_study.architecture.clone() {
+                ArchitectureSection { html }
+            }
+            if let Some(html) = case_study.outcome.clone() {
+                OutcomeSection { html }
+            }
+            if !case_study.metrics.is_empty() {
+                MetricsSection { metrics: case_study.metrics.clone() }
+            }
+        }
+    }
+}
+
+/// The problem the project had to solve
+#[component]
+pub fn ChallengeSection(html: String) -> Element {
+    rsx! {
+        ProseSection { section: CaseStudySection::Challenge, icon: "🎯", accent: "border-secondary-pink", html }
+    }
+}
+
+/// How it was built
+#[component]
+pub fn ArchitectureSection(html: String) -> Element {
+    rsx! {
+        ProseSection { section: CaseStudySection::Architecture, icon: "🏗️", accent: "border-secondary-purple", html }
+    }
+}
+
+/// What came out of it
+#[component]
+pub fn OutcomeSection(html: String) -> Element {
+    rsx! {
+        ProseSection { section: CaseStudySection::Outcome, icon: "🚀", accent: "border-primary", html }
+    }
+}
+
+/// Headline numbers as highlight cards
+#[component]
+pub fn MetricsSection(metrics: Vec<Metric>) -> Element {
+    let lang = I18N_CONFIG.read().language;
+
+    rsx! {
+        section { "aria-labelledby": "case-metrics",
+            h2 { id: "case-metrics", class: "text-lg font-bold text-white mb-4",
+                "{CaseStudySection::Metrics.label(&lang)}"
+            }
+            div { class: "grid grid-cols-2 md:grid-cols-4 gap-4",
+                for metric in metrics {
+                    HighlightCard { number: metric.value, label: metric.label }
+                }
+            }
+        }
+    }
+}
+
+/// Heading plus rendered markdown, with a colored rule per section
+#[component]
+fn ProseSection(section: CaseStudySection, icon: String, accent: String, html: String) -> Element {
+    let lang = I18N_CONFIG.read().language;
+
+    rsx! {
+        section { class: "border-l-2 {accent} pl-6",
+            h2 { class: "text-xl font-bold text-white mb-3",
+                span { class: "mr-2", "aria-hidden": "true", "{icon}" }
+                "{section.label(&lang)}"
+            }
+            div { class: "prose prose-invert max-w-none text-muted", dangerous_inner_html: "{html}" }
+        }
+    }
+}
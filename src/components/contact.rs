@@ -1,10 +1,22 @@
 //! Contact Section Component
 //! Contact form with validation and alternative contact info
+//! (delivery goes through `utils::contact`)
 
 use crate::components::atoms::{Badge, Button, ButtonVariant};
 use crate::components::layout_components::{Container, Grid, Section};
 use crate::components::molecules::{Card, SectionTitle};
+use crate::config::CONTACT_PROOF_OF_WORK;
+use crate::data::{ContactField, ContactForm as ContactFormData};
+use crate::i18n::I18N_CONFIG;
+use crate::i18n::dict::ContactConfig;
+use crate::utils::clock::default_clock;
+use crate::utils::contact::{ContactError, Delivery, configured_transport};
+use crate::utils::spam::{SpamError, SpamGuard, mint_proof};
+use crate::utils::validation::{
+    ValidationError, max_length, validate_contact_form, validate_field,
+};
 use dioxus::prelude::*;
+use std::collections::{HashMap, HashSet};
 
 /// Form State (P11-C)
 #[derive(Clone, PartialEq, Debug)]
@@ -12,63 +24,65 @@ pub enum FormState {
     Idle,
     Loading,
     Success,
+    /// Handed to the visitor's mail client (`ContactService::Mailto`)
+    MailClientOpened,
     Error(String),
 }
 
-/// Contact Form Data
-#[derive(Clone, Default)]
-struct ContactFormData {
-    name: String,
-    email: String,
-    subject: String,
-    message: String,
+impl FormState {
+    /// State after a delivery attempt; errors carry the localized message
+    pub fn from_result(
+        result: Result<Delivery, ContactError>,
+        i18n: &crate::i18n::dict::ContactConfig,
+    ) -> Self {
+        match result {
+            Ok(Delivery::Sent) => FormState::Success,
+            Ok(Delivery::MailClient) => FormState::MailClientOpened,
+            Err(e) => FormState::Error(e.message(i18n).to_string()),
+        }
+    }
 }
 
 /// Validation Errors
 #[derive(Clone, Default)]
 struct ValidationErrors {
-    name: Option<String>,
-    email: Option<String>,
-    subject: Option<String>,
-    message: Option<String>,
+    fields: HashMap<ContactField, ValidationError>,
+    /// Fields left at least once (or all, after a submit); they validate live
+    touched: HashSet<ContactField>,
+    /// Only checked once the fields are valid
+    spam: Option<SpamError>,
 }
 
 impl ValidationErrors {
     fn has_errors(&self) -> bool {
-        self.name.is_some()
-            || self.email.is_some()
-            || self.subject.is_some()
-            || self.message.is_some()
+        !self.fields.is_empty() || self.spam.is_some()
     }
-}
-
-/// Validate email format (P11-B2)
-fn is_valid_email(email: &str) -> bool {
-    email.contains('@') && email.contains('.') && email.len() > 5
-}
-
-/// Validate form (P11-B1, P11-B2, P11-B3)
-fn validate_form(data: &ContactFormData) -> ValidationErrors {
-    let mut errors = ValidationErrors::default();
 
-    if data.name.trim().is_empty() {
-        errors.name = Some("Name is required".to_string());
+    fn field(&self, field: ContactField) -> Option<ValidationError> {
+        self.fields.get(&field).copied()
     }
 
-    if data.email.trim().is_empty() {
-        errors.email = Some("Email is required".to_string());
-    } else if !is_valid_email(&data.email) {
-        errors.email = Some("Please enter a valid email".to_string());
+    /// Re-checks `field` with its current value and starts validating it live
+    fn touch(&mut self, field: ContactField, value: &str) {
+        self.touched.insert(field);
+        match validate_field(field, value) {
+            Ok(()) => self.fields.remove(&field),
+            Err(e) => self.fields.insert(field, e),
+        };
     }
+}
 
-    if data.subject.trim().is_empty() {
-        errors.subject = Some("Subject is required".to_string());
-    }
+/// Validate form (P11-B1, P11-B2, P11-B3), then run the spam checks
+/// Rules live in `utils::validation`; messages come from the active dictionary
+fn validate_form(data: &ContactFormData, guard: &SpamGuard) -> ValidationErrors {
+    let mut errors = ValidationErrors {
+        fields: validate_contact_form(data).into_iter().collect(),
+        touched: ContactField::ALL.into_iter().collect(),
+        spam: None,
+    };
 
-    if data.message.trim().is_empty() {
-        errors.message = Some("Message is required".to_string());
-    } else if data.message.len() < 20 {
-        errors.message = Some("Message must be at least 20 characters".to_string());
+    if !errors.has_errors() {
+        errors.spam = guard.check(data).err();
     }
 
     errors
@@ -77,12 +91,14 @@ fn validate_form(data: &ContactFormData) -> ValidationErrors {
 /// Contact Section (P11-A1)
 #[component]
 pub fn ContactSection() -> Element {
+    let i18n = crate::i18n::use_i18n();
+
     rsx! {
         Section { id: "contact", alternate: true,
             Container {
                 SectionTitle {
-                    text: "Get In Touch".to_string(),
-                    subtitle: "Let's Work Together".to_string(),
+                    text: i18n.contact.title.to_string(),
+                    subtitle: i18n.contact.subtitle.to_string(),
                     center: true
                 }
 
@@ -109,26 +125,52 @@ fn ContactForm() -> Element {
     let mut form_data = use_signal(ContactFormData::default);
     let mut errors = use_signal(ValidationErrors::default);
     let mut form_state = use_signal(|| FormState::Idle);
+    // When the form was (re)opened, for the minimum fill time
+    let mut opened_at = use_signal(|| default_clock().now_seconds());
+    let i18n = crate::i18n::use_i18n();
+    let lang = I18N_CONFIG.read().language;
 
     let on_submit = move |evt: FormEvent| {
         evt.prevent_default();
+        if form_state() == FormState::Loading {
+            return;
+        }
 
         // Validate
-        let validation_errors = validate_form(&form_data());
+        let guard = SpamGuard::new(opened_at());
+        let validation_errors = validate_form(&form_data(), &guard);
         errors.set(validation_errors.clone());
 
+        // Bots filling the honeypot see a success they can't learn from
+        if validation_errors.spam == Some(SpamError::Honeypot) {
+            form_state.set(FormState::Success);
+            return;
+        }
         if validation_errors.has_errors() {
             return;
         }
 
-        // Simulate submission (P11-D1 placeholder)
+        // Deliver through the configured transport (P11-D1)
         form_state.set(FormState::Loading);
-
-        // In a real app, this would be an async call
-        // For now, simulate success after "submission"
+        let mut form = form_data().trimmed();
         spawn(async move {
-            // Simulated delay would go here
-            form_state.set(FormState::Success);
+            if let Some(bits) = CONTACT_PROOF_OF_WORK {
+                form.proof = Some(mint_proof(&form.email, bits).await);
+            }
+
+            let transport = configured_transport();
+            #[cfg(target_arch = "wasm32")]
+            let result = crate::utils::contact::send(transport, &form).await;
+            #[cfg(not(target_arch = "wasm32"))]
+            let result = crate::utils::contact::send_blocking(transport, &form);
+
+            // Only messages an endpoint accepted count against the rate limit;
+            // failures and `mailto:` hand-offs sent nothing
+            if result == Ok(Delivery::Sent) {
+                guard.record_submission();
+            }
+            let i18n = crate::i18n::use_i18n();
+            form_state.set(FormState::from_result(result, &i18n.contact));
         });
     };
 
@@ -137,6 +179,7 @@ fn ContactForm() -> Element {
         form_data.set(ContactFormData::default());
         errors.set(ValidationErrors::default());
         form_state.set(FormState::Idle);
+        opened_at.set(default_clock().now_seconds());
     };
 
     rsx! {
@@ -145,24 +188,36 @@ fn ContactForm() -> Element {
                 FormState::Success => rsx! {
                     div { class: "text-center py-8",
                         div { class: "text-5xl mb-4", "✅" }
-                        h3 { class: "text-xl font-bold text-primary mb-2", "Message Sent!" }
-                        p { class: "text-muted mb-4", "Thank you for reaching out. I'll get back to you soon." }
+                        h3 { class: "text-xl font-bold text-primary mb-2", "{i18n.contact.success_title}" }
+                        p { class: "text-muted mb-4", "{i18n.contact.success_message}" }
                         Button {
                             variant: ButtonVariant::Ghost,
                             onclick: reset_form,
-                            "Send Another Message"
+                            "{i18n.contact.btn_send_another}"
+                        }
+                    }
+                },
+                FormState::MailClientOpened => rsx! {
+                    div { class: "text-center py-8",
+                        div { class: "text-5xl mb-4", "📨" }
+                        h3 { class: "text-xl font-bold text-primary mb-2", "{i18n.contact.mailto_title}" }
+                        p { class: "text-muted mb-4", "{i18n.contact.mailto_message}" }
+                        Button {
+                            variant: ButtonVariant::Ghost,
+                            onclick: reset_form,
+                            "{i18n.contact.btn_send_another}"
                         }
                     }
                 },
                 FormState::Error(ref msg) => rsx! {
                     div { class: "text-center py-8",
                         div { class: "text-5xl mb-4", "❌" }
-                        h3 { class: "text-xl font-bold text-red-400 mb-2", "Something went wrong" }
+                        h3 { class: "text-xl font-bold text-red-400 mb-2", "{i18n.contact.error_title}" }
                         p { class: "text-muted mb-4", "{msg}" }
                         Button {
                             variant: ButtonVariant::Ghost,
                             onclick: reset_form,
-                            "Try Again"
+                            "{i18n.contact.btn_try_again}"
                         }
                     }
                 },
@@ -171,68 +226,59 @@ fn ContactForm() -> Element {
                         onsubmit: on_submit,
                         class: "space-y-4",
 
-                        // Name Field (P11-A3)
-                        FormField {
-                            label: "Name".to_string(),
-                            field_type: "text".to_string(),
-                            placeholder: "Your Name".to_string(),
-                            value: form_data().name,
-                            error: errors().name,
-                            oninput: move |evt: FormEvent| {
-                                form_data.write().name = evt.value();
-                            }
-                        }
-
-                        // Email Field (P11-A4)
-                        FormField {
-                            label: "Email".to_string(),
-                            field_type: "email".to_string(),
-                            placeholder: "your@email.com".to_string(),
-                            value: form_data().email,
-                            error: errors().email,
-                            oninput: move |evt: FormEvent| {
-                                form_data.write().email = evt.value();
-                            }
-                        }
-
-                        // Subject Field (P11-A5)
-                        FormField {
-                            label: "Subject".to_string(),
-                            field_type: "text".to_string(),
-                            placeholder: "What's this about?".to_string(),
-                            value: form_data().subject,
-                            error: errors().subject,
-                            oninput: move |evt: FormEvent| {
-                                form_data.write().subject = evt.value();
+                        // Name, Email, Subject and Message Fields (P11-A3 - P11-A6)
+                        for field in ContactField::ALL {
+                            FormField {
+                                key: "{field.id()}",
+                                field,
+                                label: field_label(field, &i18n.contact).to_string(),
+                                placeholder: field_placeholder(field, &i18n.contact).to_string(),
+                                value: form_data().value(field).to_string(),
+                                error: errors().field(field).map(|e| e.message(field, &lang)),
+                                oninput: move |evt: FormEvent| {
+                                    *form_data.write().value_mut(field) = evt.value();
+                                    if errors.peek().touched.contains(&field) {
+                                        errors.write().touch(field, &evt.value());
+                                    }
+                                },
+                                onblur: move |_| {
+                                    let value = form_data.peek().value(field).to_string();
+                                    errors.write().touch(field, &value);
+                                }
                             }
                         }
 
-                        // Message Field (P11-A6)
-                        div { class: "space-y-1",
-                            label { class: "text-sm font-medium text-secondary", "Message" }
-                            textarea {
-                                class: "w-full px-4 py-3 bg-bg-element border border-white/10 rounded-lg text-white placeholder-muted focus:border-primary focus:ring-1 focus:ring-primary outline-none transition-all resize-none",
-                                placeholder: "Your message (min 20 characters)...",
-                                rows: "5",
-                                value: "{form_data().message}",
+                        // Honeypot: off-screen and skipped by keyboard and screen readers
+                        div {
+                            class: "absolute -left-[9999px] w-px h-px overflow-hidden",
+                            aria_hidden: "true",
+                            label { r#for: "contact-website", "Website" }
+                            input {
+                                id: "contact-website",
+                                r#type: "text",
+                                name: "website",
+                                tabindex: "-1",
+                                autocomplete: "off",
+                                value: "{form_data().website}",
                                 oninput: move |evt: FormEvent| {
-                                    form_data.write().message = evt.value();
+                                    form_data.write().website = evt.value();
                                 }
                             }
-                            if let Some(error) = errors().message {
-                                p { class: "text-xs text-red-400", "{error}" }
-                            }
                         }
 
                         // Submit Button (P11-A7)
                         div { class: "pt-4",
+                            if let Some(message) = errors().spam.and_then(|s| s.message(&i18n.errors)) {
+                                p { class: "text-sm text-red-400 mb-3", role: "alert", "{message}" }
+                            }
                             Button {
                                 variant: ButtonVariant::Neon,
                                 class: "w-full".to_string(),
+                                disabled: matches!(form_state(), FormState::Loading),
                                 if matches!(form_state(), FormState::Loading) {
-                                    "Sending..."
+                                    "{i18n.contact.btn_sending}"
                                 } else {
-                                    "Send Message"
+                                    "{i18n.contact.btn_send}"
                                 }
                             }
                         }
@@ -243,34 +289,86 @@ fn ContactForm() -> Element {
     }
 }
 
+fn field_label(field: ContactField, i18n: &ContactConfig) -> &'static str {
+    match field {
+        ContactField::Name => i18n.label_name,
+        ContactField::Email => i18n.label_email,
+        ContactField::Subject => i18n.label_subject,
+        ContactField::Message => i18n.label_message,
+    }
+}
+
+fn field_placeholder(field: ContactField, i18n: &ContactConfig) -> &'static str {
+    match field {
+        ContactField::Name => i18n.placeholder_name,
+        ContactField::Email => i18n.placeholder_email,
+        ContactField::Subject => i18n.placeholder_subject,
+        ContactField::Message => i18n.placeholder_message,
+    }
+}
+
 /// Form Field Component
+/// Input (textarea for the message) with its label and error wired up for
+/// assistive tech through `aria-invalid` and `aria-describedby`
 #[component]
 fn FormField(
+    field: ContactField,
     label: String,
-    field_type: String,
     placeholder: String,
     value: String,
     error: Option<String>,
     oninput: EventHandler<FormEvent>,
+    onblur: EventHandler<FocusEvent>,
 ) -> Element {
+    let id = field.id();
+    let error_id = format!("{}-error", id);
+    let described_by = error.is_some().then(|| error_id.clone());
+    let invalid = if error.is_some() { "true" } else { "false" };
+    let maxlength = max_length(field);
     let border_class = if error.is_some() {
         "border-red-400"
     } else {
         "border-white/10"
     };
+    let class = format!(
+        "w-full px-4 py-3 bg-bg-element border {} rounded-lg text-white placeholder-muted focus:border-primary focus:ring-1 focus:ring-primary outline-none transition-all",
+        border_class
+    );
 
     rsx! {
         div { class: "space-y-1",
-            label { class: "text-sm font-medium text-secondary", "{label}" }
-            input {
-                r#type: "{field_type}",
-                class: "w-full px-4 py-3 bg-bg-element border {border_class} rounded-lg text-white placeholder-muted focus:border-primary focus:ring-1 focus:ring-primary outline-none transition-all",
-                placeholder: "{placeholder}",
-                value: "{value}",
-                oninput: move |evt| oninput.call(evt)
+            label { r#for: "{id}", class: "text-sm font-medium text-secondary", "{label}" }
+            if field == ContactField::Message {
+                textarea {
+                    id: "{id}",
+                    name: "{id}",
+                    class: "{class} resize-none",
+                    placeholder: "{placeholder}",
+                    rows: "5",
+                    maxlength: "{maxlength}",
+                    aria_invalid: "{invalid}",
+                    aria_describedby: described_by,
+                    value: "{value}",
+                    oninput: move |evt| oninput.call(evt),
+                    onblur: move |evt| onblur.call(evt)
+                }
+            } else {
+                input {
+                    id: "{id}",
+                    name: "{id}",
+                    r#type: if field == ContactField::Email { "email" } else { "text" },
+                    class: "{class}",
+                    placeholder: "{placeholder}",
+                    maxlength: "{maxlength}",
+                    aria_invalid: "{invalid}",
+                    aria_describedby: described_by,
+                    value: "{value}",
+                    oninput: move |evt| oninput.call(evt),
+                    onblur: move |evt| onblur.call(evt)
+                }
             }
             if let Some(err) = error {
-                p { class: "text-xs text-red-400", "{err}" }
+                p { id: "{error_id}", class: "text-xs text-red-400", "{err}" }
             }
         }
     }
@@ -279,16 +377,18 @@ fn FormField(
 /// Contact Info Card (P11-E1, P11-E3)
 #[component]
 fn ContactInfoCard() -> Element {
+    let i18n = crate::i18n::use_i18n();
+
     rsx! {
         Card {
-            h3 { class: "text-lg font-bold text-white mb-4", "Contact Info" }
+            h3 { class: "text-lg font-bold text-white mb-4", "{i18n.contact.info_title}" }
 
             div { class: "space-y-4",
                 // Email
                 div { class: "flex items-center gap-3",
                     span { class: "text-2xl", "📧" }
                     div {
-                        p { class: "text-xs text-muted", "Email" }
+                        p { class: "text-xs text-muted", "{i18n.contact.info_email}" }
                         a {
                             class: "text-primary hover:underline",
                             href: "mailto:hello@enerby.dev",
@@ -301,8 +401,8 @@ fn ContactInfoCard() -> Element {
                 div { class: "flex items-center gap-3",
                     span { class: "text-2xl", "📍" }
                     div {
-                        p { class: "text-xs text-muted", "Location" }
-                        p { class: "text-secondary", "Mexico 🇲🇽" }
+                        p { class: "text-xs text-muted", "{i18n.contact.info_location}" }
+                        p { class: "text-secondary", "{i18n.contact.location_value}" }
                     }
                 }
 
@@ -310,8 +410,8 @@ fn ContactInfoCard() -> Element {
                 div { class: "flex items-center gap-3",
                     span { class: "text-2xl", "⏰" }
                     div {
-                        p { class: "text-xs text-muted", "Availability" }
-                        Badge { color: "cyan".to_string(), "Open for Projects" }
+                        p { class: "text-xs text-muted", "{i18n.contact.info_availability}" }
+                        Badge { color: "cyan".to_string(), "{i18n.contact.availability_value}" }
                     }
                 }
             }
@@ -327,10 +427,11 @@ fn SocialLinks() -> Element {
         ("LinkedIn", "https://linkedin.com/in/enerbydev", "💼"),
         ("Twitter", "https://twitter.com/enerbydev", "🐦"),
     ];
+    let i18n = crate::i18n::use_i18n();
 
     rsx! {
         Card {
-            h3 { class: "text-lg font-bold text-white mb-4", "Connect" }
+            h3 { class: "text-lg font-bold text-white mb-4", "{i18n.contact.connect_title}" }
 
             div { class: "flex gap-4",
                 for (name, url, emoji) in socials.iter() {
@@ -347,3 +448,80 @@ fn SocialLinks() -> Element {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_form_flags_every_field() {
+        let data = ContactFormData {
+            email: "nope".to_string(),
+            message: "short".to_string(),
+            ..Default::default()
+        };
+
+        let errors = validate_form(&data, &SpamGuard::new(0));
+        assert_eq!(
+            errors.field(ContactField::Name),
+            Some(ValidationError::Required)
+        );
+        assert_eq!(
+            errors.field(ContactField::Email),
+            Some(ValidationError::InvalidEmail)
+        );
+        assert_eq!(
+            errors.field(ContactField::Subject),
+            Some(ValidationError::Required)
+        );
+        assert!(matches!(
+            errors.field(ContactField::Message),
+            Some(ValidationError::TooShort { .. })
+        ));
+        assert_eq!(errors.touched.len(), ContactField::ALL.len());
+        assert_eq!(errors.spam, None);
+    }
+
+    #[test]
+    fn test_touch_revalidates_one_field() {
+        let mut errors = ValidationErrors::default();
+
+        errors.touch(ContactField::Email, "ada@");
+        assert_eq!(
+            errors.field(ContactField::Email),
+            Some(ValidationError::InvalidEmail)
+        );
+        assert_eq!(errors.field(ContactField::Name), None);
+        assert!(!errors.touched.contains(&ContactField::Name));
+
+        errors.touch(ContactField::Email, " ada@example.com ");
+        assert_eq!(errors.field(ContactField::Email), None);
+        assert!(!errors.has_errors());
+    }
+
+    #[test]
+    fn test_validate_form_runs_spam_checks_on_valid_forms() {
+        let data = ContactFormData {
+            name: "Ada".to_string(),
+            email: "ada@example.com".to_string(),
+            subject: "Hello".to_string(),
+            message: "A message long enough to send".to_string(),
+            ..Default::default()
+        };
+        let now = default_clock().now_seconds();
+
+        let errors = validate_form(&data, &SpamGuard::new(0));
+        assert!(!errors.has_errors());
+
+        // Opened just now
+        let errors = validate_form(&data, &SpamGuard::new(now));
+        assert_eq!(errors.spam, Some(SpamError::TooFast));
+
+        let bot = ContactFormData {
+            website: "https://spam.example".to_string(),
+            ..data
+        };
+        let errors = validate_form(&bot, &SpamGuard::new(0));
+        assert_eq!(errors.spam, Some(SpamError::Honeypot));
+    }
+}
@@ -4,6 +4,7 @@
 use crate::components::atoms::{Button, ButtonVariant};
 use crate::components::layout_components::Container;
 use crate::components::molecules::GlitchText;
+use crate::components::project_filters::ProjectQuery;
 use crate::config::SITE;
 use crate::routes::Route;
 use dioxus::prelude::*;
@@ -14,27 +15,6 @@ use crate::i18n::use_i18n;
 #[component]
 pub fn HeroSection() -> Element {
     let i18n = use_i18n();
-    
-    // Static role (typing animation handled by CSS) - now localized via i18n
-    // Note: If you want roles to cycle, we might need an array in i18n
-    // For now, using a static string from i18n or just keeping it 'Full-Stack Developer' if universal?
-    // Let's assume description_highlight or a new field serves as role? 
-    // Wait, HeroConfig doesn't have 'role'. It has 'role_prefix'?
-    // "role_prefix": "> " ?? 
-    // Let's check dict.rs again. `pub role_prefix: &'static str`
-    // I will use `i18n.hero.role` if I add it, or hardcode it if it's universal "Full Stack Developer".
-    // Actually, "Full-Stack Developer" should be translated. "Desarrollador Full-Stack".
-    // I will assume it is part of `greeting`?? No.
-    // I'll add `role` to `HeroConfig` implies modifying `i18n` crates. This is outside scope of E?
-    // "Update UI Components". If I find missing keys, I should add them.
-    // But let's look at what I have: `description_highlight` might be "Rust".
-    
-    // Let's stick to what's in `HeroConfig`:
-    // greeting, role_prefix, description_start, description_highlight, description_end, cta_projects, cta_contact, scroll_indicator.
-    // Missing: The actual "Full-Stack Developer" text. 
-    // I will verify `src/i18n/locales/en.rs` content to see what's mapped to what.
-    
-    let current_role = "Full-Stack Developer"; // Fallback if not in dict
 
     rsx! {
         section {
@@ -77,7 +57,7 @@ pub fn HeroSection() -> Element {
                         div { class: "h-8 mb-6",
                             p { class: "text-xl md:text-2xl text-secondary font-mono",
                                 span { class: "text-primary", "{i18n.hero.role_prefix}" }
-                                span { class: "animate-pulse", "{current_role}" }
+                                span { class: "animate-pulse", "{i18n.hero.role}" }
                                 span { class: "animate-blink text-primary", "_" }
                             }
                         }
@@ -93,7 +73,7 @@ pub fn HeroSection() -> Element {
                         div { class: "flex gap-4 justify-center lg:justify-start flex-wrap",
                             Button {
                                 variant: ButtonVariant::Neon,
-                                to: Route::ProjectsPage {},
+                                to: Route::ProjectsPage { query: ProjectQuery::default() },
                                 "{i18n.hero.cta_projects}"
                             }
                             Button {
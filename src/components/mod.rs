@@ -5,13 +5,17 @@ pub mod about;
 pub mod animations;
 pub mod atoms;
 pub mod blog;
+pub mod case_study;
 pub mod contact;
 pub mod hero;
 pub mod layout_components;
 pub mod molecules;
+pub mod project_filters;
 pub mod projects;
+pub mod radar;
 pub mod seo;
 pub mod skills;
+pub mod structured_data;
 
 // Re-export specific components for easier access if needed
 // pub use atoms::*;
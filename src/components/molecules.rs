@@ -1,7 +1,13 @@
 //! Molecular Components
 //! Complex UI elements built from atoms
 
+use crate::i18n::format::{format_integer, format_percent};
+use crate::theme::colors;
+use crate::theme::languages::{OTHER, language_color};
+use crate::utils::github_api::language_breakdown;
+use crate::utils::repo_history::sparkline_points;
 use dioxus::prelude::*;
+use std::collections::BTreeMap;
 
 /// Card Component with Glassmorphism and Hover Glue
 #[component]
@@ -33,6 +39,20 @@ pub fn Card(
     }
 }
 
+/// Highlight Card for stats
+/// A big number over a short label (About stats, case study metrics)
+#[component]
+pub fn HighlightCard(number: String, label: String) -> Element {
+    rsx! {
+        Card {
+            div { class: "text-center py-2",
+                p { class: "text-3xl font-bold text-primary font-display", "{number}" }
+                p { class: "text-xs text-muted uppercase tracking-wider mt-1", "{label}" }
+            }
+        }
+    }
+}
+
 /// Section Title with Glitch/Neon Effect
 #[component]
 pub fn SectionTitle(
@@ -105,6 +125,8 @@ pub fn ProgressBar(
     #[props(default = "cyan".to_string())] color: String,
 ) -> Element {
     let width = format!("{}%", percentage);
+    let lang = crate::i18n::I18N_CONFIG.read().language;
+    let percentage_label = format_percent(percentage as f64, &lang);
     let color_class = match color.as_str() {
         "pink" => "bg-secondary-pink shadow-[0_0_10px_rgba(255,0,255,0.5)]",
         "purple" => "bg-secondary-purple shadow-[0_0_10px_rgba(157,0,255,0.5)]",
@@ -115,7 +137,7 @@ pub fn ProgressBar(
         div { class: "w-full mb-4",
             div { class: "flex justify-between mb-1",
                 span { class: "text-sm font-medium text-white", "{label}" }
-                span { class: "text-sm font-medium text-muted", "{percentage}%" }
+                span { class: "text-sm font-medium text-muted", "{percentage_label}" }
             }
             div { class: "w-full bg-white/5 rounded-full h-2.5 overflow-hidden border border-white/5",
                 div {
@@ -127,6 +149,123 @@ pub fn ProgressBar(
     }
 }
 
+/// Language Bar (Repository)
+/// GitHub-style stacked bar of code bytes per language, with a legend
+#[component]
+pub fn LanguageBar(
+    languages: BTreeMap<String, u64>,
+    #[props(default = 5)] limit: usize,
+    #[props(default = true)] legend: bool,
+) -> Element {
+    let lang = crate::i18n::I18N_CONFIG.read().language;
+    let i18n = crate::i18n::use_i18n();
+
+    let segments: Vec<(String, &'static str, String, f64)> = language_breakdown(&languages, limit)
+        .into_iter()
+        .map(|(language, percent)| {
+            let (name, color) = match language {
+                Some(name) => (name.to_string(), language_color(name)),
+                None => (i18n.projects.languages_other.to_string(), OTHER),
+            };
+            (name, color, format_percent(percent, &lang), percent)
+        })
+        .collect();
+
+    if segments.is_empty() {
+        return rsx! {};
+    }
+
+    let summary = segments
+        .iter()
+        .map(|(name, _, label, _)| format!("{} {}", name, label))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    rsx! {
+        div { class: "w-full",
+            div {
+                class: "flex w-full h-2 gap-0.5 rounded-full overflow-hidden bg-white/5",
+                role: "img",
+                aria_label: "{i18n.projects.languages_title}: {summary}",
+                for (name, color, label, percent) in segments.iter() {
+                    span {
+                        key: "{name}",
+                        class: "h-full",
+                        style: "width: {percent}%; background-color: {color}",
+                        title: "{name} {label}"
+                    }
+                }
+            }
+            if legend {
+                ul { class: "flex flex-wrap gap-x-4 gap-y-1 mt-2 text-xs",
+                    for (name, color, label, _) in segments.iter() {
+                        li { key: "{name}", class: "flex items-center gap-1.5",
+                            span {
+                                class: "w-2 h-2 rounded-full",
+                                style: "background-color: {color}"
+                            }
+                            span { class: "text-white", "{name}" }
+                            span { class: "text-muted", "{label}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Sparkline viewBox size; the SVG stretches to its container
+const SPARKLINE_WIDTH: u32 = 120;
+const SPARKLINE_HEIGHT: u32 = 32;
+
+/// Sparkline (Trend)
+/// Inline SVG line of a series over time, oldest value first, with the latest value
+#[component]
+pub fn Sparkline(
+    label: String,
+    values: Vec<u32>,
+    #[props(default = colors::PRIMARY.to_string())] color: String,
+) -> Element {
+    let lang = crate::i18n::I18N_CONFIG.read().language;
+    let points = sparkline_points(&values, SPARKLINE_WIDTH, SPARKLINE_HEIGHT);
+    let first = values.first().copied().unwrap_or(0);
+    let latest = values.last().copied().unwrap_or(0);
+
+    let first_label = format_integer(first as u64, &lang);
+    let latest_label = format_integer(latest as u64, &lang);
+    let change_label = if latest >= first {
+        format!("+{}", format_integer((latest - first) as u64, &lang))
+    } else {
+        format!("−{}", format_integer((first - latest) as u64, &lang))
+    };
+
+    rsx! {
+        div { class: "p-4 rounded-lg bg-bg-card border border-white/5",
+            div { class: "flex items-baseline justify-between mb-2",
+                span { class: "text-sm text-muted", "{label}" }
+                span { class: "text-lg font-bold text-white", "{latest_label}" }
+            }
+            svg {
+                class: "w-full h-8 overflow-visible",
+                view_box: "0 0 {SPARKLINE_WIDTH} {SPARKLINE_HEIGHT}",
+                preserve_aspect_ratio: "none",
+                role: "img",
+                "aria-label": "{label}: {first_label} → {latest_label}",
+                polyline {
+                    points: "{points}",
+                    fill: "none",
+                    stroke: "{color}",
+                    stroke_width: "2",
+                    stroke_linecap: "round",
+                    stroke_linejoin: "round",
+                    vector_effect: "non-scaling-stroke",
+                }
+            }
+            span { class: "text-xs text-muted", "{change_label}" }
+        }
+    }
+}
+
 /// Language Switcher Component
 /// Toggles between available languages
 #[component]
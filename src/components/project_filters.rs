@@ -0,0 +1,643 @@
+//! Project Filters
+//!
+//! Technology and status chips, sorting and a text filter for the projects
+//! page. The state lives in the `Route::ProjectsPage` query string, so a
+//! filtered view can be shared as a link:
+//!
+//! ```text
+//! /projects?tech=Rust,CLI&status=active&sort=stars&q=docs
+//! ```
+//!
+//! Chips are plain links to the toggled query; the sort select replaces the
+//! current history entry as it changes, the text field once it is committed
+//! (Enter or leaving the field) rather than on every keystroke.
+
+use std::fmt;
+use std::str::FromStr;
+
+use dioxus::prelude::*;
+
+use crate::components::skills::get_skills;
+use crate::domain::{Project, ProjectStatus};
+use crate::i18n::format::format_integer;
+use crate::i18n::{I18N_CONFIG, Language};
+use crate::routes::Route;
+use crate::utils::screenshots::{decode_query_value, encode_query_value};
+
+/// Technology chips shown before the rarer ones are left out
+pub const TECH_CHIP_LIMIT: usize = 12;
+
+/// True when a project technology is the selected one: the same spelling
+/// (ignoring case) or another name of the same skill ("Vue" and "Vue.js")
+fn same_technology(selected: &str, technology: &str) -> bool {
+    selected.eq_ignore_ascii_case(technology)
+        || get_skills()
+            .iter()
+            .any(|skill| skill.matches(selected) && skill.matches(technology))
+}
+
+// ============================================================================
+// QUERY STATE
+// ============================================================================
+
+/// Project ordering
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProjectSort {
+    /// Featured projects first, otherwise as listed
+    #[default]
+    Featured,
+    /// Most recently started first (unknown dates last)
+    Newest,
+    /// Most stars first
+    Stars,
+    /// Title, A to Z
+    Name,
+}
+
+impl ProjectSort {
+    pub const ALL: [Self; 4] = [Self::Featured, Self::Newest, Self::Stars, Self::Name];
+
+    /// Name used in URLs (`?sort=stars`)
+    pub fn slug(&self) -> &'static str {
+        match self {
+            Self::Featured => "featured",
+            Self::Newest => "newest",
+            Self::Stars => "stars",
+            Self::Name => "name",
+        }
+    }
+
+    pub fn label(&self, lang: &Language) -> &'static str {
+        let i18n = lang.dict().projects;
+        match self {
+            Self::Featured => i18n.sort_featured,
+            Self::Newest => i18n.sort_newest,
+            Self::Stars => i18n.sort_stars,
+            Self::Name => i18n.sort_name,
+        }
+    }
+}
+
+impl FromStr for ProjectSort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|sort| sort.slug().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| format!("unknown project sort: {}", s))
+    }
+}
+
+/// Filters and ordering of the projects page (`Route::ProjectsPage` query)
+///
+/// Unknown keys and invalid values are ignored, so old or hand-edited links
+/// still open the page.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProjectQuery {
+    /// Technologies a project must all use (case-insensitive, skill aliases
+    /// count as the same technology)
+    pub tech: Vec<String>,
+    pub status: Option<ProjectStatus>,
+    pub sort: ProjectSort,
+    /// Text to find in the title, description or technologies
+    pub q: String,
+}
+
+impl ProjectQuery {
+    /// True when nothing is filtered (the sort may still differ)
+    pub fn is_unfiltered(&self) -> bool {
+        self.tech.is_empty() && self.status.is_none() && self.q.trim().is_empty()
+    }
+
+    /// True when `tech` is one of the selected technologies
+    pub fn has_tech(&self, tech: &str) -> bool {
+        self.tech.iter().any(|t| t.eq_ignore_ascii_case(tech))
+    }
+
+    /// Same query with `tech` selected, or unselected if it already was
+    pub fn toggle_tech(&self, tech: &str) -> Self {
+        let mut query = self.clone();
+        if self.has_tech(tech) {
+            query.tech.retain(|t| !t.eq_ignore_ascii_case(tech));
+        } else {
+            query.tech.push(tech.to_string());
+        }
+        query
+    }
+
+    /// Same query with another status filter
+    pub fn with_status(&self, status: Option<ProjectStatus>) -> Self {
+        Self {
+            status,
+            ..self.clone()
+        }
+    }
+
+    /// Same query with another ordering
+    pub fn with_sort(&self, sort: ProjectSort) -> Self {
+        Self {
+            sort,
+            ..self.clone()
+        }
+    }
+
+    /// Same query with another text filter
+    pub fn with_text(&self, q: &str) -> Self {
+        Self {
+            q: q.to_string(),
+            ..self.clone()
+        }
+    }
+
+    /// Same ordering, no filters
+    pub fn cleared(&self) -> Self {
+        Self {
+            sort: self.sort,
+            ..Self::default()
+        }
+    }
+
+    /// True when a project passes every filter
+    pub fn matches(&self, project: &Project, lang: &Language) -> bool {
+        let uses = |tech: &String| {
+            project
+                .technologies
+                .iter()
+                .any(|t| same_technology(tech, t))
+        };
+        if !self.tech.iter().all(uses) {
+            return false;
+        }
+        if self.status.is_some_and(|status| status != project.status) {
+            return false;
+        }
+
+        let needle = self.q.trim().to_lowercase();
+        needle.is_empty()
+            || project.title.to_lowercase().contains(&needle)
+            || project
+                .description
+                .get(lang)
+                .to_lowercase()
+                .contains(&needle)
+            || project
+                .technologies
+                .iter()
+                .any(|t| t.to_lowercase().contains(&needle))
+    }
+
+    /// Matching projects in the selected order
+    pub fn apply(&self, projects: &[Project], lang: &Language) -> Vec<Project> {
+        let mut results: Vec<Project> = projects
+            .iter()
+            .filter(|p| self.matches(p, lang))
+            .cloned()
+            .collect();
+
+        // Stable sorts keep the listed order for ties
+        match self.sort {
+            ProjectSort::Featured => results.sort_by_key(|p| !p.featured),
+            ProjectSort::Newest => {
+                results.sort_by_key(|p| (p.started.is_none(), std::cmp::Reverse(p.started)))
+            }
+            ProjectSort::Stars => results.sort_by_key(|p| std::cmp::Reverse(p.stars)),
+            ProjectSort::Name => results.sort_by_key(|p| p.title.to_lowercase()),
+        }
+        results
+    }
+
+    /// Number of projects that match
+    pub fn count(&self, projects: &[Project], lang: &Language) -> usize {
+        projects.iter().filter(|p| self.matches(p, lang)).count()
+    }
+}
+
+impl From<&str> for ProjectQuery {
+    /// Parses a query string (`tech=Rust,CLI&status=active`) as handed over
+    /// by the router, decoding each value after splitting (see `Display`)
+    fn from(query: &str) -> Self {
+        let mut parsed = Self::default();
+        for (key, value) in query.split('&').filter_map(|pair| pair.split_once('=')) {
+            match key {
+                "tech" => {
+                    for tech in value.split(',').map(decode_query_value) {
+                        let tech = tech.trim();
+                        if !tech.is_empty() && !parsed.has_tech(tech) {
+                            parsed.tech.push(tech.to_string());
+                        }
+                    }
+                }
+                "status" => parsed.status = value.parse().ok(),
+                "sort" => parsed.sort = value.parse().unwrap_or_default(),
+                "q" => parsed.q = decode_query_value(value),
+                _ => {}
+            }
+        }
+        parsed
+    }
+}
+
+impl fmt::Display for ProjectQuery {
+    /// Query string without the `?`; default values are left out
+    ///
+    /// Values are percent-encoded with their `%` escaped once more: the router
+    /// writes this string as is and decodes the whole query once before
+    /// `From<&str>` splits it, so `&`, `=`, `,` and `#` in a value only survive
+    /// as `%26`, `%3D`, `%2C` and `%23` after that first decode.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let encode = |value: &str| encode_query_value(value).replace('%', "%25");
+
+        let mut pairs = Vec::new();
+        let techs: Vec<String> = self
+            .tech
+            .iter()
+            .filter(|t| !t.trim().is_empty())
+            .map(|t| encode(t))
+            .collect();
+        if !techs.is_empty() {
+            pairs.push(format!("tech={}", techs.join(",")));
+        }
+        if let Some(status) = self.status {
+            pairs.push(format!("status={}", status.slug()));
+        }
+        if self.sort != ProjectSort::default() {
+            pairs.push(format!("sort={}", self.sort.slug()));
+        }
+        if !self.q.trim().is_empty() {
+            pairs.push(format!("q={}", encode(&self.q)));
+        }
+        write!(f, "{}", pairs.join("&"))
+    }
+}
+
+/// Technologies across `projects` with the number of projects using each
+///
+/// Most used first, then by name; names keep the casing first seen.
+pub fn technology_counts(projects: &[Project]) -> Vec<(String, usize)> {
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for project in projects {
+        for tech in &project.technologies {
+            match counts
+                .iter_mut()
+                .find(|(t, _)| t.eq_ignore_ascii_case(tech))
+            {
+                Some((_, count)) => *count += 1,
+                None => counts.push((tech.clone(), 1)),
+            }
+        }
+    }
+    counts.sort_by(|(a, ca), (b, cb)| {
+        cb.cmp(ca)
+            .then_with(|| a.to_lowercase().cmp(&b.to_lowercase()))
+    });
+    counts
+}
+
+/// Technologies offered as chips: the most used ones plus any selected
+pub fn technology_chips(projects: &[Project], query: &ProjectQuery) -> Vec<String> {
+    let mut chips: Vec<String> = technology_counts(projects)
+        .into_iter()
+        .map(|(tech, _)| tech)
+        .take(TECH_CHIP_LIMIT)
+        .collect();
+    for tech in &query.tech {
+        if !chips.iter().any(|t| t.eq_ignore_ascii_case(tech)) {
+            chips.push(tech.clone());
+        }
+    }
+    chips
+}
+
+// ============================================================================
+// COMPONENTS
+// ============================================================================
+
+/// Filter chip linking to the toggled query
+#[component]
+fn FilterChip(label: String, count: usize, active: bool, to: ProjectQuery) -> Element {
+    let lang = I18N_CONFIG.read().language;
+    let class = if active {
+        "bg-primary/20 text-primary border-primary"
+    } else if count == 0 {
+        "text-muted/50 border-white/5 pointer-events-none"
+    } else {
+        "text-muted border-white/10 hover:border-primary/50 hover:text-white"
+    };
+
+    rsx! {
+        Link {
+            to: Route::ProjectsPage { query: to },
+            class: "inline-flex items-center gap-1.5 px-3 py-1 rounded-full border text-xs transition-colors {class}",
+            "{label}"
+            span { class: "font-mono opacity-70", "{format_integer(count as u64, &lang)}" }
+        }
+    }
+}
+
+/// Text filter, sort select and technology/status chips with live counts
+#[component]
+pub fn ProjectFilters(query: ProjectQuery, projects: Vec<Project>) -> Element {
+    let lang = I18N_CONFIG.read().language;
+    let i18n = crate::i18n::use_i18n();
+    let navigator = use_navigator();
+
+    let status_chips: Vec<(String, Option<ProjectStatus>)> =
+        std::iter::once((i18n.projects.filter_all.to_string(), None))
+            .chain(
+                ProjectStatus::ALL
+                    .into_iter()
+                    .map(|status| (status.label(&lang).to_string(), Some(status))),
+            )
+            .collect();
+    let tech_chips = technology_chips(&projects, &query);
+
+    let text_query = query.clone();
+    let sort_query = query.clone();
+
+    rsx! {
+        div { class: "mb-10 space-y-4",
+            // Text filter + sort
+            div { class: "flex flex-col md:flex-row gap-4",
+                label { class: "flex-1 flex flex-col gap-1",
+                    span { class: "text-xs uppercase tracking-wider text-muted", "{i18n.projects.filter_search}" }
+                    input {
+                        r#type: "search",
+                        class: "w-full px-4 py-2 bg-bg-element border border-white/10 rounded-lg text-white placeholder-muted focus:border-primary focus:ring-1 focus:ring-primary outline-none transition-all",
+                        placeholder: "{i18n.projects.filter_search_placeholder}",
+                        value: "{query.q}",
+                        onchange: move |evt: FormEvent| {
+                            navigator.replace(Route::ProjectsPage { query: text_query.with_text(&evt.value()) });
+                        }
+                    }
+                }
+                label { class: "flex flex-col gap-1",
+                    span { class: "text-xs uppercase tracking-wider text-muted", "{i18n.projects.sort_label}" }
+                    select {
+                        class: "px-4 py-2 bg-bg-element border border-white/10 rounded-lg text-white focus:border-primary outline-none",
+                        onchange: move |evt: FormEvent| {
+                            let sort = evt.value().parse().unwrap_or_default();
+                            navigator.replace(Route::ProjectsPage { query: sort_query.with_sort(sort) });
+                        },
+                        for sort in ProjectSort::ALL {
+                            option {
+                                value: sort.slug(),
+                                selected: sort == query.sort,
+                                "{sort.label(&lang)}"
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Status chips
+            div { class: "flex flex-wrap items-center gap-2", role: "group", "aria-label": "{i18n.projects.filter_status}",
+                span { class: "text-xs uppercase tracking-wider text-muted mr-1", "{i18n.projects.filter_status}" }
+                for (label, status) in status_chips {
+                    FilterChip {
+                        label,
+                        count: query.with_status(status).count(&projects, &lang),
+                        active: query.status == status,
+                        to: query.with_status(status),
+                    }
+                }
+            }
+
+            // Technology chips
+            div { class: "flex flex-wrap items-center gap-2", role: "group", "aria-label": "{i18n.projects.filter_technology}",
+                span { class: "text-xs uppercase tracking-wider text-muted mr-1", "{i18n.projects.filter_technology}" }
+                for tech in tech_chips {
+                    FilterChip {
+                        count: if query.has_tech(&tech) {
+                            query.count(&projects, &lang)
+                        } else {
+                            query.toggle_tech(&tech).count(&projects, &lang)
+                        },
+                        active: query.has_tech(&tech),
+                        to: query.toggle_tech(&tech),
+                        label: tech,
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::LocalizedText;
+
+    fn project(id: &str, technologies: &[&str], status: ProjectStatus) -> Project {
+        Project {
+            id: id.to_string(),
+            title: id.to_string(),
+            description: LocalizedText::new(format!("{} tool", id), format!("herramienta {}", id)),
+            technologies: technologies.iter().map(|t| t.to_string()).collect(),
+            status,
+            ..Default::default()
+        }
+    }
+
+    fn sample() -> Vec<Project> {
+        vec![
+            project("alpha", &["Rust", "CLI"], ProjectStatus::Active),
+            project("beta", &["Rust", "WASM"], ProjectStatus::Archived),
+            project("gamma", &["Vue.js"], ProjectStatus::Completed),
+            project("delta", &["rust", "cli"], ProjectStatus::Active),
+        ]
+    }
+
+    fn ids(projects: &[Project]) -> Vec<&str> {
+        projects.iter().map(|p| p.id.as_str()).collect()
+    }
+
+    // -------------------------
+    // Query String Tests
+    // -------------------------
+
+    #[test]
+    fn test_query_roundtrip() {
+        let query = ProjectQuery {
+            tech: vec!["Rust".to_string(), "Nuxt 4".to_string()],
+            status: Some(ProjectStatus::Archived),
+            sort: ProjectSort::Stars,
+            q: "docs".to_string(),
+        };
+        let route = Route::ProjectsPage {
+            query: query.clone(),
+        };
+
+        assert_eq!(
+            route.to_string(),
+            "/projects?tech=Rust,Nuxt%25204&status=archived&sort=stars&q=docs"
+        );
+        assert_eq!(route.to_string().parse::<Route>().unwrap(), route);
+    }
+
+    #[test]
+    fn test_default_query_is_empty() {
+        assert_eq!(ProjectQuery::default().to_string(), "");
+        assert_eq!(ProjectQuery::from(""), ProjectQuery::default());
+    }
+
+    #[test]
+    fn test_query_ignores_unknown_and_invalid_values() {
+        let query = ProjectQuery::from("status=planned&sort=random&page=2&tech=,Rust,rust&q");
+
+        assert_eq!(query.status, None);
+        assert_eq!(query.sort, ProjectSort::Featured);
+        assert_eq!(query.tech, vec!["Rust"]);
+        assert_eq!(query.q, "");
+    }
+
+    #[test]
+    fn test_query_keeps_separators_in_values() {
+        let query = ProjectQuery {
+            tech: vec!["C#".to_string(), "C,C++".to_string()],
+            q: "a&b=c#d 100%".to_string(),
+            ..Default::default()
+        };
+        let route = Route::ProjectsPage {
+            query: query.clone(),
+        };
+
+        assert_eq!(route.to_string().parse::<Route>().unwrap(), route);
+        assert!(!route.to_string().contains('#'));
+    }
+
+    #[test]
+    fn test_query_accepts_hand_written_links() {
+        assert_eq!(
+            "/projects?tech=Nuxt%204,C%23&q=a%20b"
+                .parse::<Route>()
+                .unwrap(),
+            Route::ProjectsPage {
+                query: ProjectQuery {
+                    tech: vec!["Nuxt 4".to_string(), "C#".to_string()],
+                    q: "a b".to_string(),
+                    ..Default::default()
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn test_route_carries_query() {
+        let query = ProjectQuery::from("tech=Rust&sort=name");
+        let route = Route::ProjectsPage {
+            query: query.clone(),
+        };
+
+        assert_eq!(route.to_string(), "/projects?tech=Rust&sort=name");
+        assert_eq!(
+            "/projects?tech=Rust&sort=name".parse::<Route>().unwrap(),
+            route
+        );
+        assert_eq!(
+            "/projects".parse::<Route>().unwrap(),
+            Route::ProjectsPage {
+                query: ProjectQuery::default()
+            }
+        );
+    }
+
+    // -------------------------
+    // Filter Tests
+    // -------------------------
+
+    #[test]
+    fn test_tech_filters_combine() {
+        let projects = sample();
+        let query = ProjectQuery::default()
+            .toggle_tech("rust")
+            .toggle_tech("CLI");
+
+        assert_eq!(
+            ids(&query.apply(&projects, &Language::EN)),
+            vec!["alpha", "delta"]
+        );
+
+        // Toggling again removes the filter
+        let query = query.toggle_tech("cli");
+        assert_eq!(query.tech, vec!["rust"]);
+        assert_eq!(query.count(&projects, &Language::EN), 3);
+    }
+
+    #[test]
+    fn test_status_and_text_filters() {
+        let projects = sample();
+        let archived = ProjectQuery::default().with_status(Some(ProjectStatus::Archived));
+        assert_eq!(ids(&archived.apply(&projects, &Language::EN)), vec!["beta"]);
+
+        // Text matches title, technologies and the description in the page language
+        let text = ProjectQuery::default().with_text(" VUE ");
+        assert_eq!(ids(&text.apply(&projects, &Language::EN)), vec!["gamma"]);
+        let spanish = ProjectQuery::default().with_text("herramienta delta");
+        assert_eq!(spanish.count(&projects, &Language::ES), 1);
+        assert_eq!(spanish.count(&projects, &Language::EN), 0);
+
+        assert!(text.cleared().is_unfiltered());
+    }
+
+    #[test]
+    fn test_sorts() {
+        let mut projects = sample();
+        projects[2].featured = true;
+        projects[1].stars = 9;
+        projects[3].stars = 3;
+        projects[3].started = chrono::NaiveDate::from_ymd_opt(2025, 6, 1);
+        projects[0].started = chrono::NaiveDate::from_ymd_opt(2024, 1, 1);
+        let sorted = |sort| {
+            ids(&ProjectQuery::default()
+                .with_sort(sort)
+                .apply(&projects, &Language::EN))
+            .join(" ")
+        };
+
+        assert_eq!(sorted(ProjectSort::Featured), "gamma alpha beta delta");
+        assert_eq!(sorted(ProjectSort::Newest), "delta alpha beta gamma");
+        assert_eq!(sorted(ProjectSort::Stars), "beta delta alpha gamma");
+        assert_eq!(sorted(ProjectSort::Name), "alpha beta delta gamma");
+    }
+
+    #[test]
+    fn test_technology_counts_and_chips() {
+        let projects = sample();
+
+        assert_eq!(
+            technology_counts(&projects),
+            vec![
+                ("Rust".to_string(), 3),
+                ("CLI".to_string(), 2),
+                ("Vue.js".to_string(), 1),
+                ("WASM".to_string(), 1),
+            ]
+        );
+
+        // A selected technology stays a chip even when no project lists it
+        let query = ProjectQuery::from("tech=Go");
+        assert_eq!(
+            technology_chips(&projects, &query)
+                .last()
+                .map(String::as_str),
+            Some("Go")
+        );
+    }
+
+    #[test]
+    fn test_status_slugs_parse() {
+        for status in ProjectStatus::ALL {
+            assert_eq!(status.slug().parse::<ProjectStatus>(), Ok(status));
+        }
+        assert_eq!(
+            "ARCHIVED".parse::<ProjectStatus>(),
+            Ok(ProjectStatus::Archived)
+        );
+        assert!("featured".parse::<ProjectStatus>().is_err());
+    }
+}
@@ -3,156 +3,110 @@
 
 use crate::components::atoms::{Badge, Button, ButtonVariant};
 use crate::components::layout_components::{Container, Grid, Section};
-use crate::components::molecules::{Card, SectionTitle};
+use crate::components::molecules::{Card, LanguageBar, SectionTitle};
+use crate::components::project_filters::ProjectQuery;
+use crate::domain::{DemoUrlSource, LocalizedText, Project, ProjectStatus};
 use crate::routes::Route;
-use crate::utils::github_api::parse_github_url;
+use crate::utils::forge::{Forge, RepoRef};
+use crate::utils::github_api::{GitHubRepoInfo, get_all_repos};
+use crate::utils::screenshots::{SCREENSHOT_HEIGHT, SCREENSHOT_WIDTH};
+use crate::utils::slugify;
+use crate::utils::responsive_images::{
+    CARD_SIZES, FEATURED_SIZES, VariantFormat, responsive_image,
+};
 use crate::utils::{ImageSource, get_project_image_url};
-use crate::i18n::Language;
 use dioxus::prelude::*;
-
-/// Project Status (P9-A4)
-#[derive(Clone, PartialEq, Copy, Debug)]
-pub enum ProjectStatus {
-    Featured,
-    Active,
-    Archived,
-}
-
-impl ProjectStatus {
-    pub fn label(&self) -> &'static str {
-        match self {
-            Self::Featured => "Featured",
-            Self::Active => "Active",
-            Self::Archived => "Archived",
-        }
-    }
-
-    pub fn color(&self) -> &'static str {
-        match self {
-            Self::Featured => "cyan",
-            Self::Active => "purple",
-            Self::Archived => "orange",
-        }
-    }
-}
-
-/// Project data structure (P9-A1, P9-A2)
-/// Updated for i18n (F18-C)
-#[derive(Clone, PartialEq)]
-pub struct Project {
-    pub id: &'static str,
-    pub title: &'static str,
-    
-    // Internal fields for localization - made pub for tests/construction
-    pub description_en: &'static str,
-    pub description_es: &'static str,
-    
-    pub long_description_en: &'static str,
-    pub long_description_es: &'static str,
-    
-    pub technologies: Vec<&'static str>,
-    pub status: ProjectStatus,
-    pub github_url: Option<&'static str>,
-    pub demo_url: Option<&'static str>,
-    pub image_override: Option<&'static str>,
-    pub image_fallback: &'static str,
-}
-
-impl Project {
-    pub fn description(&self, lang: &Language) -> &'static str {
-        match lang {
-            Language::EN => self.description_en,
-            Language::ES => self.description_es,
-        }
-    }
-
-    pub fn long_description(&self, lang: &Language) -> &'static str {
-        match lang {
-            Language::EN => self.long_description_en,
-            Language::ES => self.long_description_es,
-        }
-    }
-}
+use std::collections::HashMap;
 
 /// Get all projects data - Proyectos Reales del Usuario
+///
+/// Hand-written entries only; `get_projects_enriched` adds repository data
+/// and discovered repos.
 pub fn get_projects() -> Vec<Project> {
     vec![
         // Featured - Core Portfolio
         Project {
-            id: "enerby-dev",
-            title: "enerby.dev",
-            
-            description_en: "Strategic SPA built with Rust and Dioxus (WASM). Demonstrating architecture-first frontend development.",
-            description_es: "SPA estratégica construida con Rust y Dioxus (WASM). Demostrando desarrollo frontend basado en arquitectura.",
-            
-            long_description_en: "More than a portfolio, this is a statement. Designed to bridge Systems Programming and UI/UX. \n\nStrategy: Leverage Rust's memory safety for bulletproof logic. \nPsychology: Sub-two-second interactivity to project immediate efficiency and trust. \nArchitecture: Strict Atomic Design where every component is an isolated, testable unit. It represents the fusion of 'Safe' and 'Fast'.",
-            long_description_es: "Más que un portafolio, es una declaración de principios. Diseñado para unir la Programación de Sistemas con UI/UX. \n\nEstrategia: Utilizar la seguridad de memoria de Rust para una lógica a prueba de balas. \nPsicología: Interactividad instantánea para proyectar eficiencia y confianza absoluta. \nArquitectura: Diseño Atómico estricto donde cada componente es una unidad aislada. Representa la fusión de 'Seguro' y 'Rápido'.",
-            
-            technologies: vec!["Rust", "Dioxus", "WASM", "Tailwind", "Atomic Design"],
-            status: ProjectStatus::Featured,
-            github_url: Some("https://github.com/enerBydev/enerby-dev"),
-            demo_url: Some("https://enerbydev.pages.dev"),
-            image_override: None,
-            image_fallback: "🦀",
+            id: "enerby-dev".to_string(),
+            title: "enerby.dev".to_string(),
+            description: LocalizedText::new(
+                "Strategic SPA built with Rust and Dioxus (WASM). Demonstrating architecture-first frontend development.",
+                "SPA estratégica construida con Rust y Dioxus (WASM). Demostrando desarrollo frontend basado en arquitectura.",
+            ),
+            long_description: LocalizedText::new(
+                "More than a portfolio, this is a statement. Designed to bridge Systems Programming and UI/UX. \n\nStrategy: Leverage Rust's memory safety for bulletproof logic. \nPsychology: Sub-two-second interactivity to project immediate efficiency and trust. \nArchitecture: Strict Atomic Design where every component is an isolated, testable unit. It represents the fusion of 'Safe' and 'Fast'.",
+                "Más que un portafolio, es una declaración de principios. Diseñado para unir la Programación de Sistemas con UI/UX. \n\nEstrategia: Utilizar la seguridad de memoria de Rust para una lógica a prueba de balas. \nPsicología: Interactividad instantánea para proyectar eficiencia y confianza absoluta. \nArquitectura: Diseño Atómico estricto donde cada componente es una unidad aislada. Representa la fusión de 'Seguro' y 'Rápido'.",
+            ),
+            technologies: ["Rust", "Dioxus", "WASM", "Tailwind", "Atomic Design"].map(String::from).into(),
+            status: ProjectStatus::Active,
+            featured: true,
+            github_url: Some("https://github.com/enerBydev/enerby-dev".to_string()),
+            demo_url: Some("https://enerbydev.pages.dev".to_string()),
+            demo_url_source: DemoUrlSource::Manual,
+            image_fallback: "🦀".to_string(),
+            ..Default::default()
         },
         // Featured - Rust CLI Tool
         Project {
-            id: "oc-diagdoc",
-            title: "oc_diagdoc",
-            
-            description_en: "Advanced CLI for technical documentation. Includes integral verification and real-time dashboard.",
-            description_es: "CLI avanzado para documentación técnica. Incluye verificación integral y dashboard en tiempo real.",
-            
-            long_description_en: "Advanced CLI tool for extensive technical documentation projects. Features integral verification, real-time stats dashboard, hierarchical visualization, dependency analysis, and quantum diagnostics with auto-repair.",
-            long_description_es: "Herramienta CLI avanzada para proyectos de documentación técnica extensos. Cuenta con verificación integral, panel de estadísticas en tiempo real, visualización jerárquica, análisis de dependencias y diagnóstico cuántico con auto-reparación.",
-            
-            technologies: vec!["Rust", "CLI", "WalkDir", "Serde", "Clap"],
-            status: ProjectStatus::Featured,
-            github_url: Some("https://github.com/enerBydev/oc_diagdoc"),
-            demo_url: Some("https://diag-doc.pages.dev"),
-            image_override: None,
-            image_fallback: "⚛️",
+            id: "oc-diagdoc".to_string(),
+            title: "oc_diagdoc".to_string(),
+            description: LocalizedText::new(
+                "Advanced CLI for technical documentation. Includes integral verification and real-time dashboard.",
+                "CLI avanzado para documentación técnica. Incluye verificación integral y dashboard en tiempo real.",
+            ),
+            long_description: LocalizedText::new(
+                "Advanced CLI tool for extensive technical documentation projects. Features integral verification, real-time stats dashboard, hierarchical visualization, dependency analysis, and quantum diagnostics with auto-repair.",
+                "Herramienta CLI avanzada para proyectos de documentación técnica extensos. Cuenta con verificación integral, panel de estadísticas en tiempo real, visualización jerárquica, análisis de dependencias y diagnóstico cuántico con auto-reparación.",
+            ),
+            technologies: ["Rust", "CLI", "WalkDir", "Serde", "Clap"].map(String::from).into(),
+            status: ProjectStatus::Active,
+            featured: true,
+            github_url: Some("https://github.com/enerBydev/oc_diagdoc".to_string()),
+            demo_url: Some("https://diag-doc.pages.dev".to_string()),
+            demo_url_source: DemoUrlSource::Manual,
+            image_fallback: "⚛️".to_string(),
+            ..Default::default()
         },
         // Featured - Linux Tool
         Project {
-            id: "affinity-legacy-bridge",
-            title: "Affinity Legacy Bridge",
-            
-            description_en: "Bridge to run Affinity on Linux LTS using Bottles/Flatpak. Solution for systems with old GLIBC.",
-            description_es: "Puente para ejecutar Affinity en Linux LTS usando Bottles/Flatpak. Solución para sistemas con GLIBC antiguo.",
-            
-            long_description_en: "Installation kit using Bottles (Flatpak) as a bridge to run Affinity Photo/Designer/Publisher on Linux LTS systems with GLIBC 2.35. Contains isolated modern libraries (GLIBC 2.42+) without breaking the host system.",
-            long_description_es: "Kit de instalación que usa Bottles (Flatpak) como puente para ejecutar Affinity Photo/Designer/Publisher en sistemas Linux LTS con GLIBC 2.35. Contiene bibliotecas aisladas modernas (GLIBC 2.42+) sin romper el sistema.",
-            
-            technologies: vec!["Shell", "Flatpak", "Bottles", "Wine", "Linux"],
-            status: ProjectStatus::Featured,
-            github_url: Some("https://github.com/enerBydev/Affinity-Legacy-Bridge"),
-            demo_url: None,
-            image_override: None,
-            image_fallback: "🎨",
+            id: "affinity-legacy-bridge".to_string(),
+            title: "Affinity Legacy Bridge".to_string(),
+            description: LocalizedText::new(
+                "Bridge to run Affinity on Linux LTS using Bottles/Flatpak. Solution for systems with old GLIBC.",
+                "Puente para ejecutar Affinity en Linux LTS usando Bottles/Flatpak. Solución para sistemas con GLIBC antiguo.",
+            ),
+            long_description: LocalizedText::new(
+                "Installation kit using Bottles (Flatpak) as a bridge to run Affinity Photo/Designer/Publisher on Linux LTS systems with GLIBC 2.35. Contains isolated modern libraries (GLIBC 2.42+) without breaking the host system.",
+                "Kit de instalación que usa Bottles (Flatpak) como puente para ejecutar Affinity Photo/Designer/Publisher en sistemas Linux LTS con GLIBC 2.35. Contiene bibliotecas aisladas modernas (GLIBC 2.42+) sin romper el sistema.",
+            ),
+            technologies: ["Shell", "Flatpak", "Bottles", "Wine", "Linux"].map(String::from).into(),
+            status: ProjectStatus::Active,
+            featured: true,
+            github_url: Some("https://github.com/enerBydev/Affinity-Legacy-Bridge".to_string()),
+            image_fallback: "🎨".to_string(),
+            ..Default::default()
         },
         // Active - AI Project
         Project {
-            id: "videoginiusai",
-            title: "VideoGIniusAI",
-            
-            description_en: "AI-powered video platform. Content automation with Nuxt 4, Vue.js, and Nitro/UnJS ecosystem.",
-            description_es: "Plataforma de video con IA. Automatización con Nuxt 4, Vue.js y el ecosistema Nitro/UnJS.",
-            
-            long_description_en: "Web application for video generation/edition using AI. Built with Nuxt 4 (Vue 3), utilizes Nitro for server-side logic and the UnJS toolchain for optimized performance. Integrates AI models to automate multimedia content creation.",
-            long_description_es: "Aplicación web para generación/edición de video con IA. Construida con Nuxt 4 (Vue 3), utiliza Nitro para lógica de servidor y el toolchain UnJS para rendimiento optimizado. Integra modelos de IA para automatizar contenido.",
-            
-            technologies: vec!["Nuxt 4", "Vue.js", "Nitro", "UnJS", "AI"],
+            id: "videoginiusai".to_string(),
+            title: "VideoGIniusAI".to_string(),
+            description: LocalizedText::new(
+                "AI-powered video platform. Content automation with Nuxt 4, Vue.js, and Nitro/UnJS ecosystem.",
+                "Plataforma de video con IA. Automatización con Nuxt 4, Vue.js y el ecosistema Nitro/UnJS.",
+            ),
+            long_description: LocalizedText::new(
+                "Web application for video generation/edition using AI. Built with Nuxt 4 (Vue 3), utilizes Nitro for server-side logic and the UnJS toolchain for optimized performance. Integrates AI models to automate multimedia content creation.",
+                "Aplicación web para generación/edición de video con IA. Construida con Nuxt 4 (Vue 3), utiliza Nitro para lógica de servidor y el toolchain UnJS para rendimiento optimizado. Integra modelos de IA para automatizar contenido.",
+            ),
+            technologies: ["Nuxt 4", "Vue.js", "Nitro", "UnJS", "AI"].map(String::from).into(),
             status: ProjectStatus::Active,
-            github_url: Some("https://github.com/enerBydev/VideoGIniusAI"),
-            demo_url: None,
-            image_override: None,
-            image_fallback: "🎬",
+            github_url: Some("https://github.com/enerBydev/VideoGIniusAI".to_string()),
+            image_fallback: "🎬".to_string(),
+            ..Default::default()
         },
     ]
 }
 
-/// Get project by ID
+/// Get hand-written project by ID
 pub fn get_project_by_id(id: &str) -> Option<Project> {
     get_projects().into_iter().find(|p| p.id == id)
 }
@@ -161,150 +115,221 @@ pub fn get_project_by_id(id: &str) -> Option<Project> {
 // GITHUB ENRICHMENT (F17 - Phase D)
 // ============================================================================
 
-/// Enriched project with dynamic demo_url from GitHub
-/// This struct owns its strings for dynamic data and i18n support
-#[derive(Clone, PartialEq)]
-pub struct EnrichedProject {
-    pub id: String,
-    pub title: String,
-    
-    // Localized fields
-    pub description_en: String,
-    pub description_es: String,
-    
-    pub long_description_en: String,
-    pub long_description_es: String,
-    
-    pub technologies: Vec<String>,
-    pub status: ProjectStatus,
-    pub github_url: Option<String>,
-    /// Demo URL - either manual or auto-detected from GitHub homepage
-    pub demo_url: Option<String>,
-    pub image_override: Option<String>,
-    pub image_fallback: String,
-    /// Source of demo_url for debugging/UI
-    pub demo_url_source: DemoUrlSource,
+/// Enriches a project with repository data from its forge
+///
+/// If the project has a github_url but no demo_url, attempts to fetch
+/// the homepage field from the repository (any supported forge).
+///
+/// # Arguments
+/// * `project` - The project to enrich
+///
+/// # Returns
+/// The project with demo_url potentially filled from GitHub
+pub fn enrich_project_with_github(mut project: Project) -> Project {
+    if project.demo_url.is_some() {
+        project.demo_url_source = DemoUrlSource::Manual;
+    }
+
+    if let Some(repo) = project.github_url.as_deref().and_then(RepoRef::parse)
+        && let Ok(repo_info) = repo.provider().repo_info(&repo)
+    {
+        apply_repo_info(&mut project, &repo_info);
+    }
+
+    project
 }
 
-impl EnrichedProject {
-    pub fn description(&self, lang: &Language) -> &str {
-        match lang {
-            Language::EN => &self.description_en,
-            Language::ES => &self.description_es,
-        }
+/// Applies GitHub repository data to a project
+///
+/// Star counts and languages are always taken from GitHub, the start date
+/// unless set by hand. Homepage, description and topics
+/// are only applied when the demo_url was not set manually, so hand-written
+/// entries stay as authored.
+pub fn apply_repo_info(project: &mut Project, repo_info: &GitHubRepoInfo) {
+    project.repo_synced = true;
+    project.stars = repo_info.stargazers_count;
+    project.forks = repo_info.forks_count;
+    project.open_issues = repo_info.open_issues_count;
+    project.language = repo_info.language.clone().filter(|l| !l.is_empty());
+    project.license = repo_info.license.as_ref().map(|l| l.label().to_string());
+    project.last_push = repo_info.pushed_date();
+
+    // Only the sync fetches `/languages`; live repo data comes without them
+    if !repo_info.languages.is_empty() {
+        project.languages = repo_info.languages.clone();
     }
 
-    pub fn long_description(&self, lang: &Language) -> &str {
-        match lang {
-            Language::EN => &self.long_description_en,
-            Language::ES => &self.long_description_es,
-        }
+    if project.started.is_none() {
+        project.started = repo_info.created_date();
     }
-}
 
-/// Indicates where the demo_url came from
-#[derive(Clone, PartialEq, Debug)]
-pub enum DemoUrlSource {
-    /// Manually set in project definition
-    Manual,
-    /// Auto-detected from GitHub repository homepage field
-    GitHub,
-    /// No demo URL available
-    None,
-}
+    // The portfolio marker is not a topic or a technology
+    project.topics = repo_info
+        .topics
+        .iter()
+        .filter(|t| !t.eq_ignore_ascii_case(PORTFOLIO_TOPIC))
+        .cloned()
+        .collect();
 
-impl From<Project> for EnrichedProject {
-    fn from(p: Project) -> Self {
-        Self {
-            id: p.id.to_string(),
-            title: p.title.to_string(),
-            
-            description_en: p.description_en.to_string(),
-            description_es: p.description_es.to_string(),
-            
-            long_description_en: p.long_description_en.to_string(),
-            long_description_es: p.long_description_es.to_string(),
-            
-            technologies: p.technologies.iter().map(|t| t.to_string()).collect(),
-            status: p.status,
-            github_url: p.github_url.map(|s| s.to_string()),
-            demo_url: p.demo_url.map(|s| s.to_string()),
-            image_override: p.image_override.map(|s| s.to_string()),
-            image_fallback: p.image_fallback.to_string(),
-            demo_url_source: if p.demo_url.is_some() { DemoUrlSource::Manual } else { DemoUrlSource::None },
+    if project.demo_url_source == DemoUrlSource::Manual {
+        return;
+    }
+
+    // Enrich Homepage
+    if let Some(homepage) = repo_info.extract_homepage() {
+        project.demo_url = Some(homepage);
+        project.demo_url_source = DemoUrlSource::GitHub;
+    }
+
+    // Enrich Description - GitHub descriptions are English, ES keeps the manual translation
+    if let Some(desc) = repo_info.description.as_ref().filter(|d| !d.is_empty()) {
+        project.description.en = desc.clone();
+    }
+
+    // Enrich Technologies/Topics (if available)
+    let topics = project.topics.clone();
+    if !topics.is_empty() {
+        let mut new_techs = topics;
+        for tech in &project.technologies {
+            // Avoid duplicates (case-insensitive check)
+            if !new_techs.iter().any(|t| t.eq_ignore_ascii_case(tech)) {
+                new_techs.push(tech.clone());
+            }
         }
+        project.technologies = new_techs;
     }
 }
 
-/// Enriches a project with GitHub data
-///
-/// If the project has a github_url but no demo_url, attempts to fetch
-/// the homepage field from the GitHub repository.
-///
-/// # Arguments
-/// * `project` - The project to enrich
+// ============================================================================
+// AUTO-DISCOVERED PROJECTS
+// ============================================================================
+
+/// Repository topic that adds a repo to the portfolio without a hand-written entry
+pub const PORTFOLIO_TOPIC: &str = "portfolio";
+
+/// Placeholder emoji for discovered projects
+const DISCOVERED_FALLBACK_EMOJI: &str = "📦";
+
+/// Builds a project from a snapshot repo tagged with `PORTFOLIO_TOPIC`
 ///
-/// # Returns
-/// An EnrichedProject with demo_url potentially filled from GitHub
-pub fn enrich_project_with_github(project: Project) -> EnrichedProject {
-    let mut enriched = EnrichedProject::from(project.clone());
+/// Forks and untagged repos yield `None`. Archived repos are listed as
+/// `ProjectStatus::Archived`, everything else as `Active`. GitHub descriptions
+/// are English, so both languages show the same text (the repo's full name
+/// when it has no description).
+pub fn discover_project(repo: &GitHubRepoInfo) -> Option<Project> {
+    let tagged = repo
+        .topics
+        .iter()
+        .any(|t| t.eq_ignore_ascii_case(PORTFOLIO_TOPIC));
+    if repo.fork || !tagged {
+        return None;
+    }
 
-    // DEBUG PROBE REMOVED
+    let description = repo
+        .description
+        .clone()
+        .filter(|d| !d.trim().is_empty())
+        .unwrap_or_else(|| repo.full_name.clone());
+
+    let mut project = Project {
+        id: slugify(&repo.name),
+        title: repo.name.clone(),
+        description: LocalizedText::untranslated(description.clone()),
+        long_description: LocalizedText::untranslated(description),
+        technologies: repo.language.iter().cloned().collect(),
+        status: if repo.archived {
+            ProjectStatus::Archived
+        } else {
+            ProjectStatus::Active
+        },
+        github_url: Some(repo.html_url.clone()),
+        image_fallback: DISCOVERED_FALLBACK_EMOJI.to_string(),
+        ..Default::default()
+    };
+    apply_repo_info(&mut project, repo);
+    Some(project)
+}
 
-    // If demo_url already exists (manually set), keep it
-    if enriched.demo_url.is_some() {
-        return enriched;
+/// Hand-written projects followed by the discovered repos they don't cover
+///
+/// A discovered repo is dropped when a hand-written entry has the same id or
+/// points at the same repository, so hand-written fields always win.
+pub fn merge_discovered_projects(
+    hand_written: Vec<Project>,
+    repos: &[GitHubRepoInfo],
+) -> Vec<Project> {
+    let mut projects = hand_written;
+
+    for discovered in repos.iter().filter_map(discover_project) {
+        let covered = projects.iter().any(|p| {
+            p.id == discovered.id
+                || same_repository(p.github_url.as_deref(), discovered.github_url.as_deref())
+        });
+        if !covered {
+            projects.push(discovered);
+        }
     }
+    projects
+}
 
-    // Try to get homepage from GitHub
-    if let Some(github_url) = &project.github_url {
-        if let Some((owner, repo)) = parse_github_url(github_url) {
-            // Trace logs
-            println!("enriching: {}", &project.id);
-            if let Ok(repo_info) = crate::utils::github_api::get_repo_info(&owner, &repo) {
-                println!("found repo: {}", &repo_info.full_name);
-
-                // Enrich Homepage
-                if let Some(homepage) = repo_info.extract_homepage() {
-                    println!("updating demo_url: {}", &homepage);
-                    enriched.demo_url = Some(homepage);
-                    enriched.demo_url_source = DemoUrlSource::GitHub;
-                }
+/// True when both URLs parse to the same repository (case-insensitive)
+fn same_repository(a: Option<&str>, b: Option<&str>) -> bool {
+    match (a.and_then(RepoRef::parse), b.and_then(RepoRef::parse)) {
+        (Some(a), Some(b)) => {
+            a.host.eq_ignore_ascii_case(&b.host)
+                && a.full_name().eq_ignore_ascii_case(&b.full_name())
+        }
+        _ => false,
+    }
+}
 
-                // Enrich Description (if available)
-                if let Some(desc) = &repo_info.description {
-                    println!("checking description: '{}'", desc);
-                    if !desc.is_empty() {
-                        println!("updating description");
-                        // GitHub descriptions are typically in English (or the repo's primary language)
-                        // We update the EN field and optionally sync ES or leave it
-                        enriched.description_en = desc.clone();
-                        // For now, if we get a better description from GitHub, we might want to use it for ES too 
-                        // if the original was empty or placeholder, but to be safe let's just update EN.
-                        // Or better: update both if we assume the GitHub desc is the "canonical" one.
-                        // Strategy: Update EN, leave ES as is (manual translation preserved).
-                    }
-                }
+// ============================================================================
+// LIVE GITHUB DATA
+// ============================================================================
 
-                // Enrich Technologies/Topics (if available)
-                if !repo_info.topics.is_empty() {
-                    println!("merging topics: {:?}", &repo_info.topics);
-                    let mut new_techs = repo_info.topics.clone();
-                    for tech in &enriched.technologies {
-                        // Avoid duplicates (case-insensitive check)
-                        if !new_techs.iter().any(|t| t.eq_ignore_ascii_case(tech)) {
-                            new_techs.push(tech.clone());
+/// Repo data refreshed in the browser, keyed by lowercase "owner/repo"
+///
+/// Empty until a runtime fetch returns data that differs from the embedded
+/// snapshot; cards subscribed through `use_live_repo` re-render on updates.
+pub static LIVE_REPOS: GlobalSignal<HashMap<String, GitHubRepoInfo>> =
+    Signal::global(HashMap::new);
+
+fn live_repo_key(owner: &str, repo: &str) -> String {
+    format!("{}/{}", owner, repo).to_lowercase()
+}
+
+/// Returns fresh GitHub data for a project once the browser has fetched it
+///
+/// On WASM the first render spawns a background refresh; failures (rate limit,
+/// offline, missing repo) are ignored and the card keeps its snapshot data.
+/// Only GitHub repos are refreshed live; other forges use the snapshot.
+pub fn use_live_repo(github_url: Option<String>) -> Option<GitHubRepoInfo> {
+    let target = github_url
+        .as_deref()
+        .and_then(RepoRef::parse)
+        .filter(|repo| repo.forge == Forge::GitHub);
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        let target = target.clone();
+        use_hook(move || {
+            if let Some(repo) = target {
+                spawn(async move {
+                    if let Ok(info) =
+                        crate::utils::github_fetch::refresh_repo(&repo.owner, &repo.name).await
+                    {
+                        let key = live_repo_key(&repo.owner, &repo.name);
+                        if LIVE_REPOS.peek().get(&key) != Some(&info) {
+                            LIVE_REPOS.write().insert(key, info);
                         }
                     }
-                    enriched.technologies = new_techs;
-                }
-            } else {
-                println!("repo not found: {}/{}", owner, repo);
+                });
             }
-        }
+        });
     }
 
-    enriched
+    let repo = target?;
+    LIVE_REPOS.read().get(&live_repo_key(&repo.owner, &repo.name)).cloned()
 }
 
 /// Get all projects enriched with GitHub data
@@ -312,17 +337,18 @@ pub fn enrich_project_with_github(project: Project) -> EnrichedProject {
 /// This function:
 /// 1. Loads all static projects
 /// 2. For each project without demo_url, checks GitHub for homepage
-/// 3. Returns enriched projects with auto-detected demo URLs
-pub fn get_projects_enriched() -> Vec<EnrichedProject> {
-    get_projects()
+/// 3. Appends snapshot repos tagged `PORTFOLIO_TOPIC` that no static project covers
+pub fn get_projects_enriched() -> Vec<Project> {
+    let hand_written = get_projects()
         .into_iter()
         .map(enrich_project_with_github)
-        .collect()
+        .collect();
+    merge_discovered_projects(hand_written, get_all_repos())
 }
 
-/// Get enriched project by ID
-pub fn get_enriched_project_by_id(id: &str) -> Option<EnrichedProject> {
-    get_project_by_id(id).map(enrich_project_with_github)
+/// Get enriched project by ID (hand-written or discovered)
+pub fn get_enriched_project_by_id(id: &str) -> Option<Project> {
+    get_projects_enriched().into_iter().find(|p| p.id == id)
 }
 
 /// Statistics about GitHub enrichment
@@ -359,168 +385,91 @@ pub fn get_enrichment_stats() -> EnrichmentStats {
     }
 }
 
-/// Projects Section (P9-C1)
-#[component]
-pub fn ProjectsSection() -> Element {
-    let projects = get_projects();
-    let featured: Vec<_> = projects
-        .iter()
-        .filter(|p| p.status == ProjectStatus::Featured)
-        .take(3)
-        .cloned()
-        .collect();
-    let active: Vec<_> = projects
-        .iter()
-        .filter(|p| p.status == ProjectStatus::Active)
-        .cloned()
-        .collect();
-    let i18n = crate::i18n::use_i18n();
-
-    rsx! {
-        Section { id: "projects", alternate: true,
-            Container {
-                SectionTitle {
-                    text: i18n.projects.title.to_string(),
-                    subtitle: i18n.projects.subtitle.to_string(),
-                    center: true
-                }
-
-                // Featured Projects
-                if !featured.is_empty() {
-                    div { class: "mb-12",
-                        for project in featured.iter() {
-                            ProjectCard { project: project.clone(), featured: true }
-                        }
-                    }
-                }
-
-                // Active Projects Grid (P9-C3)
-                Grid { cols: 1, md_cols: 2, gap: 6,
-                    for project in active.iter() {
-                        ProjectCard { project: project.clone(), featured: false }
-                    }
-                }
-
-                // View All Button
-                div { class: "text-center mt-12",
-                    Button {
-                        variant: ButtonVariant::Ghost,
-                        to: Route::ProjectsPage {},
-                        "{i18n.projects.view_all}"
-                    }
-                }
-            }
-        }
-    }
-}
-
 use crate::i18n::I18N_CONFIG;
+use crate::i18n::format::{format_compact, format_integer};
 
-/// Project Card (P9-C2)
+/// Card image: responsive variants when the image pipeline has produced them,
+/// otherwise the remote image, or the emoji when there is no image at all
 #[component]
-pub fn ProjectCard(project: Project, #[props(default = false)] featured: bool) -> Element {
-    let lang = I18N_CONFIG.read().language;
-    let i18n = crate::i18n::use_i18n();
-    let card_class = if featured {
-        "mb-8".to_string()
-    } else {
-        "h-full".to_string()
-    };
-
-    // Get the best available image source for this project
-    let image_source = get_project_image_url(&project);
-    let fallback_emoji = project.image_fallback.to_string();
-
-    rsx! {
-        Card { hover_effect: true, class: card_class,
-            div { class: if featured { "lg:flex gap-8" } else { "" },
-                // Project Image - dynamic loading with fallback
-                div { class: if featured { "lg:w-1/3 mb-4 lg:mb-0" } else { "mb-4" },
-                    div { class: "aspect-video bg-gradient-to-br from-primary/20 via-bg-element to-secondary-purple/20 rounded-lg flex items-center justify-center border border-white/5 overflow-hidden relative",
-                        match &image_source {
-                            ImageSource::Fallback(emoji) => rsx! {
-                                span { class: "text-4xl font-display font-bold text-white/60", "{emoji}" }
-                            },
-                            _ => rsx! {
-                                // Fallback emoji shown behind image (visible if image fails to load)
-                                span {
-                                    class: "absolute inset-0 flex items-center justify-center text-4xl font-display font-bold text-white/60",
-                                    "{fallback_emoji}"
-                                }
-                                img {
-                                    src: "{image_source}",
-                                    alt: "{project.title}",
-                                    class: "relative w-full h-full object-cover",
-                                    loading: "lazy",
-                                }
-                            }
-                        }
-                    }
-                }
-
-                // Content
-                div { class: if featured { "lg:w-2/3" } else { "" },
-                    // Status badge
-                    div { class: "flex items-center gap-2 mb-2",
-                        Badge { color: project.status.color().to_string(), "{project.status.label()}" }
-                    }
-
-                    // Title
-                    h3 { class: "text-xl font-bold text-white mb-2 group-hover:text-primary transition-colors",
-                        "{project.title}"
-                    }
+fn ProjectThumbnail(
+    project_id: String,
+    title: String,
+    image_source: ImageSource,
+    fallback_emoji: String,
+    featured: bool,
+) -> Element {
+    let sizes = if featured { FEATURED_SIZES } else { CARD_SIZES };
 
-                    // Description
-                    p { class: "text-muted text-sm mb-4 line-clamp-2",
-                        "{project.description(&lang)}"
+    match (&image_source, responsive_image(&project_id)) {
+        (ImageSource::Fallback(emoji), _) => rsx! {
+            span { class: "text-4xl font-display font-bold text-white/60", "{emoji}" }
+        },
+        (_, responsive) => rsx! {
+            // Fallback emoji shown behind image (visible if image fails to load)
+            span {
+                class: "absolute inset-0 flex items-center justify-center text-4xl font-display font-bold text-white/60",
+                "{fallback_emoji}"
+            }
+            if let Some(image) = responsive {
+                picture { class: "relative w-full h-full",
+                    for set in image.sources.iter() {
+                        source { r#type: set.format.mime_type(), "srcset": "{set.srcset}", "sizes": sizes }
                     }
-
-                    // Technologies
-                    div { class: "flex flex-wrap gap-2 mb-4",
-                        for tech in project.technologies.iter() {
-                            span { class: "text-xs px-2 py-1 bg-white/5 rounded text-secondary", "{tech}" }
-                        }
-                    }
-
-                    // Links
-                    div { class: "flex gap-3",
-                        if let Some(github) = project.github_url {
-                            Button {
-                                variant: ButtonVariant::Ghost,
-                                href: Some(github.to_string()),
-                                new_tab: true,
-                                "{i18n.projects.btn_github}"
-                            }
-                        }
-                        if let Some(demo) = project.demo_url {
-                            Button {
-                                variant: ButtonVariant::Neon,
-                                href: Some(demo.to_string()),
-                                new_tab: true,
-                                "{i18n.projects.btn_demo}"
-                            }
-                        }
+                    // Blurred placeholder paints until the variant loads
+                    img {
+                        src: "{image.src}",
+                        srcset: image.srcset(VariantFormat::Webp).unwrap_or_default().to_string(),
+                        sizes,
+                        width: "{image.width}",
+                        height: "{image.height}",
+                        alt: "{title}",
+                        class: "w-full h-full object-cover bg-cover bg-center",
+                        style: "background-image: url({image.placeholder})",
+                        loading: "lazy",
+                        decoding: "async",
                     }
                 }
+            } else {
+                // Remote sources are requested at the screenshot size, which
+                // reserves the box before they load
+                img {
+                    src: "{image_source}",
+                    width: "{SCREENSHOT_WIDTH}",
+                    height: "{SCREENSHOT_HEIGHT}",
+                    alt: "{title}",
+                    class: "relative w-full h-full object-cover",
+                    loading: "lazy",
+                    decoding: "async",
+                }
             }
-        }
+        },
     }
 }
 
 // ============================================================================
-// ENRICHED PROJECT CARD (F17 - Phase F)
+// PROJECT CARD (F17 - Phase F)
 // ============================================================================
 
-/// Enriched Project Card with GitHub demo_url source indicator
+/// Languages shown on a card before folding the rest into "Other"
+pub const CARD_LANGUAGE_LIMIT: usize = 3;
+
+/// Project Card with GitHub demo_url source indicator
 ///
-/// This component uses EnrichedProject and shows:
+/// `featured` picks the wide layout; the "Featured" badge follows
+/// `project.featured`. Shows:
 /// - Visual indicator (GitHub icon) if demo_url came from GitHub
 /// - Tooltip explaining the source
 #[component]
-pub fn EnrichedProjectCard(
-    project: EnrichedProject,
+pub fn ProjectCard(
+    project: Project,
     #[props(default = false)] featured: bool,
 ) -> Element {
+    // Re-apply GitHub data once the browser has fetched a fresher copy
+    let mut project = project;
+    if let Some(info) = use_live_repo(project.github_url.clone()) {
+        apply_repo_info(&mut project, &info);
+    }
+
     let lang = I18N_CONFIG.read().language;
     let i18n = crate::i18n::use_i18n();
     let card_class = if featured {
@@ -529,42 +478,9 @@ pub fn EnrichedProjectCard(
         "h-full".to_string()
     };
 
-    // Create a temporary Project for image lookup
-    let temp_project = Project {
-        id: Box::leak(project.id.clone().into_boxed_str()),
-        title: Box::leak(project.title.clone().into_boxed_str()),
-        
-        description_en: Box::leak(project.description_en.clone().into_boxed_str()),
-        description_es: Box::leak(project.description_es.clone().into_boxed_str()),
-        
-        long_description_en: Box::leak(project.long_description_en.clone().into_boxed_str()),
-        long_description_es: Box::leak(project.long_description_es.clone().into_boxed_str()),
-        
-        technologies: project
-            .technologies
-            .iter()
-            .map(|s| -> &'static str { Box::leak(s.clone().into_boxed_str()) })
-            .collect(),
-        status: project.status,
-        github_url: project
-            .github_url
-            .clone()
-            .map(|s| -> &'static str { Box::leak(s.into_boxed_str()) }),
-        demo_url: project
-            .demo_url
-            .clone()
-            .map(|s| -> &'static str { Box::leak(s.into_boxed_str()) }),
-        image_override: project
-            .image_override
-            .clone()
-            .map(|s| -> &'static str { Box::leak(s.into_boxed_str()) }),
-        image_fallback: Box::leak(project.image_fallback.clone().into_boxed_str()),
-    };
-
-    let image_source = get_project_image_url(&temp_project);
+    let image_source = get_project_image_url(&project);
     let fallback_emoji = project.image_fallback.to_string();
 
-    // Demo URL button label and tooltip based on source
     // Demo URL button label and tooltip based on source
     let (demo_label, demo_tooltip): (String, Option<&'static str>) = match project.demo_url_source {
         DemoUrlSource::GitHub => (format!("{} 🔗", i18n.projects.btn_demo), Some(i18n.projects.tooltip_auto)),
@@ -578,23 +494,12 @@ pub fn EnrichedProjectCard(
                 // Project Image - dynamic loading with fallback
                 div { class: if featured { "lg:w-1/3 mb-4 lg:mb-0" } else { "mb-4" },
                     div { class: "aspect-video bg-gradient-to-br from-primary/20 via-bg-element to-secondary-purple/20 rounded-lg flex items-center justify-center border border-white/5 overflow-hidden relative",
-                        match &image_source {
-                            ImageSource::Fallback(emoji) => rsx! {
-                                span { class: "text-4xl font-display font-bold text-white/60", "{emoji}" }
-                            },
-                            _ => rsx! {
-                                // Fallback emoji shown behind image (visible if image fails to load)
-                                span {
-                                    class: "absolute inset-0 flex items-center justify-center text-4xl font-display font-bold text-white/60",
-                                    "{fallback_emoji}"
-                                }
-                                img {
-                                    src: "{image_source}",
-                                    alt: "{project.title}",
-                                    class: "relative w-full h-full object-cover",
-                                    loading: "lazy",
-                                }
-                            }
+                        ProjectThumbnail {
+                            project_id: project.id.to_string(),
+                            title: project.title.to_string(),
+                            image_source: image_source.clone(),
+                            fallback_emoji: fallback_emoji.clone(),
+                            featured,
                         }
                         // Demo Source Indicator
                         if project.demo_url_source == DemoUrlSource::GitHub {
@@ -610,9 +515,12 @@ pub fn EnrichedProjectCard(
 
                 // Content
                 div { class: if featured { "lg:w-2/3" } else { "" },
-                    // Status badge + GitHub indicator
+                    // Featured + status badges + GitHub indicator
                     div { class: "flex items-center gap-2 mb-2",
-                        Badge { color: project.status.color().to_string(), "{project.status.label()}" }
+                        if project.featured {
+                            Badge { "{i18n.projects.status_featured}" }
+                        }
+                        Badge { color: project.status.color().to_string(), "{project.status.label(&lang)}" }
                         // GitHub auto-detection indicator
                         if project.demo_url_source == DemoUrlSource::GitHub {
                             span {
@@ -621,6 +529,13 @@ pub fn EnrichedProjectCard(
                                 "🔗 {i18n.projects.badge_auto}"
                             }
                         }
+                        // Compact on the card, exact count in the tooltip
+                        if project.stars > 0 {
+                            span { class: "text-xs text-muted",
+                                title: "{i18n.projects.stars_title}: {format_integer(project.stars as u64, &lang)}",
+                                "★ {format_compact(project.stars as u64, &lang)}"
+                            }
+                        }
                     }
 
                     // Title
@@ -630,7 +545,7 @@ pub fn EnrichedProjectCard(
 
                     // Description
                     p { class: "text-muted text-sm mb-4 line-clamp-2",
-                        "{project.description(&lang)}"
+                        "{project.description.get(&lang)}"
                     }
 
                     // Technologies
@@ -640,6 +555,16 @@ pub fn EnrichedProjectCard(
                         }
                     }
 
+                    // Language breakdown
+                    if !project.languages.is_empty() {
+                        div { class: "mb-4",
+                            LanguageBar {
+                                languages: project.languages.clone(),
+                                limit: CARD_LANGUAGE_LIMIT,
+                            }
+                        }
+                    }
+
                     // Links
                     div { class: "flex gap-3 items-center",
                         if let Some(github) = &project.github_url {
@@ -669,21 +594,21 @@ pub fn EnrichedProjectCard(
     }
 }
 
-/// Projects Section using Enriched Projects (F17)
+/// Projects Section (P9-C1, F17)
 ///
-/// Uses EnrichedProjectCard to show GitHub-sourced demo URLs with visual indicator
+/// Up to three featured projects, then the other projects still being worked on
 #[component]
-pub fn EnrichedProjectsSection() -> Element {
+pub fn ProjectsSection() -> Element {
     let projects = get_projects_enriched();
     let featured: Vec<_> = projects
         .iter()
-        .filter(|p| p.status == ProjectStatus::Featured)
+        .filter(|p| p.featured)
         .take(3)
         .cloned()
         .collect();
     let active: Vec<_> = projects
         .iter()
-        .filter(|p| p.status == ProjectStatus::Active)
+        .filter(|p| !p.featured && p.status != ProjectStatus::Archived)
         .cloned()
         .collect();
     let i18n = crate::i18n::use_i18n();
@@ -703,7 +628,7 @@ pub fn EnrichedProjectsSection() -> Element {
                         h3 { class: "text-lg font-semibold text-primary mb-6", "{i18n.projects.section_featured}" }
                         div { class: "space-y-6",
                             for project in featured {
-                                EnrichedProjectCard { project: project.clone(), featured: true }
+                                ProjectCard { project: project.clone(), featured: true }
                             }
                         }
                     }
@@ -715,7 +640,7 @@ pub fn EnrichedProjectsSection() -> Element {
                         h3 { class: "text-lg font-semibold text-secondary mb-6", "{i18n.projects.section_active}" }
                         Grid { cols: 2, gap: 6,
                             for project in active {
-                                EnrichedProjectCard { project: project.clone() }
+                                ProjectCard { project: project.clone() }
                             }
                         }
                     }
@@ -723,7 +648,7 @@ pub fn EnrichedProjectsSection() -> Element {
 
                 // View all projects link
                 div { class: "mt-8 text-center",
-                    Link { to: Route::ProjectsPage {},
+                    Link { to: Route::ProjectsPage { query: ProjectQuery::default() },
                         Button { variant: ButtonVariant::Secondary, "{i18n.projects.view_all}" }
                     }
                 }
@@ -740,49 +665,33 @@ pub fn EnrichedProjectsSection() -> Element {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_enriched_project_from_project() {
-        let project = Project {
-            id: "test",
-            title: "Test Project",
-            description_en: "A test",
-            description_es: "A test",
-            long_description_en: "A longer test",
-            long_description_es: "A longer test",
-            technologies: vec!["Rust"],
-            status: ProjectStatus::Active,
-            github_url: Some("https://github.com/owner/repo"),
-            demo_url: Some("https://demo.com"),
-            image_override: None,
-            image_fallback: "🧪",
-        };
+    fn test_project(github_url: &str, demo_url: Option<&str>) -> Project {
+        Project {
+            id: "test".to_string(),
+            title: "Test".to_string(),
+            description: LocalizedText::untranslated("Test"),
+            long_description: LocalizedText::untranslated("Test"),
+            github_url: Some(github_url.to_string()),
+            demo_url: demo_url.map(str::to_string),
+            image_fallback: "🧪".to_string(),
+            ..Default::default()
+        }
+    }
 
-        let enriched = EnrichedProject::from(project);
+    #[test]
+    fn test_enrich_project_marks_manual_demo_url() {
+        // Not in the snapshot, so only the hand-written fields are used
+        let enriched =
+            enrich_project_with_github(test_project("https://github.com/owner/repo", Some("https://demo.com")));
 
         assert_eq!(enriched.id, "test");
-        assert_eq!(enriched.title, "Test Project");
         assert_eq!(enriched.demo_url, Some("https://demo.com".to_string()));
         assert_eq!(enriched.demo_url_source, DemoUrlSource::Manual);
     }
 
     #[test]
-    fn test_enriched_project_no_demo_url() {
-        let project = Project {
-            id: "test",
-            title: "Test",
-            description_en: "Test",
-            description_es: "Test",
-            long_description_en: "Test",
-            long_description_es: "Test",
-            technologies: vec![],
-            status: ProjectStatus::Active,
-            github_url: Some("https://github.com/owner/repo"),
-            demo_url: None,
-            image_override: None,
-            image_fallback: "🧪",
-        };
-
-        let enriched = EnrichedProject::from(project);
+    fn test_enrich_project_no_demo_url() {
+        let enriched = enrich_project_with_github(test_project("https://github.com/owner/repo", None));
 
         assert_eq!(enriched.demo_url, None);
         assert_eq!(enriched.demo_url_source, DemoUrlSource::None);
@@ -790,20 +699,7 @@ mod tests {
 
     #[test]
     fn test_enrich_project_keeps_manual_demo_url() {
-        let project = Project {
-            id: "test",
-            title: "Test",
-            description_en: "Test",
-            description_es: "Test",
-            long_description_en: "Test",
-            long_description_es: "Test",
-            technologies: vec![],
-            status: ProjectStatus::Active,
-            github_url: Some("https://github.com/enerBydev/enerby.dev"),
-            demo_url: Some("https://manual.demo"),
-            image_override: None,
-            image_fallback: "🧪",
-        };
+        let project = test_project("https://github.com/enerBydev/enerby.dev", Some("https://manual.demo"));
 
         let enriched = enrich_project_with_github(project);
 
@@ -814,21 +710,8 @@ mod tests {
 
     #[test]
     fn test_enrich_project_gets_github_homepage() {
-        // enerby.dev has homepage in static data
-        let project = Project {
-            id: "test",
-            title: "Test",
-            description_en: "Test",
-            description_es: "Test",
-            long_description_en: "Test",
-            long_description_es: "Test",
-            technologies: vec![],
-            status: ProjectStatus::Active,
-            github_url: Some("https://github.com/enerBydev/enerby.dev"),
-            demo_url: None, // No manual demo_url
-            image_override: None,
-            image_fallback: "🧪",
-        };
+        // enerby.dev has homepage in static data; no manual demo_url
+        let project = test_project("https://github.com/enerBydev/enerby.dev", None);
 
         let enriched = enrich_project_with_github(project);
 
@@ -840,40 +723,221 @@ mod tests {
     #[test]
     fn test_enrich_project_no_homepage_in_github() {
         // Case: Project has github_url, but repo has NO homepage (nvim-config)
+        let project = test_project("https://github.com/enerBydev/nvim-config", None);
+
+        let enriched = enrich_project_with_github(project);
+
+        // Should remain None since nvim-config has no homepage
+        assert_eq!(enriched.demo_url, None);
+        assert_eq!(enriched.demo_url_source, DemoUrlSource::None);
+    }
+
+    #[test]
+    fn test_enrich_project_does_not_mix_forges() {
+        // Same owner/name as a GitHub repo in the snapshot, but hosted on GitLab
         let project = Project {
-            id: "test",
-            title: "Test",
-            description_en: "Test",
-            description_es: "Test",
-            long_description_en: "Test",
-            long_description_es: "Test",
-            technologies: vec![],
-            status: ProjectStatus::Active,
-            github_url: Some("https://github.com/enerBydev/nvim-config"),
+            github_url: Some("https://gitlab.com/enerBydev/enerby.dev".to_string()),
             demo_url: None,
-            image_override: None,
-            image_fallback: "🧪",
+            ..get_projects()[0].clone()
         };
 
         let enriched = enrich_project_with_github(project);
 
-        // Should remain None since nvim-config has no homepage
         assert_eq!(enriched.demo_url, None);
-        assert_eq!(enriched.demo_url_source, DemoUrlSource::None);
+        assert_eq!(enriched.stars, 0);
+        assert!(!enriched.repo_synced);
+    }
+
+    #[test]
+    fn test_apply_repo_info_updates_live_fields() {
+        let mut enriched = get_projects()[0].clone();
+        enriched.demo_url = None;
+        enriched.demo_url_source = DemoUrlSource::None;
+
+        let repo_info = GitHubRepoInfo {
+            homepage: Some("https://live.example".to_string()),
+            description: Some("Live description".to_string()),
+            stargazers_count: 12,
+            forks_count: 4,
+            open_issues_count: 2,
+            language: Some("Rust".to_string()),
+            pushed_at: Some("2026-03-01T12:00:00Z".to_string()),
+            license: Some(crate::utils::github_api::RepoLicense {
+                spdx_id: Some("MIT".to_string()),
+                name: "MIT License".to_string(),
+            }),
+            topics: vec!["live-topic".to_string(), PORTFOLIO_TOPIC.to_string()],
+            ..GitHubRepoInfo::minimal("owner", "repo")
+        };
+        apply_repo_info(&mut enriched, &repo_info);
+
+        assert!(enriched.repo_synced);
+        assert_eq!(enriched.stars, 12);
+        assert_eq!(enriched.forks, 4);
+        assert_eq!(enriched.open_issues, 2);
+        assert_eq!(enriched.language.as_deref(), Some("Rust"));
+        assert_eq!(enriched.license.as_deref(), Some("MIT"));
+        assert_eq!(enriched.last_push, chrono::NaiveDate::from_ymd_opt(2026, 3, 1));
+        assert_eq!(enriched.topics, vec!["live-topic"]);
+        assert_eq!(enriched.demo_url, Some("https://live.example".to_string()));
+        assert_eq!(enriched.demo_url_source, DemoUrlSource::GitHub);
+        assert_eq!(enriched.description.en, "Live description");
+        assert_eq!(enriched.technologies[0], "live-topic");
+    }
+
+    #[test]
+    fn test_apply_repo_info_keeps_languages_without_new_data() {
+        let mut enriched = get_projects()[0].clone();
+        let mut repo_info = GitHubRepoInfo::minimal("owner", "repo");
+        repo_info.languages.insert("Rust".to_string(), 42);
+        apply_repo_info(&mut enriched, &repo_info);
+        assert_eq!(enriched.languages.get("Rust"), Some(&42));
+
+        // Live data from the browser has no languages
+        apply_repo_info(&mut enriched, &GitHubRepoInfo::minimal("owner", "repo"));
+        assert_eq!(enriched.languages.get("Rust"), Some(&42));
+    }
+
+    #[test]
+    fn test_apply_repo_info_fills_start_date_once() {
+        let mut enriched = get_projects()[0].clone();
+        let mut repo_info = GitHubRepoInfo::minimal("owner", "repo");
+        repo_info.created_at = Some("2025-01-15T10:00:00Z".to_string());
+        apply_repo_info(&mut enriched, &repo_info);
+        assert_eq!(enriched.started, chrono::NaiveDate::from_ymd_opt(2025, 1, 15));
+
+        // A date set by hand (or already known) is kept
+        repo_info.created_at = Some("2020-01-01T00:00:00Z".to_string());
+        apply_repo_info(&mut enriched, &repo_info);
+        assert_eq!(enriched.started, chrono::NaiveDate::from_ymd_opt(2025, 1, 15));
+    }
+
+    #[test]
+    fn test_apply_repo_info_keeps_manual_entries() {
+        let mut enriched = get_projects()[0].clone();
+        enriched.demo_url = Some("https://manual.demo".to_string());
+        enriched.demo_url_source = DemoUrlSource::Manual;
+        let description = enriched.description.clone();
+
+        let repo_info = GitHubRepoInfo {
+            homepage: Some("https://live.example".to_string()),
+            description: Some("Live description".to_string()),
+            stargazers_count: 3,
+            ..GitHubRepoInfo::minimal("owner", "repo")
+        };
+        apply_repo_info(&mut enriched, &repo_info);
+
+        // Stars always refresh, hand-written fields do not
+        assert_eq!(enriched.stars, 3);
+        assert_eq!(enriched.demo_url, Some("https://manual.demo".to_string()));
+        assert_eq!(enriched.description, description);
     }
 
     #[test]
     fn test_get_projects_enriched() {
         let enriched = get_projects_enriched();
 
-        // Should have same count as regular projects
-        assert_eq!(enriched.len(), get_projects().len());
+        // Every hand-written project, followed by any discovered repos
+        assert!(enriched.len() >= get_projects().len());
 
-        // All should be EnrichedProject
         for p in &enriched {
             assert!(!p.id.is_empty());
             assert!(!p.title.is_empty());
         }
+
+        // Ids stay unique once discovered repos are merged in
+        for (i, p) in enriched.iter().enumerate() {
+            assert!(!enriched[i + 1..].iter().any(|q| q.id == p.id), "duplicate id {}", p.id);
+        }
+    }
+
+    // -------------------------
+    // Discovery Tests
+    // -------------------------
+
+    fn tagged_repo(name: &str, topics: &[&str]) -> GitHubRepoInfo {
+        GitHubRepoInfo {
+            description: Some(format!("{} description", name)),
+            homepage: Some(format!("https://{}.example.com", name)),
+            language: Some("Rust".to_string()),
+            stargazers_count: 5,
+            topics: topics.iter().map(|t| t.to_string()).collect(),
+            ..GitHubRepoInfo::minimal("someone", name)
+        }
+    }
+
+    #[test]
+    fn test_discover_project_from_tagged_repo() {
+        let project = discover_project(&tagged_repo("New.App", &["portfolio", "wasm"])).unwrap();
+
+        assert_eq!(project.id, "new-app");
+        assert_eq!(project.title, "New.App");
+        assert_eq!(project.description, LocalizedText::untranslated("New.App description"));
+        assert_eq!(project.status, ProjectStatus::Active);
+        assert_eq!(project.github_url.as_deref(), Some("https://github.com/someone/New.App"));
+        assert_eq!(project.demo_url.as_deref(), Some("https://New.App.example.com"));
+        assert_eq!(project.demo_url_source, DemoUrlSource::GitHub);
+        assert_eq!(project.stars, 5);
+        // The marker topic is not a technology
+        assert_eq!(project.technologies, vec!["wasm", "Rust"]);
+    }
+
+    #[test]
+    fn test_discover_project_skips_forks_and_untagged() {
+        assert!(discover_project(&tagged_repo("plain", &["rust"])).is_none());
+
+        let fork = GitHubRepoInfo {
+            fork: true,
+            ..tagged_repo("fork", &["portfolio"])
+        };
+        assert!(discover_project(&fork).is_none());
+
+        // Topic matching ignores case
+        assert!(discover_project(&tagged_repo("upper", &["Portfolio"])).is_some());
+    }
+
+    #[test]
+    fn test_discover_project_archived_and_undescribed() {
+        let repo = GitHubRepoInfo {
+            archived: true,
+            description: None,
+            ..tagged_repo("old", &["portfolio"])
+        };
+        let project = discover_project(&repo).unwrap();
+
+        assert_eq!(project.status, ProjectStatus::Archived);
+        assert!(!project.featured);
+        assert_eq!(project.description.en, "someone/old");
+    }
+
+    #[test]
+    fn test_merge_discovered_projects_hand_written_wins() {
+        let hand_written = vec![
+            // Same id as the discovered "same-id" repo
+            Project {
+                id: "same-id".to_string(),
+                title: "Manual Title".to_string(),
+                ..get_projects()[0].clone()
+            },
+            // Same repository as the discovered "Renamed" repo, different id
+            Project {
+                id: "custom".to_string(),
+                github_url: Some("https://github.com/Someone/renamed".to_string()),
+                ..get_projects()[0].clone()
+            },
+        ];
+        let repos = vec![
+            tagged_repo("same-id", &["portfolio"]),
+            tagged_repo("Renamed", &["portfolio"]),
+            tagged_repo("fresh", &["portfolio"]),
+            tagged_repo("untagged", &[]),
+        ];
+
+        let merged = merge_discovered_projects(hand_written, &repos);
+        let ids: Vec<&str> = merged.iter().map(|p| p.id.as_str()).collect();
+
+        assert_eq!(ids, vec!["same-id", "custom", "fresh"]);
+        assert_eq!(merged[0].title, "Manual Title");
     }
 
     #[test]
@@ -940,14 +1004,15 @@ mod tests {
         let static_projects = get_projects();
         let enriched_projects = get_projects_enriched();
 
-        // Count should match
-        assert_eq!(static_projects.len(), enriched_projects.len());
+        // Hand-written projects come first, in order
+        assert!(enriched_projects.len() >= static_projects.len());
 
         // Each project should have preserved its original data
         for (static_p, enriched_p) in static_projects.iter().zip(enriched_projects.iter()) {
             assert_eq!(static_p.id, enriched_p.id);
             assert_eq!(static_p.title, enriched_p.title);
             assert_eq!(static_p.status, enriched_p.status);
+            assert_eq!(static_p.featured, enriched_p.featured);
         }
     }
 
@@ -964,7 +1029,7 @@ mod tests {
                     if let Some(orig_demo) = orig.demo_url {
                         assert_eq!(
                             project.demo_url,
-                            Some(orig_demo.to_string()),
+                            Some(orig_demo),
                             "Manual demo_url should be preserved for {}",
                             project.id
                         );
@@ -1010,7 +1075,7 @@ mod tests {
                 "Project title should not be empty"
             );
             assert!(
-                !project.description(&Language::EN).is_empty(),
+                !project.description.get(&Language::EN).is_empty(),
                 "Project description (EN) should not be empty"
             );
             assert!(
@@ -1018,11 +1083,11 @@ mod tests {
                 "Fallback emoji should exist"
             );
 
-            // If has github_url, should be valid
+            // If has github_url, should be a repository on a supported forge
             if let Some(ref url) = project.github_url {
                 assert!(
-                    url.starts_with("https://github.com/"),
-                    "GitHub URL invalid for {}",
+                    url.starts_with("https://") && RepoRef::parse(url).is_some(),
+                    "Repository URL invalid for {}",
                     project.id
                 );
             }
@@ -0,0 +1,399 @@
+//! Tech Radar Component
+//! SVG radar of the skills: `SkillCategory` quadrants × `RadarRing` rings
+//!
+//! Blip positions are computed from the skill data, never hand-placed: each
+//! quadrant/ring cell spreads its skills evenly across the quadrant.
+
+use crate::components::project_filters::ProjectQuery;
+use crate::domain::{Project, RadarRing, Skill, SkillCategory};
+use crate::i18n::I18N_CONFIG;
+use crate::routes::Route;
+use crate::theme::colors;
+use dioxus::prelude::*;
+
+/// viewBox size; the SVG scales to its container
+const RADAR_SIZE: f64 = 600.0;
+
+/// Outer radius of each ring, in `RadarRing::ALL` order
+const RING_RADII: [f64; 4] = [120.0, 190.0, 245.0, 290.0];
+
+/// Blip circle radius
+const BLIP_RADIUS: f64 = 9.0;
+
+/// Blips keep this many degrees away from the quadrant axes
+const AXIS_MARGIN_DEG: f64 = 8.0;
+
+/// One technology on the radar
+#[derive(Clone, Debug, PartialEq)]
+pub struct Blip {
+    /// 1-based, shared by the SVG and the legend
+    pub number: usize,
+    pub name: String,
+    pub category: SkillCategory,
+    pub ring: RadarRing,
+    pub x: f64,
+    pub y: f64,
+    /// The technology as the first project spells it, for the `?tech=`
+    /// filter, which matches the skill's other spellings too
+    pub technology: Option<String>,
+    /// Id and title of every project using it
+    pub projects: Vec<(String, String)>,
+}
+
+impl Blip {
+    /// Projects page filtered to this technology, if any project uses it
+    pub fn projects_route(&self) -> Option<Route> {
+        self.technology.as_ref().map(|tech| Route::ProjectsPage {
+            query: ProjectQuery::default().toggle_tech(tech),
+        })
+    }
+}
+
+/// Quadrant color (hex) of a category, matching its `Badge` color
+pub fn quadrant_color(category: SkillCategory) -> &'static str {
+    match category {
+        SkillCategory::Languages => colors::PRIMARY,
+        SkillCategory::Frameworks => colors::SECONDARY_PINK,
+        SkillCategory::Tools => colors::SECONDARY_PURPLE,
+        SkillCategory::Concepts => colors::SECONDARY_ORANGE,
+    }
+}
+
+/// Places every skill on the radar and links it to the projects using it
+///
+/// Numbered quadrant by quadrant, ring by ring, highest level first.
+pub fn radar_blips(skills: &[Skill], projects: &[Project]) -> Vec<Blip> {
+    let center = RADAR_SIZE / 2.0;
+    let mut blips = Vec::new();
+
+    for (quadrant, category) in SkillCategory::ALL.into_iter().enumerate() {
+        for (ring_index, ring) in RadarRing::ALL.into_iter().enumerate() {
+            let mut cell: Vec<&Skill> = skills
+                .iter()
+                .filter(|s| s.category == category && s.ring() == ring)
+                .collect();
+            cell.sort_by(|a, b| b.level.cmp(&a.level).then(a.name.cmp(&b.name)));
+
+            let inner = ring_index.checked_sub(1).map_or(0.0, |i| RING_RADII[i]);
+            let outer = RING_RADII[ring_index];
+
+            for (i, skill) in cell.iter().enumerate() {
+                let (angle, radius) = blip_position(quadrant, inner, outer, i, cell.len());
+                let using: Vec<&Project> = projects
+                    .iter()
+                    .filter(|p| p.technologies.iter().any(|t| skill.matches(t)))
+                    .collect();
+
+                blips.push(Blip {
+                    number: blips.len() + 1,
+                    name: skill.name.clone(),
+                    category,
+                    ring,
+                    x: center + radius * angle.cos(),
+                    y: center + radius * angle.sin(),
+                    technology: using
+                        .first()
+                        .and_then(|p| p.technologies.iter().find(|t| skill.matches(t)).cloned()),
+                    projects: using
+                        .iter()
+                        .map(|p| (p.id.clone(), p.title.clone()))
+                        .collect(),
+                });
+            }
+        }
+    }
+
+    blips
+}
+
+/// Angle (radians, clockwise from the x axis) and radius of the `i`-th of `n`
+/// blips in a quadrant/ring cell
+///
+/// Spread evenly across the quadrant, alternating between two radii so that
+/// neighbours do not touch. Quadrants go clockwise from the top right.
+fn blip_position(quadrant: usize, inner: f64, outer: f64, i: usize, n: usize) -> (f64, f64) {
+    let start = -90.0 + 90.0 * quadrant as f64 + AXIS_MARGIN_DEG;
+    let span = 90.0 - 2.0 * AXIS_MARGIN_DEG;
+    let angle = start + span * (i as f64 + 0.5) / n as f64;
+
+    // The innermost ring has no inner edge; keep blips off the center
+    let low = inner.max(BLIP_RADIUS * 3.0) + BLIP_RADIUS;
+    let high = outer - BLIP_RADIUS;
+    let radius = match (n, i % 2) {
+        (1, _) => (low + high) / 2.0,
+        (_, 0) => low + (high - low) * 0.3,
+        _ => low + (high - low) * 0.75,
+    };
+
+    (angle.to_radians(), radius)
+}
+
+/// The radar drawing
+#[component]
+pub fn TechRadar(blips: Vec<Blip>) -> Element {
+    let lang = I18N_CONFIG.read().language;
+    let i18n = crate::i18n::use_i18n();
+    let center = RADAR_SIZE / 2.0;
+    let outer = RING_RADII[RING_RADII.len() - 1];
+
+    rsx! {
+        svg {
+            class: "w-full max-w-2xl mx-auto",
+            view_box: "0 0 {RADAR_SIZE} {RADAR_SIZE}",
+            role: "group",
+            "aria-label": "{i18n.skills.radar_title}",
+
+            // Rings, outermost first so the inner ones draw on top
+            for (ring , radius) in RadarRing::ALL.into_iter().zip(RING_RADII).rev() {
+                circle {
+                    cx: "{center}",
+                    cy: "{center}",
+                    r: "{radius}",
+                    fill: if ring == RadarRing::Adopt { "rgba(0, 255, 255, 0.06)" } else { "rgba(255, 255, 255, 0.02)" },
+                    stroke: "rgba(255, 255, 255, 0.15)",
+                }
+                text {
+                    x: "{center + 4.0}",
+                    y: "{center - radius + 14.0}",
+                    fill: colors::TEXT_MUTED,
+                    font_size: "11",
+                    "{ring.label(&lang)}"
+                }
+            }
+
+            // Quadrant axes
+            line { x1: "{center - outer}", y1: "{center}", x2: "{center + outer}", y2: "{center}", stroke: "rgba(255, 255, 255, 0.2)" }
+            line { x1: "{center}", y1: "{center - outer}", x2: "{center}", y2: "{center + outer}", stroke: "rgba(255, 255, 255, 0.2)" }
+
+            // Blips
+            for blip in blips {
+                RadarBlip { key: "{blip.number}", blip }
+            }
+        }
+    }
+}
+
+/// A numbered circle; opens the projects using the technology
+#[component]
+fn RadarBlip(blip: Blip) -> Element {
+    let color = quadrant_color(blip.category);
+    let route = blip.projects_route();
+    let label = format!("{}. {}", blip.number, blip.name);
+
+    rsx! {
+        g {
+            class: if route.is_some() { "cursor-pointer" } else { "" },
+            role: if route.is_some() { "link" } else { "img" },
+            tabindex: if route.is_some() { "0" } else { "-1" },
+            "aria-label": "{label}",
+            onclick: {
+                let route = route.clone();
+                move |_| {
+                    if let Some(route) = route.clone() {
+                        navigator().push(route);
+                    }
+                }
+            },
+            onkeydown: move |evt: KeyboardEvent| {
+                if evt.key() == Key::Enter
+                    && let Some(route) = route.clone()
+                {
+                    navigator().push(route);
+                }
+            },
+            title { "{label}" }
+            circle {
+                cx: "{blip.x:.1}",
+                cy: "{blip.y:.1}",
+                r: "{BLIP_RADIUS}",
+                fill: "{color}",
+                fill_opacity: if blip.projects.is_empty() { "0.35" } else { "0.9" },
+            }
+            text {
+                x: "{blip.x:.1}",
+                y: "{blip.y + 3.5:.1}",
+                text_anchor: "middle",
+                font_size: "9",
+                font_weight: "bold",
+                fill: colors::BG_PRIMARY,
+                "{blip.number}"
+            }
+        }
+    }
+}
+
+/// Numbered list of the blips by quadrant, with the projects using each
+#[component]
+pub fn RadarLegend(blips: Vec<Blip>) -> Element {
+    let lang = I18N_CONFIG.read().language;
+    let i18n = crate::i18n::use_i18n();
+
+    rsx! {
+        div { class: "grid grid-cols-1 md:grid-cols-2 gap-8 mt-12",
+            for category in SkillCategory::ALL {
+                div { key: "{category.label(&lang)}",
+                    h2 {
+                        class: "text-lg font-bold mb-4",
+                        style: "color: {quadrant_color(category)}",
+                        "{category.label(&lang)}"
+                    }
+                    ol { class: "space-y-2",
+                        for blip in blips.iter().filter(|b| b.category == category) {
+                            li { key: "{blip.number}", class: "text-sm",
+                                span { class: "font-mono text-muted mr-2", "{blip.number}." }
+                                if let Some(route) = blip.projects_route() {
+                                    Link { to: route, class: "text-white hover:text-primary", "{blip.name}" }
+                                } else {
+                                    span { class: "text-white", "{blip.name}" }
+                                }
+                                span { class: "ml-2 text-xs text-muted", "· {blip.ring.label(&lang)}" }
+                                p { class: "ml-6 text-xs text-muted",
+                                    if blip.projects.is_empty() {
+                                        "{i18n.skills.radar_unused}"
+                                    } else {
+                                        "{i18n.skills.radar_used_in} "
+                                        for (i , (id , title)) in blip.projects.iter().enumerate() {
+                                            if i > 0 {
+                                                ", "
+                                            }
+                                            Link {
+                                                to: Route::ProjectDetailPage { slug: id.clone() },
+                                                class: "text-primary hover:underline",
+                                                "{title}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::projects::get_projects;
+    use crate::components::skills::get_skills;
+
+    fn distance(a: &Blip, b: &Blip) -> f64 {
+        ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt()
+    }
+
+    #[test]
+    fn test_every_skill_gets_one_blip() {
+        let skills = get_skills();
+        let blips = radar_blips(skills, &get_projects());
+
+        assert_eq!(blips.len(), skills.len());
+        let numbers: Vec<usize> = blips.iter().map(|b| b.number).collect();
+        assert_eq!(numbers, (1..=skills.len()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_blips_sit_in_their_ring_and_quadrant() {
+        let center = RADAR_SIZE / 2.0;
+        for blip in radar_blips(get_skills(), &get_projects()) {
+            let (dx, dy) = (blip.x - center, blip.y - center);
+            let radius = (dx * dx + dy * dy).sqrt();
+            let ring = RadarRing::ALL.iter().position(|r| *r == blip.ring).unwrap();
+            let inner = ring.checked_sub(1).map_or(0.0, |i| RING_RADII[i]);
+            assert!(
+                radius > inner && radius < RING_RADII[ring],
+                "{} outside its ring",
+                blip.name
+            );
+
+            // Clockwise from the top right: Languages, Frameworks, Tools, Concepts
+            let expected = match blip.category {
+                SkillCategory::Languages => dx > 0.0 && dy < 0.0,
+                SkillCategory::Frameworks => dx > 0.0 && dy > 0.0,
+                SkillCategory::Tools => dx < 0.0 && dy > 0.0,
+                SkillCategory::Concepts => dx < 0.0 && dy < 0.0,
+            };
+            assert!(expected, "{} outside its quadrant", blip.name);
+        }
+    }
+
+    #[test]
+    fn test_blips_do_not_overlap() {
+        let blips = radar_blips(get_skills(), &get_projects());
+        for (i, a) in blips.iter().enumerate() {
+            for b in &blips[i + 1..] {
+                assert!(
+                    distance(a, b) >= BLIP_RADIUS * 2.0,
+                    "{} overlaps {}",
+                    a.name,
+                    b.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_blips_link_to_projects_using_the_technology() {
+        let mut project = get_projects()[0].clone();
+        project.technologies = vec!["rust".to_string(), "Tailwind-CSS".to_string()];
+        let blips = radar_blips(get_skills(), std::slice::from_ref(&project));
+
+        let rust = blips.iter().find(|b| b.name == "Rust").unwrap();
+        assert_eq!(rust.technology.as_deref(), Some("rust"));
+        assert_eq!(
+            rust.projects,
+            vec![(project.id.clone(), project.title.clone())]
+        );
+        assert_eq!(
+            rust.projects_route().unwrap().to_string(),
+            "/projects?tech=rust"
+        );
+
+        // Punctuation does not matter
+        let tailwind = blips.iter().find(|b| b.name == "Tailwind CSS").unwrap();
+        assert_eq!(tailwind.projects.len(), 1);
+
+        let docker = blips.iter().find(|b| b.name == "Docker").unwrap();
+        assert!(docker.projects.is_empty());
+        assert_eq!(docker.projects_route(), None);
+    }
+
+    #[test]
+    fn test_blip_link_finds_every_spelling() {
+        let mut vue = get_projects()[0].clone();
+        vue.id = "vue".to_string();
+        vue.technologies = vec!["Vue".to_string()];
+        let mut vue_js = get_projects()[1].clone();
+        vue_js.id = "vue-js".to_string();
+        vue_js.technologies = vec!["Vue.js".to_string()];
+        let projects = vec![vue, vue_js];
+
+        let blips = radar_blips(get_skills(), &projects);
+        let blip = blips.iter().find(|b| b.name == "Vue.js").unwrap();
+        assert_eq!(blip.projects.len(), 2);
+
+        let Some(Route::ProjectsPage { query }) = blip.projects_route() else {
+            panic!("no projects route for {}", blip.name);
+        };
+        let linked: Vec<String> = query
+            .apply(&projects, &crate::i18n::Language::EN)
+            .into_iter()
+            .map(|p| p.id)
+            .collect();
+        assert_eq!(linked, vec!["vue", "vue-js"]);
+    }
+
+    #[test]
+    fn test_ring_comes_from_skill_level() {
+        assert_eq!(RadarRing::from_level(95), RadarRing::Adopt);
+        assert_eq!(RadarRing::from_level(85), RadarRing::Adopt);
+        assert_eq!(RadarRing::from_level(84), RadarRing::Trial);
+        assert_eq!(RadarRing::from_level(75), RadarRing::Assess);
+        assert_eq!(RadarRing::from_level(40), RadarRing::Hold);
+    }
+}
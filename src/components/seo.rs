@@ -1,49 +1,199 @@
 //! SEO Module
 //! Components for meta tags, Open Graph, and Twitter Cards
+//!
+//! `RootLayout` renders `RouteSeoHead`, which fills `SeoHead` from the
+//! `PageMeta` of the active route and adds its structured data.
 
+use crate::components::blog::get_post_by_slug;
+use crate::components::projects::get_enriched_project_by_id;
+use crate::components::structured_data::{PageSchema, StructuredData};
 use crate::config::SITE;
+use crate::i18n::{I18N_CONFIG, Language};
+use crate::routes::Route;
+use crate::utils::get_project_image_url;
+use chrono::NaiveDate;
 use dioxus::prelude::*;
 
 /// Page metadata for SEO
-#[derive(Clone, Default)]
-pub struct PageMeta {
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SeoMeta {
+    /// Page title, before the site name; `None` for the site title alone
     pub title: Option<String>,
+    /// `None` for the site description
     pub description: Option<String>,
+    /// Absolute URL of the image; `None` for the default OG image
     pub image: Option<String>,
+    /// Absolute URL; `None` for the site root
     pub url: Option<String>,
-    pub page_type: Option<String>,
+    pub og_type: OgType,
+    /// Keep the page out of search results (missing content)
+    pub noindex: bool,
+}
+
+/// Open Graph object type
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum OgType {
+    #[default]
+    Website,
+    /// Blog post, with its `article:*` properties
+    Article {
+        published: NaiveDate,
+        tags: Vec<String>,
+    },
+}
+
+impl OgType {
+    /// `og:type` value
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Website => "website",
+            Self::Article { .. } => "article",
+        }
+    }
+}
+
+/// Provides the SEO metadata of a page
+pub trait PageMeta {
+    fn page_meta(&self, lang: &Language) -> SeoMeta;
+}
+
+impl PageMeta for Route {
+    fn page_meta(&self, lang: &Language) -> SeoMeta {
+        let i18n = lang.dict();
+        let page = |title: &str, description: &str, path: &str| SeoMeta {
+            title: Some(title.to_string()),
+            description: Some(description.to_string()),
+            url: Some(absolute_url(path)),
+            ..Default::default()
+        };
+        let missing = |title: &str| SeoMeta {
+            title: Some(title.to_string()),
+            noindex: true,
+            ..Default::default()
+        };
+
+        match self {
+            Route::HomePage {} => SeoMeta {
+                url: Some(absolute_url("/")),
+                ..Default::default()
+            },
+            Route::AboutPage {} => page(i18n.about.title, i18n.about.page_summary, "/about"),
+            // Filtered listings are the same page
+            Route::ProjectsPage { .. } => page(
+                i18n.projects.page_title,
+                i18n.projects.meta_description,
+                "/projects",
+            ),
+            Route::ProjectDetailPage { slug } => match get_enriched_project_by_id(slug) {
+                Some(project) => SeoMeta {
+                    image: get_project_image_url(&project).url().map(absolute_url),
+                    ..page(
+                        &project.title,
+                        project.description.get(lang),
+                        &format!("/projects/{}", project.id),
+                    )
+                },
+                None => missing(i18n.projects.not_found_title),
+            },
+            Route::RadarPage {} => page(
+                i18n.skills.radar_title,
+                i18n.skills.radar_description,
+                "/radar",
+            ),
+            Route::BlogPage {} => page(i18n.blog.page_title, i18n.blog.meta_description, "/blog"),
+            Route::BlogPostPage { slug } => match get_post_by_slug(slug) {
+                Some(post) => SeoMeta {
+                    og_type: OgType::Article {
+                        published: post.date,
+                        tags: post.tags.clone(),
+                    },
+                    ..page(
+                        post.title.get(lang),
+                        post.excerpt.get(lang),
+                        &format!("/blog/{}", post.slug),
+                    )
+                },
+                None => missing(i18n.blog.not_found_title),
+            },
+            Route::ContactPage {} => page(
+                i18n.contact.title,
+                i18n.contact.meta_description,
+                "/contact",
+            ),
+            Route::NotFoundPage { .. } => missing(i18n.not_found.title),
+        }
+    }
+}
+
+/// Prefixes site-relative URLs ("/projects") with the site URL
+pub fn absolute_url(url: &str) -> String {
+    if url.starts_with('/') {
+        format!("{}{}", SITE.base_url, url)
+    } else {
+        url.to_string()
+    }
+}
+
+/// `@handle` from a profile URL or handle
+/// e.g., "https://twitter.com/enerbydev" -> "@enerbydev"
+pub fn twitter_handle(profile: &str) -> String {
+    let handle = profile
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or_default()
+        .trim_start_matches('@');
+    format!("@{}", handle)
+}
+
+/// `SeoHead` and JSON-LD (P13-D) for the active route
+#[component]
+pub fn RouteSeoHead() -> Element {
+    let route = use_route::<Route>();
+    let lang = I18N_CONFIG.read().language;
+
+    rsx! {
+        SeoHead { meta: route.page_meta(&lang) }
+        StructuredData { schemas: route.schemas(&lang) }
+    }
 }
 
 /// Full SEO Head component (P13-A, P13-B, P13-C)
 #[component]
-pub fn SeoHead(
-    #[props(default = None)] title: Option<String>,
-    #[props(default = None)] description: Option<String>,
-    #[props(default = None)] image: Option<String>,
-    #[props(default = None)] canonical: Option<String>,
-    #[props(default = "website".to_string())] og_type: String,
-) -> Element {
+pub fn SeoHead(#[props(default)] meta: SeoMeta) -> Element {
+    let lang = I18N_CONFIG.read().language;
+
     // Build full title (P13-A1)
-    let full_title = match title {
-        Some(ref t) => format!("{} | {}", t, SITE.title),
+    let full_title = match meta.title {
+        Some(ref t) => format!("{} | {}", t, SITE.name),
         None => SITE.title.to_string(),
     };
 
     // Use provided or default description (P13-A2)
-    let meta_description = description.unwrap_or_else(|| SITE.description.to_string());
+    let meta_description = meta
+        .description
+        .unwrap_or_else(|| SITE.description.to_string());
 
     // Default OG image
-    let og_image = image.unwrap_or_else(|| format!("{}/og-image.png", SITE.base_url));
+    let og_image = meta
+        .image
+        .unwrap_or_else(|| format!("{}/og-image.png", SITE.base_url));
 
     // Canonical URL (P13-A3)
-    let canonical_url = canonical.unwrap_or_else(|| SITE.base_url.to_string());
+    let canonical_url = meta.url.unwrap_or_else(|| SITE.base_url.to_string());
+    let robots = if meta.noindex {
+        "noindex, follow"
+    } else {
+        "index, follow"
+    };
+    let creator = twitter_handle(SITE.twitter);
 
     rsx! {
         // Basic Meta Tags (P13-A)
         document::Title { "{full_title}" }
         document::Meta { name: "description", content: "{meta_description}" }
         document::Meta { name: "author", content: "{SITE.author}" }
-        document::Meta { name: "robots", content: "index, follow" }
+        document::Meta { name: "robots", content: "{robots}" }
 
         // Canonical URL (P13-A3)
         document::Link { rel: "canonical", href: "{canonical_url}" }
@@ -53,16 +203,28 @@ pub fn SeoHead(
         document::Meta { property: "og:description", content: "{meta_description}" }
         document::Meta { property: "og:image", content: "{og_image}" }
         document::Meta { property: "og:url", content: "{canonical_url}" }
-        document::Meta { property: "og:type", content: "{og_type}" }
+        document::Meta { property: "og:type", content: "{meta.og_type.name()}" }
         document::Meta { property: "og:site_name", content: "{SITE.name}" }
-        document::Meta { property: "og:locale", content: "en_US" }
+        document::Meta { property: "og:locale", content: "{lang.og_locale()}" }
+        for other in Language::ALL.into_iter().filter(|l| *l != lang) {
+            document::Meta { property: "og:locale:alternate", content: "{other.og_locale()}" }
+        }
+
+        // Article Tags (blog posts)
+        if let OgType::Article { published, tags } = meta.og_type {
+            document::Meta { property: "article:published_time", content: "{published}" }
+            document::Meta { property: "article:author", content: "{SITE.author}" }
+            for tag in tags {
+                document::Meta { property: "article:tag", content: "{tag}" }
+            }
+        }
 
         // Twitter Cards (P13-C)
         document::Meta { name: "twitter:card", content: "summary_large_image" }
         document::Meta { name: "twitter:title", content: "{full_title}" }
         document::Meta { name: "twitter:description", content: "{meta_description}" }
         document::Meta { name: "twitter:image", content: "{og_image}" }
-        document::Meta { name: "twitter:creator", content: "@{SITE.twitter}" }
+        document::Meta { name: "twitter:creator", content: "{creator}" }
 
         // Theme Color
         document::Meta { name: "theme-color", content: "#00FFFF" }
@@ -72,69 +234,136 @@ pub fn SeoHead(
     }
 }
 
-/// Structured Data / JSON-LD for Person (P13-D)
-#[component]
-pub fn PersonSchema() -> Element {
-    let schema = format!(
-        r#"{{
-        "@context": "https://schema.org",
-        "@type": "Person",
-        "name": "{}",
-        "url": "{}",
-        "email": "{}",
-        "sameAs": [
-            "https://github.com/{}",
-            "https://linkedin.com/in/{}",
-            "https://twitter.com/{}"
-        ],
-        "jobTitle": "Full-Stack Developer",
-        "knowsAbout": ["Rust", "Web Development", "Dioxus", "WebAssembly"]
-    }}"#,
-        SITE.author, SITE.base_url, SITE.email, SITE.github, SITE.linkedin, SITE.twitter
-    );
+// ============================================================================
+// TESTS
+// ============================================================================
 
-    rsx! {
-        script {
-            r#type: "application/ld+json",
-            dangerous_inner_html: "{schema}"
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::blog::get_published_posts;
+    use crate::components::project_filters::ProjectQuery;
+    use crate::components::projects::get_projects;
+
+    fn all_routes() -> Vec<Route> {
+        let mut routes = vec![
+            Route::HomePage {},
+            Route::AboutPage {},
+            Route::ProjectsPage {
+                query: ProjectQuery::from("tech=Rust"),
+            },
+            Route::RadarPage {},
+            Route::BlogPage {},
+            Route::ContactPage {},
+        ];
+        routes.extend(
+            get_projects()
+                .into_iter()
+                .map(|p| Route::ProjectDetailPage { slug: p.id }),
+        );
+        routes.extend(
+            get_published_posts()
+                .into_iter()
+                .map(|p| Route::BlogPostPage { slug: p.slug }),
+        );
+        routes
+    }
+
+    #[test]
+    fn test_twitter_handle() {
+        assert_eq!(
+            twitter_handle("https://twitter.com/enerbydev"),
+            "@enerbydev"
+        );
+        assert_eq!(twitter_handle("https://x.com/enerbydev/"), "@enerbydev");
+        assert_eq!(twitter_handle("@enerbydev"), "@enerbydev");
+        assert_eq!(twitter_handle("enerbydev"), "@enerbydev");
+    }
+
+    #[test]
+    fn test_og_locale_follows_language() {
+        assert_eq!(Language::EN.og_locale(), "en_US");
+        assert_eq!(Language::ES.og_locale(), "es_MX");
+    }
+
+    #[test]
+    fn test_every_page_has_canonical_url_and_description() {
+        for route in all_routes() {
+            for lang in Language::ALL {
+                let meta = route.page_meta(&lang);
+                let url = meta.url.unwrap_or_default();
+                assert!(
+                    url.starts_with(SITE.base_url),
+                    "{} has no canonical URL",
+                    route
+                );
+                assert!(
+                    !url.contains('?'),
+                    "{} canonical URL keeps the query",
+                    route
+                );
+                assert!(!meta.noindex, "{} is noindex", route);
+                if route != (Route::HomePage {}) {
+                    assert!(meta.title.is_some(), "{} has no title", route);
+                    assert!(meta.description.is_some(), "{} has no description", route);
+                }
+            }
         }
     }
-}
 
-/// Breadcrumb Schema
-#[component]
-pub fn BreadcrumbSchema(items: Vec<(String, String)>) -> Element {
-    let items_json: Vec<String> = items
-        .iter()
-        .enumerate()
-        .map(|(i, (name, url))| {
-            format!(
-                r#"{{
-            "@type": "ListItem",
-            "position": {},
-            "name": "{}",
-            "item": "{}"
-        }}"#,
-                i + 1,
-                name,
-                url
-            )
-        })
-        .collect();
-
-    let schema = format!(
-        r#"{{
-        "@context": "https://schema.org",
-        "@type": "BreadcrumbList",
-        "itemListElement": [{}]
-    }}"#,
-        items_json.join(",")
-    );
+    #[test]
+    fn test_titles_follow_language() {
+        let en = Route::BlogPage {}.page_meta(&Language::EN);
+        let es = Route::BlogPage {}.page_meta(&Language::ES);
+        assert_ne!(en.title, es.title);
+        assert_ne!(en.description, es.description);
+    }
 
-    rsx! {
-        script {
-            r#type: "application/ld+json",
-            dangerous_inner_html: "{schema}"
+    #[test]
+    fn test_blog_posts_are_articles() {
+        let post = &get_published_posts()[0];
+        let meta = Route::BlogPostPage {
+            slug: post.slug.clone(),
         }
+        .page_meta(&Language::EN);
+
+        assert_eq!(meta.og_type.name(), "article");
+        assert_eq!(
+            meta.og_type,
+            OgType::Article {
+                published: post.date,
+                tags: post.tags.clone()
+            }
+        );
+        assert_eq!(meta.title.as_deref(), Some(post.title.en.as_str()));
+    }
+
+    #[test]
+    fn test_missing_pages_are_noindex() {
+        let missing = [
+            Route::ProjectDetailPage {
+                slug: "does-not-exist".to_string(),
+            },
+            Route::BlogPostPage {
+                slug: "does-not-exist".to_string(),
+            },
+            Route::NotFoundPage {
+                route: vec!["nope".to_string()],
+            },
+        ];
+        for route in missing {
+            let meta = route.page_meta(&Language::EN);
+            assert!(meta.noindex, "{} is indexable", route);
+            assert!(meta.title.is_some());
+        }
+    }
+
+    #[test]
+    fn test_absolute_url() {
+        assert_eq!(absolute_url("/radar"), format!("{}/radar", SITE.base_url));
+        assert_eq!(
+            absolute_url("https://cdn.example/a.png"),
+            "https://cdn.example/a.png"
+        );
     }
 }
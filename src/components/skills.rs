@@ -1,260 +1,100 @@
 //! Skills Section Component
-//! Technical skills showcase with categories and progress bars
+//! Technical skills showcase with categories, progress bars and the projects
+//! and posts backing each skill up
 
-use crate::components::atoms::Badge;
+use crate::components::atoms::{Badge, Button, ButtonVariant};
+use crate::components::blog::get_published_posts;
 use crate::components::layout_components::{Container, Grid, Section};
 use crate::components::molecules::{Card, ProgressBar, SectionTitle};
+use crate::components::projects::get_projects_enriched;
+use crate::domain::{Skill, SkillCategory, SkillEvidence};
+use crate::routes::Route;
+use crate::utils::clock::default_clock;
+use chrono::{DateTime, NaiveDate};
 use dioxus::prelude::*;
+use serde::Deserialize;
+use std::sync::LazyLock;
 
-/// Skill Category
-#[derive(Clone, PartialEq)]
-pub enum SkillCategory {
-    Languages,
-    Frameworks,
-    Tools,
-    Concepts,
+use crate::i18n::format::{format_percent, format_years};
+
+/// Skills data, see the header of the file for the fields
+const EMBEDDED_SKILLS: &str = include_str!("../../content/skills.toml");
+
+/// Contents of `content/skills.toml`
+#[derive(Debug, Default, Deserialize)]
+struct SkillsFile {
+    /// Project technologies that are not skills of their own
+    #[serde(default)]
+    untracked: Vec<String>,
+    #[serde(default)]
+    skill: Vec<Skill>,
 }
 
-use crate::i18n::Language;
-
-impl SkillCategory {
-    pub fn label(&self, lang: &Language) -> &'static str {
-        match lang {
-            Language::EN => match self {
-                Self::Languages => "Languages",
-                Self::Frameworks => "Frameworks & Libraries",
-                Self::Tools => "Tools & Platforms",
-                Self::Concepts => "Concepts & Practices",
-            },
-            Language::ES => match self {
-                Self::Languages => "Lenguajes",
-                Self::Frameworks => "Frameworks y Librerías",
-                Self::Tools => "Herramientas y Plataformas",
-                Self::Concepts => "Conceptos y Prácticas",
-            },
-        }
-    }
+/// `content/skills.toml`, parsed once
+static SKILLS_FILE: LazyLock<SkillsFile> = LazyLock::new(|| {
+    toml::from_str(EMBEDDED_SKILLS).unwrap_or_else(|e| {
+        eprintln!("Warning: content/skills.toml is invalid: {}", e);
+        SkillsFile::default()
+    })
+});
 
-    pub fn color(&self) -> &'static str {
-        match self {
-            Self::Languages => "cyan",
-            Self::Frameworks => "pink",
-            Self::Tools => "purple",
-            Self::Concepts => "orange",
-        }
-    }
+/// Get all skills data - Stack Real del Usuario (from `content/skills.toml`)
+pub fn get_skills() -> &'static [Skill] {
+    &SKILLS_FILE.skill
 }
 
-/// Skill data structure (P8-A2)
-#[derive(Clone, PartialEq)]
-pub struct Skill {
-    pub name: &'static str,
-    pub level: u8,
-    pub category: SkillCategory,
-    pub featured: bool,
+/// Project technologies deliberately left without a skill entry
+pub fn get_untracked_technologies() -> &'static [String] {
+    &SKILLS_FILE.untracked
 }
 
-/// Get all skills data - Stack Real del Usuario
-fn get_skills() -> Vec<Skill> {
-    vec![
-        // Languages (Stack Real: Rust & TypeScript focus)
-        Skill {
-            name: "Rust",
-            level: 90,
-            category: SkillCategory::Languages,
-            featured: true,
-        },
-        Skill {
-            name: "TypeScript",
-            level: 88,
-            category: SkillCategory::Languages,
-            featured: true,
-        },
-        Skill {
-            name: "JavaScript",
-            level: 85,
-            category: SkillCategory::Languages,
-            featured: false,
-        },
-        Skill {
-            name: "HTML/CSS",
-            level: 90,
-            category: SkillCategory::Languages,
-            featured: false,
-        },
-        Skill {
-            name: "SQL",
-            level: 80,
-            category: SkillCategory::Languages,
-            featured: false,
-        },
-        // Frameworks - Stack 1: Vue/Nuxt Ecosystem
-        Skill {
-            name: "Vue.js",
-            level: 88,
-            category: SkillCategory::Frameworks,
-            featured: true,
-        },
-        Skill {
-            name: "Nuxt 4",
-            level: 85,
-            category: SkillCategory::Frameworks,
-            featured: true,
-        },
-        Skill {
-            name: "UnJS",
-            level: 80,
-            category: SkillCategory::Frameworks,
-            featured: false,
-        },
-        Skill {
-            name: "Nitro",
-            level: 78,
-            category: SkillCategory::Frameworks,
-            featured: false,
-        },
-        Skill {
-            name: "Capacitor",
-            level: 75,
-            category: SkillCategory::Frameworks,
-            featured: false,
-        },
-        // Frameworks - Stack 2: Rust Ecosystem
-        Skill {
-            name: "Dioxus",
-            level: 88,
-            category: SkillCategory::Frameworks,
-            featured: true,
-        },
-        Skill {
-            name: "Axum",
-            level: 82,
-            category: SkillCategory::Frameworks,
-            featured: false,
-        },
-        Skill {
-            name: "SQLx",
-            level: 80,
-            category: SkillCategory::Frameworks,
-            featured: false,
-        },
-        Skill {
-            name: "Tailwind CSS",
-            level: 92,
-            category: SkillCategory::Frameworks,
-            featured: false,
-        },
-        // Tools (DevOps & Cloud)
-        Skill {
-            name: "Supabase",
-            level: 85,
-            category: SkillCategory::Tools,
-            featured: true,
-        },
-        Skill {
-            name: "Cloudflare",
-            level: 82,
-            category: SkillCategory::Tools,
-            featured: true,
-        },
-        Skill {
-            name: "Git Flow",
-            level: 90,
-            category: SkillCategory::Tools,
-            featured: false,
-        },
-        Skill {
-            name: "Docker",
-            level: 78,
-            category: SkillCategory::Tools,
-            featured: false,
-        },
-        Skill {
-            name: "Storybook",
-            level: 80,
-            category: SkillCategory::Tools,
-            featured: false,
-        },
-        Skill {
-            name: "Playwright E2E",
-            level: 78,
-            category: SkillCategory::Tools,
-            featured: false,
-        },
-        // Architecture & Methodology (Featured Expertise)
-        Skill {
-            name: "Hexagonal Arch",
-            level: 88,
-            category: SkillCategory::Concepts,
-            featured: true,
-        },
-        Skill {
-            name: "Clean + DDD",
-            level: 86,
-            category: SkillCategory::Concepts,
-            featured: true,
-        },
-        Skill {
-            name: "TDD",
-            level: 85,
-            category: SkillCategory::Concepts,
-            featured: false,
-        },
-        Skill {
-            name: "Atomic Design",
-            level: 88,
-            category: SkillCategory::Concepts,
-            featured: false,
-        },
-        Skill {
-            name: "Mobile-First",
-            level: 90,
-            category: SkillCategory::Concepts,
-            featured: false,
-        },
-        Skill {
-            name: "DaC (DTD/RD)",
-            level: 82,
-            category: SkillCategory::Concepts,
-            featured: false,
-        },
-    ]
+/// Every skill with the projects and posts mentioning it
+pub fn get_skills_with_evidence() -> Vec<(Skill, SkillEvidence)> {
+    let projects = get_projects_enriched();
+    let posts = get_published_posts();
+    get_skills()
+        .iter()
+        .cloned()
+        .map(|skill| {
+            let evidence = skill.evidence(&projects, &posts);
+            (skill, evidence)
+        })
+        .collect()
+}
+
+/// Today's date from the shared clock (years of experience)
+fn today() -> NaiveDate {
+    DateTime::from_timestamp(default_clock().now_seconds() as i64, 0)
+        .map(|now| now.date_naive())
+        .unwrap_or_default()
 }
 
 /// Skills Section (P8-A1)
 #[component]
 pub fn SkillsSection() -> Element {
-    let skills = get_skills();
-    let featured_skills: Vec<_> = skills.iter().filter(|s| s.featured).cloned().collect();
+    let skills = get_skills_with_evidence();
+    let i18n = crate::i18n::use_i18n();
+    let featured_skills: Vec<_> = skills.iter().filter(|(s, _)| s.featured).cloned().collect();
 
     // Group by category
-    let languages: Vec<_> = skills
-        .iter()
-        .filter(|s| s.category == SkillCategory::Languages)
-        .cloned()
-        .collect();
-    let frameworks: Vec<_> = skills
-        .iter()
-        .filter(|s| s.category == SkillCategory::Frameworks)
-        .cloned()
-        .collect();
-    let tools: Vec<_> = skills
-        .iter()
-        .filter(|s| s.category == SkillCategory::Tools)
-        .cloned()
-        .collect();
-    let concepts: Vec<_> = skills
-        .iter()
-        .filter(|s| s.category == SkillCategory::Concepts)
-        .cloned()
-        .collect();
+    let in_category = |category: SkillCategory| -> Vec<(Skill, SkillEvidence)> {
+        skills
+            .iter()
+            .filter(|(s, _)| s.category == category)
+            .cloned()
+            .collect()
+    };
+    let languages = in_category(SkillCategory::Languages);
+    let frameworks = in_category(SkillCategory::Frameworks);
+    let tools = in_category(SkillCategory::Tools);
+    let concepts = in_category(SkillCategory::Concepts);
 
     rsx! {
         Section { id: "skills",
             Container {
                 SectionTitle {
-                    text: "Skills & Expertise".to_string(),
-                    subtitle: "What I Work With".to_string(),
+                    text: i18n.skills.title.to_string(),
+                    subtitle: i18n.skills.subtitle.to_string(),
                     center: true
                 }
 
@@ -262,12 +102,12 @@ pub fn SkillsSection() -> Element {
                 div { class: "mb-16",
                     h3 { class: "text-xl font-bold text-center text-white mb-8",
                         span { class: "text-primary", "▸ " }
-                        "Featured Technologies"
+                        "{i18n.skills.featured_title}"
                     }
 
                     div { class: "flex flex-wrap justify-center gap-4",
-                        for skill in featured_skills.iter() {
-                            FeaturedSkillBadge { skill: skill.clone() }
+                        for (skill, evidence) in featured_skills.iter() {
+                            FeaturedSkillBadge { skill: skill.clone(), evidence: evidence.clone() }
                         }
                     }
                 }
@@ -291,6 +131,13 @@ pub fn SkillsSection() -> Element {
                         skills: concepts
                     }
                 }
+
+                // Tech radar link
+                div { class: "mt-8 text-center",
+                    Link { to: Route::RadarPage {},
+                        Button { variant: ButtonVariant::Secondary, "{i18n.skills.radar_link}" }
+                    }
+                }
             }
         }
     }
@@ -300,21 +147,25 @@ use crate::i18n::I18N_CONFIG;
 
 /// Featured Skill Badge (P8-B1)
 #[component]
-fn FeaturedSkillBadge(skill: Skill) -> Element {
-    let _color = skill.category.color();
+fn FeaturedSkillBadge(skill: Skill, evidence: SkillEvidence) -> Element {
     let lang = I18N_CONFIG.read().language;
+    let level = format_percent(skill.level as f64, &lang);
+    let years = evidence.years(today()).map(|y| format_years(y, &lang));
 
     rsx! {
         div { class: "group relative",
             div { class: "px-6 py-3 bg-bg-card border-2 border-primary/30 rounded-lg hover:border-primary hover:shadow-glow-sm transition-all cursor-default",
                 span { class: "text-lg font-bold text-white", "{skill.name}" }
-                span { class: "ml-2 text-sm text-primary", "{skill.level}%" }
+                span { class: "ml-2 text-sm text-primary", "{level}" }
             }
 
             // Hover tooltip (P8-B5)
             div { class: "absolute -bottom-2 left-1/2 -translate-x-1/2 translate-y-full opacity-0 group-hover:opacity-100 transition-opacity z-10 pointer-events-none",
                 div { class: "bg-bg-card border border-white/10 rounded px-2 py-1 text-xs text-muted whitespace-nowrap",
                     "{skill.category.label(&lang)}"
+                    if let Some(years) = years {
+                        " · {years}"
+                    }
                 }
             }
         }
@@ -323,7 +174,7 @@ fn FeaturedSkillBadge(skill: Skill) -> Element {
 
 /// Skill Category Card (P8-B1)
 #[component]
-fn SkillCategoryCard(category: SkillCategory, skills: Vec<Skill>) -> Element {
+fn SkillCategoryCard(category: SkillCategory, skills: Vec<(Skill, SkillEvidence)>) -> Element {
     let color = category.color();
     let lang = I18N_CONFIG.read().language;
 
@@ -334,14 +185,113 @@ fn SkillCategoryCard(category: SkillCategory, skills: Vec<Skill>) -> Element {
             }
 
             div { class: "space-y-4",
-                for skill in skills.iter() {
-                    ProgressBar {
-                        label: skill.name.to_string(),
-                        percentage: skill.level,
-                        color: color.to_string()
+                for (skill, evidence) in skills {
+                    SkillRow { key: "{skill.name}", skill, evidence, color: color.to_string() }
+                }
+            }
+        }
+    }
+}
+
+/// Progress bar that expands into the evidence for the skill
+#[component]
+fn SkillRow(skill: Skill, evidence: SkillEvidence, color: String) -> Element {
+    let lang = I18N_CONFIG.read().language;
+    let i18n = crate::i18n::use_i18n();
+    let years = evidence.years(today()).map(|y| format_years(y, &lang));
+
+    rsx! {
+        details { class: "group",
+            summary { class: "list-none cursor-pointer [&::-webkit-details-marker]:hidden",
+                ProgressBar {
+                    label: skill.name.clone(),
+                    percentage: skill.level,
+                    color
+                }
+            }
+
+            div { class: "-mt-2 mb-4 pl-3 border-l border-white/10 text-xs text-muted space-y-1",
+                if let Some(years) = years {
+                    p { "{i18n.skills.evidence_years}: {years}" }
+                }
+                if !evidence.projects.is_empty() {
+                    p {
+                        "{i18n.skills.evidence_projects}: "
+                        for (i , (id , title)) in evidence.projects.iter().enumerate() {
+                            if i > 0 {
+                                ", "
+                            }
+                            Link {
+                                to: Route::ProjectDetailPage { slug: id.clone() },
+                                class: "text-primary hover:underline",
+                                "{title}"
+                            }
+                        }
                     }
                 }
+                if !evidence.posts.is_empty() {
+                    p {
+                        "{i18n.skills.evidence_posts}: "
+                        for (i , (slug , title)) in evidence.posts.iter().enumerate() {
+                            if i > 0 {
+                                ", "
+                            }
+                            Link {
+                                to: Route::BlogPostPage { slug: slug.clone() },
+                                class: "text-primary hover:underline",
+                                "{title.get(&lang)}"
+                            }
+                        }
+                    }
+                }
+                if evidence.is_empty() {
+                    p { "{i18n.skills.evidence_none}" }
+                }
             }
         }
     }
 }
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skills_file_parses() {
+        let skills = get_skills();
+        assert!(!skills.is_empty());
+        assert!(skills.iter().any(|s| s.featured));
+        assert!(skills.iter().all(|s| s.level <= 100));
+
+        // Names are unique, even ignoring case and punctuation
+        for (i, skill) in skills.iter().enumerate() {
+            assert!(
+                !skills[i + 1..]
+                    .iter()
+                    .any(|other| other.matches(&skill.name)),
+                "duplicate skill {}",
+                skill.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_aliases_match_project_spellings() {
+        let skills = get_skills();
+        let find = |tech: &str| {
+            skills
+                .iter()
+                .find(|s| s.matches(tech))
+                .map(|s| s.name.as_str())
+        };
+
+        assert_eq!(find("dioxus"), Some("Dioxus"));
+        assert_eq!(find("Tailwind"), Some("Tailwind CSS"));
+        assert_eq!(find("vue"), Some("Vue.js"));
+        assert_eq!(find("COBOL"), None);
+    }
+}
@@ -0,0 +1,479 @@
+//! Structured Data Module
+//! schema.org JSON-LD for the active route, serialized with serde
+//!
+//! `RouteSeoHead` renders the `PageSchema` of the route as one
+//! `application/ld+json` script holding an `@graph` of schemas.
+
+use crate::components::blog::get_post_by_slug;
+use crate::components::projects::get_enriched_project_by_id;
+use crate::components::seo::{PageMeta, absolute_url};
+use crate::components::skills::get_skills;
+use crate::config::SITE;
+use crate::domain::{BlogPost, Project};
+use crate::i18n::Language;
+use crate::routes::Route;
+use dioxus::prelude::*;
+use serde::Serialize;
+
+/// Any schema a page can carry
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Schema {
+    Person(Person),
+    WebSite(WebSite),
+    BlogPosting(BlogPosting),
+    SoftwareSourceCode(SoftwareSourceCode),
+    BreadcrumbList(BreadcrumbList),
+}
+
+/// The site author
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "@type", rename_all = "camelCase")]
+pub struct Person {
+    name: String,
+    url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    job_title: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    same_as: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    knows_about: Vec<String>,
+}
+
+impl Person {
+    /// Full profile (home and about pages)
+    pub fn author(lang: &Language) -> Self {
+        Self {
+            email: Some(SITE.email.to_string()),
+            job_title: Some(lang.dict().hero.role.to_string()),
+            same_as: [SITE.github, SITE.linkedin, SITE.twitter]
+                .map(String::from)
+                .to_vec(),
+            knows_about: get_skills()
+                .iter()
+                .filter(|s| s.featured)
+                .map(|s| s.name.clone())
+                .collect(),
+            ..Self::reference()
+        }
+    }
+
+    /// Name and URL only, for `author` fields
+    pub fn reference() -> Self {
+        Self {
+            name: SITE.author.to_string(),
+            url: SITE.base_url.to_string(),
+            email: None,
+            job_title: None,
+            same_as: Vec::new(),
+            knows_about: Vec::new(),
+        }
+    }
+}
+
+/// The site, with the project search as its `SearchAction`
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "@type", rename_all = "camelCase")]
+pub struct WebSite {
+    name: String,
+    url: String,
+    description: String,
+    in_language: Vec<String>,
+    author: Person,
+    potential_action: SearchAction,
+}
+
+impl WebSite {
+    pub fn new() -> Self {
+        Self {
+            name: SITE.name.to_string(),
+            url: SITE.base_url.to_string(),
+            description: SITE.description.to_string(),
+            in_language: Language::ALL.iter().map(|l| l.to_string()).collect(),
+            author: Person::reference(),
+            potential_action: SearchAction {
+                // Text filter of the projects page (`ProjectQuery::q`)
+                target: absolute_url("/projects?q={search_term_string}"),
+                query_input: "required name=search_term_string".to_string(),
+            },
+        }
+    }
+}
+
+impl Default for WebSite {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "@type", rename_all = "kebab-case")]
+pub struct SearchAction {
+    target: String,
+    query_input: String,
+}
+
+/// A blog post
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "@type", rename_all = "camelCase")]
+pub struct BlogPosting {
+    headline: String,
+    description: String,
+    url: String,
+    date_published: String,
+    in_language: String,
+    author: Person,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    keywords: Vec<String>,
+    /// Minutes, as an ISO 8601 duration
+    time_required: String,
+}
+
+impl BlogPosting {
+    pub fn new(post: &BlogPost, lang: &Language) -> Self {
+        Self {
+            headline: post.title.get(lang).to_string(),
+            description: post.excerpt.get(lang).to_string(),
+            url: absolute_url(&format!("/blog/{}", post.slug)),
+            date_published: post.date.to_string(),
+            in_language: lang.to_string(),
+            author: Person::reference(),
+            keywords: post.tags.clone(),
+            time_required: format!("PT{}M", post.read_time),
+        }
+    }
+}
+
+/// A project and its repository
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "@type", rename_all = "camelCase")]
+pub struct SoftwareSourceCode {
+    name: String,
+    description: String,
+    url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code_repository: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    programming_language: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    keywords: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    license: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_created: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_modified: Option<String>,
+    author: Person,
+}
+
+impl SoftwareSourceCode {
+    pub fn new(project: &Project, lang: &Language) -> Self {
+        Self {
+            name: project.title.clone(),
+            description: project.description.get(lang).to_string(),
+            url: absolute_url(&format!("/projects/{}", project.id)),
+            code_repository: project.github_url.clone(),
+            // Bytes of code per language, largest first
+            programming_language: {
+                let mut languages: Vec<_> = project.languages.iter().collect();
+                languages.sort_by(|a, b| b.1.cmp(a.1));
+                languages.into_iter().map(|(l, _)| l.clone()).collect()
+            },
+            keywords: project.technologies.clone(),
+            license: project.license.clone(),
+            date_created: project.started.map(|d| d.to_string()),
+            date_modified: project.last_push.map(|d| d.to_string()),
+            author: Person::reference(),
+        }
+    }
+}
+
+/// Path from the home page to the current page
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "@type", rename_all = "camelCase")]
+pub struct BreadcrumbList {
+    item_list_element: Vec<ListItem>,
+}
+
+impl BreadcrumbList {
+    /// From `(name, absolute URL)` pairs, home first
+    pub fn new(items: Vec<(String, String)>) -> Self {
+        Self {
+            item_list_element: items
+                .into_iter()
+                .enumerate()
+                .map(|(i, (name, item))| ListItem {
+                    position: i + 1,
+                    name,
+                    item,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "@type")]
+pub struct ListItem {
+    position: usize,
+    name: String,
+    item: String,
+}
+
+/// JSON-LD document for the schemas of a page
+pub fn json_ld(schemas: &[Schema]) -> String {
+    #[derive(Serialize)]
+    struct Document<'a> {
+        #[serde(rename = "@context")]
+        context: &'static str,
+        #[serde(rename = "@graph")]
+        graph: &'a [Schema],
+    }
+
+    let document = Document {
+        context: "https://schema.org",
+        graph: schemas,
+    };
+    serde_json::to_string(&document)
+        .unwrap_or_else(|e| {
+            eprintln!("Warning: could not serialize JSON-LD: {}", e);
+            String::new()
+        })
+        // A "</script>" inside a string would end the script element
+        .replace("</", "<\\/")
+}
+
+/// Provides the structured data of a page
+pub trait PageSchema {
+    fn schemas(&self, lang: &Language) -> Vec<Schema>;
+
+    /// `(name, absolute URL)` from the home page down to this page
+    fn breadcrumbs(&self, lang: &Language) -> Vec<(String, String)>;
+}
+
+impl Route {
+    /// The page above this one; `None` for the home page and missing pages
+    pub fn parent(&self) -> Option<Route> {
+        match self {
+            Route::HomePage {} | Route::NotFoundPage { .. } => None,
+            Route::ProjectDetailPage { .. } => Some(Route::ProjectsPage {
+                query: Default::default(),
+            }),
+            Route::BlogPostPage { .. } => Some(Route::BlogPage {}),
+            _ => Some(Route::HomePage {}),
+        }
+    }
+}
+
+impl PageSchema for Route {
+    fn schemas(&self, lang: &Language) -> Vec<Schema> {
+        let mut schemas = match self {
+            Route::HomePage {} => vec![
+                Schema::WebSite(WebSite::new()),
+                Schema::Person(Person::author(lang)),
+            ],
+            Route::AboutPage {} => vec![Schema::Person(Person::author(lang))],
+            Route::ProjectDetailPage { slug } => get_enriched_project_by_id(slug)
+                .map(|p| Schema::SoftwareSourceCode(SoftwareSourceCode::new(&p, lang)))
+                .into_iter()
+                .collect(),
+            Route::BlogPostPage { slug } => get_post_by_slug(slug)
+                .map(|p| Schema::BlogPosting(BlogPosting::new(&p, lang)))
+                .into_iter()
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        let breadcrumbs = self.breadcrumbs(lang);
+        if breadcrumbs.len() > 1 {
+            schemas.push(Schema::BreadcrumbList(BreadcrumbList::new(breadcrumbs)));
+        }
+        schemas
+    }
+
+    fn breadcrumbs(&self, lang: &Language) -> Vec<(String, String)> {
+        let meta = self.page_meta(lang);
+        // Missing content has no place in the hierarchy
+        if meta.noindex {
+            return Vec::new();
+        }
+
+        let mut items = self
+            .parent()
+            .map(|parent| parent.breadcrumbs(lang))
+            .unwrap_or_default();
+        let name = match self {
+            Route::HomePage {} => lang.dict().nav.home.to_string(),
+            _ => meta.title.unwrap_or_default(),
+        };
+        items.push((name, meta.url.unwrap_or_else(|| absolute_url("/"))));
+        items
+    }
+}
+
+/// JSON-LD script for a page's schemas
+#[component]
+pub fn StructuredData(schemas: Vec<Schema>) -> Element {
+    if schemas.is_empty() {
+        return rsx! {};
+    }
+    let json = json_ld(&schemas);
+
+    rsx! {
+        script {
+            r#type: "application/ld+json",
+            dangerous_inner_html: "{json}"
+        }
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::blog::get_published_posts;
+    use crate::components::projects::get_projects;
+    use serde_json::Value;
+
+    fn parse(schemas: &[Schema]) -> Value {
+        serde_json::from_str(&json_ld(schemas)).expect("JSON-LD is valid JSON")
+    }
+
+    fn graph(route: Route, lang: &Language) -> Vec<Value> {
+        let document = parse(&route.schemas(lang));
+        assert_eq!(document["@context"], "https://schema.org");
+        document["@graph"].as_array().cloned().unwrap_or_default()
+    }
+
+    fn types(graph: &[Value]) -> Vec<&str> {
+        graph.iter().filter_map(|s| s["@type"].as_str()).collect()
+    }
+
+    #[test]
+    fn test_home_has_website_and_person() {
+        let graph = graph(Route::HomePage {}, &Language::EN);
+        assert_eq!(types(&graph), vec!["WebSite", "Person"]);
+
+        let website = &graph[0];
+        assert_eq!(website["potentialAction"]["@type"], "SearchAction");
+        assert_eq!(
+            website["potentialAction"]["target"],
+            format!("{}/projects?q={{search_term_string}}", SITE.base_url)
+        );
+        assert_eq!(
+            website["potentialAction"]["query-input"],
+            "required name=search_term_string"
+        );
+    }
+
+    #[test]
+    fn test_person_same_as_urls_are_not_prefixed_twice() {
+        let graph = graph(Route::AboutPage {}, &Language::EN);
+        let same_as = graph[0]["sameAs"].as_array().unwrap();
+
+        assert!(same_as.contains(&Value::from(SITE.github)));
+        assert!(same_as.contains(&Value::from(SITE.twitter)));
+        for url in same_as {
+            let url = url.as_str().unwrap();
+            assert_eq!(url.matches("https://").count(), 1, "{}", url);
+        }
+        assert!(!graph[0]["knowsAbout"].as_array().unwrap().is_empty());
+        assert_eq!(graph[0]["jobTitle"], Language::EN.dict().hero.role);
+    }
+
+    #[test]
+    fn test_quotes_and_script_tags_stay_in_strings() {
+        let breadcrumbs = BreadcrumbList::new(vec![(
+            r#"Say "hi" </script><script>alert(1)"#.to_string(),
+            absolute_url("/"),
+        )]);
+        let json = json_ld(&[Schema::BreadcrumbList(breadcrumbs)]);
+
+        assert!(!json.contains("</script>"));
+        let document: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            document["@graph"][0]["itemListElement"][0]["name"],
+            r#"Say "hi" </script><script>alert(1)"#
+        );
+    }
+
+    #[test]
+    fn test_every_blog_post_has_blog_posting() {
+        for post in get_published_posts() {
+            for lang in Language::ALL {
+                let route = Route::BlogPostPage {
+                    slug: post.slug.clone(),
+                };
+                let graph = graph(route, &lang);
+                assert_eq!(types(&graph), vec!["BlogPosting", "BreadcrumbList"]);
+                assert_eq!(graph[0]["headline"], post.title.get(&lang));
+                assert_eq!(graph[0]["datePublished"], post.date.to_string());
+                assert_eq!(graph[0]["inLanguage"], lang.to_string());
+                assert_eq!(graph[0]["author"]["@type"], "Person");
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_project_has_software_source_code() {
+        for project in get_projects() {
+            let route = Route::ProjectDetailPage {
+                slug: project.id.clone(),
+            };
+            let graph = graph(route, &Language::ES);
+            assert_eq!(types(&graph), vec!["SoftwareSourceCode", "BreadcrumbList"]);
+            assert_eq!(graph[0]["name"], project.title);
+            assert_eq!(
+                graph[0]["url"],
+                format!("{}/projects/{}", SITE.base_url, project.id)
+            );
+        }
+    }
+
+    #[test]
+    fn test_breadcrumbs_follow_route_hierarchy() {
+        let post = &get_published_posts()[0];
+        let route = Route::BlogPostPage {
+            slug: post.slug.clone(),
+        };
+        let crumbs = route.breadcrumbs(&Language::ES);
+        let names: Vec<&str> = crumbs.iter().map(|(n, _)| n.as_str()).collect();
+        let i18n = Language::ES.dict();
+
+        assert_eq!(
+            names,
+            vec![i18n.nav.home, i18n.blog.page_title, post.title.es.as_str()]
+        );
+        assert_eq!(crumbs[0].1, format!("{}/", SITE.base_url));
+        assert_eq!(crumbs[1].1, format!("{}/blog", SITE.base_url));
+
+        let graph = graph(route, &Language::ES);
+        let items = graph[1]["itemListElement"].as_array().unwrap();
+        let positions: Vec<u64> = items
+            .iter()
+            .map(|i| i["position"].as_u64().unwrap())
+            .collect();
+        assert_eq!(positions, vec![1, 2, 3]);
+        assert!(items.iter().all(|i| i["@type"] == "ListItem"));
+    }
+
+    #[test]
+    fn test_missing_pages_have_no_schema() {
+        let missing = [
+            Route::ProjectDetailPage {
+                slug: "does-not-exist".to_string(),
+            },
+            Route::NotFoundPage {
+                route: vec!["nope".to_string()],
+            },
+        ];
+        for route in missing {
+            assert!(route.schemas(&Language::EN).is_empty(), "{}", route);
+        }
+    }
+}
@@ -27,6 +27,46 @@ pub const SITE: SiteConfig = SiteConfig {
     base_url: "https://enerby.dev",
 };
 
+/// Remote service used to screenshot project demo sites
+///
+/// A committed `assets/screenshots/<project-id>.webp` always wins over the
+/// remote service (see `utils::screenshots`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScreenshotService {
+    /// thum.io free tier (no API key)
+    ThumbIo,
+    /// Microlink screenshot API
+    Microlink,
+    /// Own screenshot endpoint, called as `{base_url}?url=..&width=..&height=..`
+    SelfHosted { base_url: &'static str },
+}
+
+/// Screenshot service for demo previews
+pub const SCREENSHOT_SERVICE: ScreenshotService = ScreenshotService::ThumbIo;
+
+/// Where the contact form delivers messages (see `utils::contact`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContactService {
+    /// Formspree form (`https://formspree.io/f/<form id>`) or a compatible endpoint
+    Formspree { endpoint: &'static str },
+    /// Own endpoint receiving the form as JSON, e.g. a Cloudflare Worker URL
+    Webhook { url: &'static str },
+    /// No backend: opens the visitor's mail client addressed to `to`
+    Mailto { to: &'static str },
+}
+
+/// Contact form delivery; switch to `Formspree` or `Webhook` once one is set up
+pub const CONTACT_SERVICE: ContactService = ContactService::Mailto { to: SITE.email };
+
+/// Zero bits of the hashcash stamp sent with contact messages, if any
+///
+/// Only useful with a `Webhook` that checks `ContactForm::proof` through
+/// `utils::spam::verify_stamp`; each bit doubles the work in the browser.
+/// Minting takes ~2^bits SHA-1 hashes on the sender's device: 16 bits is
+/// ~65k hashes (barely noticeable), 20 bits ~1M (can take seconds on a slow phone).
+/// The page stays responsive meanwhile, but the form shows as sending.
+pub const CONTACT_PROOF_OF_WORK: Option<u32> = None;
+
 /// Navigation links
 pub struct NavLink {
     pub label: &'static str,
@@ -1,68 +1,76 @@
 //! Data Module - Types and structures for content
-//! Defines Project, BlogPost, Skill and other data types
+//! Defines contact form and timeline types
+//! (projects, blog posts and skills live in `crate::domain`)
 
-use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-/// Project data structure
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct Project {
-    pub slug: String,
-    pub title: String,
-    pub description: String,
-    pub long_description: Option<String>,
-    pub image: Option<String>,
-    pub technologies: Vec<String>,
-    pub github_url: Option<String>,
-    pub live_url: Option<String>,
-    pub featured: bool,
-    pub date: String,
-    pub status: ProjectStatus,
+/// Contact form data (sent as JSON by `utils::contact::Webhook`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ContactForm {
+    pub name: String,
+    pub email: String,
+    pub subject: String,
+    pub message: String,
+    /// Honeypot: hidden from people, so only bots fill it in (`utils::spam`)
+    #[serde(skip)]
+    pub website: String,
+    /// Hashcash stamp for the email, when `config::CONTACT_PROOF_OF_WORK` is set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proof: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub enum ProjectStatus {
-    Completed,
-    InProgress,
-    Planned,
-}
+impl ContactForm {
+    /// Value typed into `field`
+    pub fn value(&self, field: ContactField) -> &str {
+        match field {
+            ContactField::Name => &self.name,
+            ContactField::Email => &self.email,
+            ContactField::Subject => &self.subject,
+            ContactField::Message => &self.message,
+        }
+    }
 
-/// Blog post data structure
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct BlogPost {
-    pub slug: String,
-    pub title: String,
-    pub excerpt: String,
-    pub content: String,
-    pub date: String,
-    pub tags: Vec<String>,
-    pub reading_time: u32,
-    pub published: bool,
-}
+    pub fn value_mut(&mut self, field: ContactField) -> &mut String {
+        match field {
+            ContactField::Name => &mut self.name,
+            ContactField::Email => &mut self.email,
+            ContactField::Subject => &mut self.subject,
+            ContactField::Message => &mut self.message,
+        }
+    }
 
-/// Skill category
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct SkillCategory {
-    pub name: String,
-    pub icon: String,
-    pub skills: Vec<Skill>,
+    /// Copy with surrounding whitespace removed from every field, as sent
+    pub fn trimmed(&self) -> Self {
+        let mut form = self.clone();
+        for field in ContactField::ALL {
+            *form.value_mut(field) = self.value(field).trim().to_string();
+        }
+        form
+    }
 }
 
-/// Individual skill
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct Skill {
-    pub name: String,
-    pub level: u8, // 1-100
-    pub years: Option<f32>,
+/// Visible contact form fields (rules in `utils::validation`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContactField {
+    Name,
+    Email,
+    Subject,
+    Message,
 }
 
-/// Contact form data
-#[derive(Debug, Clone, Default)]
-pub struct ContactForm {
-    pub name: String,
-    pub email: String,
-    pub subject: String,
-    pub message: String,
+impl ContactField {
+    /// Every field, in form order
+    pub const ALL: [Self; 4] = [Self::Name, Self::Email, Self::Subject, Self::Message];
+
+    /// DOM id of the field's input; its error is `{id}-error`
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::Name => "contact-name",
+            Self::Email => "contact-email",
+            Self::Subject => "contact-subject",
+            Self::Message => "contact-message",
+        }
+    }
 }
 
 /// Timeline item for About page
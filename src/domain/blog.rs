@@ -0,0 +1,32 @@
+//! Blog posts
+
+use chrono::NaiveDate;
+
+use super::LocalizedText;
+
+/// Post Status (P10-A4)
+#[derive(Clone, PartialEq, Eq, Copy, Debug)]
+pub enum PostStatus {
+    Published,
+    Draft,
+}
+
+/// A blog post with its English and Spanish variants
+///
+/// Built by `components::blog::get_blog_posts` from the markdown files; the
+/// English variant is the source of truth for the slug, date and tags.
+#[derive(Clone, PartialEq, Debug)]
+pub struct BlogPost {
+    /// Canonical slug from the `_en.md` frontmatter
+    pub slug: String,
+    pub title: LocalizedText,
+    pub excerpt: LocalizedText,
+    /// Rendered HTML
+    pub content: LocalizedText,
+    pub date: NaiveDate,
+    /// Minutes
+    pub read_time: u8,
+    pub tags: Vec<String>,
+    pub status: PostStatus,
+    pub featured: bool,
+}
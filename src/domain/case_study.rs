@@ -0,0 +1,86 @@
+//! Project case studies
+
+use crate::i18n::Language;
+
+/// The parts of a case study, in reading order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CaseStudySection {
+    /// The problem the project had to solve
+    Challenge,
+    /// How it was built and why
+    Architecture,
+    /// What came out of it
+    Outcome,
+    /// Headline numbers, shown as highlight cards
+    Metrics,
+}
+
+impl CaseStudySection {
+    /// Every section, in reading order
+    pub const ALL: [Self; 4] = [
+        Self::Challenge,
+        Self::Architecture,
+        Self::Outcome,
+        Self::Metrics,
+    ];
+
+    /// `##` headings that start the section, in any site language
+    pub fn headings(&self) -> &'static [&'static str] {
+        match self {
+            Self::Challenge => &["Challenge", "Reto", "Desafío"],
+            Self::Architecture => &["Architecture", "Arquitectura"],
+            Self::Outcome => &["Outcome", "Results", "Resultado", "Resultados"],
+            Self::Metrics => &["Metrics", "Métricas"],
+        }
+    }
+
+    /// Section for a `##` heading (case-insensitive), if it starts one
+    pub fn from_heading(heading: &str) -> Option<Self> {
+        let heading = heading.trim().to_lowercase();
+        Self::ALL.into_iter().find(|section| {
+            section
+                .headings()
+                .iter()
+                .any(|h| h.to_lowercase() == heading)
+        })
+    }
+
+    pub fn label(&self, lang: &Language) -> &'static str {
+        let i18n = lang.dict().projects;
+        match self {
+            Self::Challenge => i18n.case_challenge,
+            Self::Architecture => i18n.case_architecture,
+            Self::Outcome => i18n.case_outcome,
+            Self::Metrics => i18n.case_metrics,
+        }
+    }
+}
+
+/// A headline number, e.g. "WASM bundle" → "−40%"
+#[derive(Clone, Debug, PartialEq)]
+pub struct Metric {
+    pub label: String,
+    pub value: String,
+}
+
+/// Problem → approach → results write-up of a project in one language
+///
+/// Parsed from `content/projects/<id>.<lang>.md` by `utils::case_studies`;
+/// the prose sections hold rendered HTML.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CaseStudy {
+    pub challenge: Option<String>,
+    pub architecture: Option<String>,
+    pub outcome: Option<String>,
+    pub metrics: Vec<Metric>,
+}
+
+impl CaseStudy {
+    /// True when the markdown had none of the sections
+    pub fn is_empty(&self) -> bool {
+        self.challenge.is_none()
+            && self.architecture.is_none()
+            && self.outcome.is_none()
+            && self.metrics.is_empty()
+    }
+}
@@ -0,0 +1,27 @@
+//! Domain Model
+//!
+//! The content types every component consumes:
+//!
+//! - `Project`: a portfolio entry, hand-written or discovered from a tagged
+//!   repository, plus what its forge reports
+//! - `BlogPost`: a markdown post with its English and Spanish variants
+//! - `CaseStudy`: the long-form challenge, architecture, outcome and metrics
+//!   of a project, per language
+//! - `Skill`: a skill from `content/skills.toml`, and the projects and posts
+//!   backing it up (`SkillEvidence`)
+//! - `LocalizedText`: text in every site language
+//!
+//! A project's lifecycle (`ProjectStatus`) and whether it is featured are
+//! separate: featuring is an editorial choice, the status is a fact.
+
+pub mod blog;
+pub mod case_study;
+pub mod project;
+pub mod skill;
+pub mod text;
+
+pub use blog::{BlogPost, PostStatus};
+pub use case_study::{CaseStudy, CaseStudySection, Metric};
+pub use project::{DemoUrlSource, Project, ProjectStatus};
+pub use skill::{RadarRing, Skill, SkillCategory, SkillEvidence};
+pub use text::LocalizedText;
@@ -0,0 +1,129 @@
+//! Portfolio projects
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+
+use super::LocalizedText;
+use crate::i18n::Language;
+
+/// Where a project is in its lifecycle
+///
+/// Independent of `Project::featured`: an archived project can still be featured.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProjectStatus {
+    /// Under development or maintained
+    #[default]
+    Active,
+    /// Done; no further work planned
+    Completed,
+    /// No longer maintained (archived repository)
+    Archived,
+}
+
+impl ProjectStatus {
+    /// Every status, in lifecycle order
+    pub const ALL: [Self; 3] = [Self::Active, Self::Completed, Self::Archived];
+
+    /// Name used in URLs (`?status=archived`)
+    pub fn slug(&self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Completed => "completed",
+            Self::Archived => "archived",
+        }
+    }
+
+    pub fn label(&self, lang: &Language) -> &'static str {
+        let i18n = lang.dict().projects;
+        match self {
+            Self::Active => i18n.status_active,
+            Self::Completed => i18n.status_completed,
+            Self::Archived => i18n.status_archived,
+        }
+    }
+
+    /// `Badge` color
+    pub fn color(&self) -> &'static str {
+        match self {
+            Self::Active => "purple",
+            Self::Completed => "pink",
+            Self::Archived => "orange",
+        }
+    }
+}
+
+impl FromStr for ProjectStatus {
+    type Err = String;
+
+    /// Parses a `slug` (case-insensitive)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|status| status.slug().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| format!("unknown project status: {}", s))
+    }
+}
+
+/// Indicates where the demo_url came from
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum DemoUrlSource {
+    /// Manually set in project definition
+    Manual,
+    /// Auto-detected from GitHub repository homepage field
+    GitHub,
+    /// No demo URL available
+    #[default]
+    None,
+}
+
+/// A portfolio project
+///
+/// Hand-written entries (`components::projects::get_projects`) fill in the
+/// authored fields; repository data (`apply_repo_info`) adds the repo stats,
+/// languages, license, the start date and, unless set by hand, the demo URL.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Project {
+    pub id: String,
+    pub title: String,
+    /// One or two sentences for cards
+    pub description: LocalizedText,
+    /// Full text for the detail page
+    pub long_description: LocalizedText,
+    pub technologies: Vec<String>,
+    pub status: ProjectStatus,
+    /// Listed first and highlighted
+    pub featured: bool,
+    /// Day work started (repository creation date unless set by hand)
+    pub started: Option<NaiveDate>,
+    /// Repository URL on GitHub, GitLab, Codeberg or Gitea
+    pub github_url: Option<String>,
+    /// Demo URL - either manual or auto-detected from GitHub homepage
+    pub demo_url: Option<String>,
+    /// Source of demo_url for debugging/UI
+    pub demo_url_source: DemoUrlSource,
+    pub image_override: Option<String>,
+    /// Emoji shown when there is no image
+    pub image_fallback: String,
+    /// Stargazers count (0 when unknown)
+    pub stars: u32,
+    /// Bytes of code per language (empty when unknown)
+    pub languages: BTreeMap<String, u64>,
+    /// Forks count (0 when unknown)
+    pub forks: u32,
+    /// Open issues count (0 when unknown)
+    pub open_issues: u32,
+    /// Repository topics, without the portfolio marker
+    pub topics: Vec<String>,
+    /// Primary language as reported by the forge
+    pub language: Option<String>,
+    /// SPDX identifier (or name) of the repository license
+    pub license: Option<String>,
+    /// Day of the last push to the repository (GitHub only)
+    pub last_push: Option<NaiveDate>,
+    /// Repository data has been applied, so zero counts are real
+    pub repo_synced: bool,
+    /// Extra screenshots for the detail page, after the main image
+    pub gallery: Vec<String>,
+}
@@ -0,0 +1,178 @@
+//! Skills and the evidence behind them
+
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+use super::{BlogPost, LocalizedText, Project};
+use crate::i18n::Language;
+use crate::utils::slugify;
+
+/// Skill Category
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SkillCategory {
+    Languages,
+    Frameworks,
+    Tools,
+    Concepts,
+}
+
+impl SkillCategory {
+    /// Every category, in display order (also the tech radar quadrants)
+    pub const ALL: [Self; 4] = [
+        Self::Languages,
+        Self::Frameworks,
+        Self::Tools,
+        Self::Concepts,
+    ];
+
+    pub fn label(&self, lang: &Language) -> &'static str {
+        match lang {
+            Language::EN => match self {
+                Self::Languages => "Languages",
+                Self::Frameworks => "Frameworks & Libraries",
+                Self::Tools => "Tools & Platforms",
+                Self::Concepts => "Concepts & Practices",
+            },
+            Language::ES => match self {
+                Self::Languages => "Lenguajes",
+                Self::Frameworks => "Frameworks y Librerías",
+                Self::Tools => "Herramientas y Plataformas",
+                Self::Concepts => "Conceptos y Prácticas",
+            },
+        }
+    }
+
+    pub fn color(&self) -> &'static str {
+        match self {
+            Self::Languages => "cyan",
+            Self::Frameworks => "pink",
+            Self::Tools => "purple",
+            Self::Concepts => "orange",
+        }
+    }
+}
+
+/// Tech radar ring: how much the technology is relied on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RadarRing {
+    /// Default choice, used in production
+    Adopt,
+    /// Used on real projects, still building experience
+    Trial,
+    /// Explored, worth keeping an eye on
+    Assess,
+    /// Not picked for new work
+    Hold,
+}
+
+impl RadarRing {
+    /// Every ring, from the center out
+    pub const ALL: [Self; 4] = [Self::Adopt, Self::Trial, Self::Assess, Self::Hold];
+
+    /// Ring for a skill level (0-100)
+    pub fn from_level(level: u8) -> Self {
+        match level {
+            85.. => Self::Adopt,
+            80..85 => Self::Trial,
+            70..80 => Self::Assess,
+            _ => Self::Hold,
+        }
+    }
+
+    pub fn label(&self, lang: &Language) -> &'static str {
+        let i18n = lang.dict().skills;
+        match self {
+            Self::Adopt => i18n.ring_adopt,
+            Self::Trial => i18n.ring_trial,
+            Self::Assess => i18n.ring_assess,
+            Self::Hold => i18n.ring_hold,
+        }
+    }
+}
+
+/// A skill from `content/skills.toml`
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Skill {
+    pub name: String,
+    pub category: SkillCategory,
+    /// 0-100, chosen by hand
+    pub level: u8,
+    #[serde(default)]
+    pub featured: bool,
+    /// Day of first use, when known
+    #[serde(default)]
+    pub since: Option<NaiveDate>,
+    /// Other spellings in project technologies and post tags
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+impl Skill {
+    /// Tech radar ring, from the skill level
+    pub fn ring(&self) -> RadarRing {
+        RadarRing::from_level(self.level)
+    }
+
+    /// Whether a technology or tag names this skill (ignoring case and punctuation)
+    pub fn matches(&self, technology: &str) -> bool {
+        let technology = slugify(technology);
+        std::iter::once(&self.name)
+            .chain(&self.aliases)
+            .any(|name| slugify(name) == technology)
+    }
+
+    /// Projects and posts mentioning the skill, and the earliest known use
+    pub fn evidence(&self, projects: &[Project], posts: &[BlogPost]) -> SkillEvidence {
+        let projects: Vec<&Project> = projects
+            .iter()
+            .filter(|p| p.technologies.iter().any(|t| self.matches(t)))
+            .collect();
+        let posts: Vec<&BlogPost> = posts
+            .iter()
+            .filter(|p| p.tags.iter().any(|t| self.matches(t)))
+            .collect();
+
+        let first_used = self
+            .since
+            .into_iter()
+            .chain(projects.iter().filter_map(|p| p.started))
+            .chain(posts.iter().map(|p| p.date))
+            .min();
+
+        SkillEvidence {
+            first_used,
+            projects: projects
+                .iter()
+                .map(|p| (p.id.clone(), p.title.clone()))
+                .collect(),
+            posts: posts
+                .iter()
+                .map(|p| (p.slug.clone(), p.title.clone()))
+                .collect(),
+        }
+    }
+}
+
+/// What backs a skill up
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SkillEvidence {
+    /// `Skill::since`, or the earliest project start or post date mentioning it
+    pub first_used: Option<NaiveDate>,
+    /// Id and title of the projects using it
+    pub projects: Vec<(String, String)>,
+    /// Slug and title of the posts tagged with it
+    pub posts: Vec<(String, LocalizedText)>,
+}
+
+impl SkillEvidence {
+    /// Whole years of use up to `today`, if the first use is known
+    pub fn years(&self, today: NaiveDate) -> Option<u32> {
+        self.first_used
+            .map(|first| today.years_since(first).unwrap_or(0))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.projects.is_empty() && self.posts.is_empty()
+    }
+}
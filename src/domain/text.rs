@@ -0,0 +1,41 @@
+//! Localized text
+
+use crate::i18n::Language;
+
+/// Text in every site language
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LocalizedText {
+    pub en: String,
+    pub es: String,
+}
+
+impl LocalizedText {
+    pub fn new(en: impl Into<String>, es: impl Into<String>) -> Self {
+        Self {
+            en: en.into(),
+            es: es.into(),
+        }
+    }
+
+    /// Same text in every language (e.g. repository descriptions, which are English)
+    pub fn untranslated(text: impl Into<String>) -> Self {
+        let text = text.into();
+        Self {
+            en: text.clone(),
+            es: text,
+        }
+    }
+
+    /// Text for a language
+    pub fn get(&self, lang: &Language) -> &str {
+        match lang {
+            Language::EN => &self.en,
+            Language::ES => &self.es,
+        }
+    }
+
+    /// True when no language is left empty
+    pub fn is_complete(&self) -> bool {
+        !self.en.trim().is_empty() && !self.es.trim().is_empty()
+    }
+}
@@ -9,6 +9,11 @@ pub struct Dictionary {
     pub about: AboutConfig,
     pub footer: FooterConfig,
     pub projects: ProjectsConfig,
+    pub skills: SkillsConfig,
+    pub blog: BlogConfig,
+    pub contact: ContactConfig,
+    pub errors: ErrorsConfig,
+    pub not_found: NotFoundConfig,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -30,6 +35,7 @@ pub struct HeroConfig {
     pub cta_projects: &'static str,
     pub cta_contact: &'static str,
     pub scroll_indicator: &'static str,
+    pub role: &'static str,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -44,6 +50,12 @@ pub struct AboutConfig {
     pub stat_projects: &'static str,
     pub stat_technologies: &'static str,
     pub stat_loc: &'static str,
+    pub stat_loc_tooltip_start: &'static str, // "Lines of code across "
+    pub stat_loc_tooltip_end: &'static str,   // " repositories"
+    pub stat_projects_tooltip: &'static str,
+    pub languages_title: &'static str,
+    pub languages_other: &'static str,
+    pub page_summary: &'static str,
     pub timeline_title: &'static str,
     pub timeline: &'static [TimelineItemData],
 }
@@ -74,4 +86,153 @@ pub struct ProjectsConfig {
     pub btn_demo_github: &'static str, // "GitHub Demo"
     pub badge_auto: &'static str, // "Auto"
     pub tooltip_auto: &'static str, 
+    pub stars_title: &'static str,
+    pub languages_title: &'static str,
+    pub languages_other: &'static str,
+    pub history_title: &'static str,
+    pub history_since: &'static str, // "since 2026-01-01"
+    pub history_stars: &'static str,
+    pub history_forks: &'static str,
+    pub history_issues: &'static str,
+    pub stats_title: &'static str,
+    pub stat_language: &'static str,
+    pub stat_license: &'static str,
+    pub stat_last_push: &'static str,
+    pub stat_started: &'static str,
+    pub topics_title: &'static str,
+    pub gallery_title: &'static str,
+    pub gallery_alt: &'static str, // "Screenshot of"
+    pub related_title: &'static str,
+    pub case_challenge: &'static str,
+    pub case_architecture: &'static str,
+    pub case_outcome: &'static str,
+    pub case_metrics: &'static str,
+    pub page_title: &'static str,
+    pub page_subtitle: &'static str,
+    pub status_featured: &'static str,
+    pub status_active: &'static str,
+    pub status_completed: &'static str,
+    pub status_archived: &'static str,
+    pub filter_search: &'static str,
+    pub filter_search_placeholder: &'static str,
+    pub filter_technology: &'static str,
+    pub filter_status: &'static str,
+    pub filter_all: &'static str,
+    pub filter_clear: &'static str,
+    pub filter_showing: &'static str, // "Showing 3 of 7"
+    pub filter_of: &'static str,
+    pub filter_empty: &'static str,
+    pub sort_label: &'static str,
+    pub sort_featured: &'static str,
+    pub sort_newest: &'static str,
+    pub sort_stars: &'static str,
+    pub sort_name: &'static str,
+    pub meta_description: &'static str,
+    pub not_found_title: &'static str,
+    pub not_found_start: &'static str, // "The project \""
+    pub not_found_end: &'static str,   // "\" does not exist."
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkillsConfig {
+    pub title: &'static str,
+    pub subtitle: &'static str,
+    pub featured_title: &'static str,
+    pub radar_link: &'static str,
+    pub radar_title: &'static str,
+    pub radar_subtitle: &'static str,
+    pub radar_description: &'static str,
+    pub radar_used_in: &'static str,
+    pub radar_unused: &'static str,
+    pub ring_adopt: &'static str,
+    pub ring_trial: &'static str,
+    pub ring_assess: &'static str,
+    pub ring_hold: &'static str,
+    pub evidence_years: &'static str,
+    pub evidence_projects: &'static str,
+    pub evidence_posts: &'static str,
+    pub evidence_none: &'static str,
+    pub years_under_one: &'static str, // "< 1 year"
+    pub year_one: &'static str,        // "1 year"
+    pub years_many: &'static str,      // "3 years"
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlogConfig {
+    pub section_title: &'static str,
+    pub section_subtitle: &'static str,
+    pub view_all: &'static str,
+    pub page_title: &'static str,
+    pub page_subtitle: &'static str,
+    pub meta_description: &'static str,
+    pub featured_posts: &'static str,
+    pub more_posts: &'static str,
+    pub min_read: &'static str,
+    pub min_short: &'static str,
+    pub back_to_blog: &'static str,
+    pub not_found_title: &'static str,
+    pub not_found_start: &'static str, // "The post \""
+    pub not_found_end: &'static str,   // "\" does not exist."
+    pub return_to_archive: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContactConfig {
+    pub title: &'static str,
+    pub subtitle: &'static str,
+    pub meta_description: &'static str,
+    pub label_name: &'static str,
+    pub label_email: &'static str,
+    pub label_subject: &'static str,
+    pub label_message: &'static str,
+    pub placeholder_name: &'static str,
+    pub placeholder_email: &'static str,
+    pub placeholder_subject: &'static str,
+    pub placeholder_message: &'static str,
+    pub btn_send: &'static str,
+    pub btn_sending: &'static str,
+    pub success_title: &'static str,
+    pub success_message: &'static str,
+    pub btn_send_another: &'static str,
+    pub error_title: &'static str,
+    pub btn_try_again: &'static str,
+    /// Shown after handing the message to the visitor's mail client
+    pub mailto_title: &'static str,
+    pub mailto_message: &'static str,
+    /// Delivery failures (`utils::contact::ContactError`)
+    pub error_network: &'static str,
+    pub error_rejected: &'static str,
+    pub error_unavailable: &'static str,
+    pub info_title: &'static str,
+    pub info_email: &'static str,
+    pub info_location: &'static str,
+    pub location_value: &'static str,
+    pub info_availability: &'static str,
+    pub availability_value: &'static str,
+    pub connect_title: &'static str,
+}
+
+/// Form validation messages
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorsConfig {
+    pub name_required: &'static str,
+    pub email_required: &'static str,
+    pub email_invalid: &'static str,
+    pub subject_required: &'static str,
+    pub message_required: &'static str,
+    pub message_too_short: &'static str,
+    /// "{too_long} 5,000 {characters}"
+    pub too_long: &'static str,
+    pub characters: &'static str,
+    /// Spam checks people can trip (`utils::spam::SpamError`)
+    pub spam_too_fast: &'static str,
+    pub spam_rate_limited: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotFoundConfig {
+    pub title: &'static str,
+    pub message_start: &'static str, // "The page \"/"
+    pub message_end: &'static str,   // "\" doesn't exist."
+    pub back_home: &'static str,
 }
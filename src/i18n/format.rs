@@ -0,0 +1,211 @@
+//! Locale-aware Number Formatting
+//! Integers, compact counts, percentages, reading time and dates per `Language`.
+//!
+//! EN: `35,847` · `35.8k` · `12.5%` · `5 min read` · `Jun 1, 2025`
+//! ES: `35.847` · `35,8 mil` · `12,5 %` · `5 min de lectura` · `1 jun 2025`
+
+use chrono::{Datelike, NaiveDate};
+
+use super::Language;
+
+/// Abbreviated month names, January first
+const MONTHS_EN: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+const MONTHS_ES: [&str; 12] = [
+    "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+];
+
+impl Language {
+    /// Separator between groups of thousands
+    pub fn thousands_separator(&self) -> char {
+        match self {
+            Language::EN => ',',
+            Language::ES => '.',
+        }
+    }
+
+    /// Separator between integer and fractional part
+    pub fn decimal_separator(&self) -> char {
+        match self {
+            Language::EN => '.',
+            Language::ES => ',',
+        }
+    }
+}
+
+/// Format an integer with thousands separators
+/// e.g., 35847 -> "35,847" (EN) / "35.847" (ES)
+pub fn format_integer(value: u64, lang: &Language) -> String {
+    let digits = value.to_string();
+    let sep = lang.thousands_separator();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(sep);
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Format a decimal with a fixed number of fraction digits,
+/// dropping the fraction when it is zero (e.g., "35" instead of "35.0")
+pub fn format_decimal(value: f64, fraction_digits: usize, lang: &Language) -> String {
+    let rendered = format!("{:.*}", fraction_digits, value.abs());
+    let (int_part, frac_part) = rendered.split_once('.').unwrap_or((&rendered, ""));
+    let int_value: u64 = int_part.parse().unwrap_or(0);
+
+    let mut out = String::new();
+    if value < 0.0 && rendered.chars().any(|c| c != '0' && c != '.') {
+        out.push('-');
+    }
+    out.push_str(&format_integer(int_value, lang));
+
+    let frac_trimmed = frac_part.trim_end_matches('0');
+    if !frac_trimmed.is_empty() {
+        out.push(lang.decimal_separator());
+        out.push_str(frac_trimmed);
+    }
+    out
+}
+
+/// Format a count in compact form
+/// e.g., 35847 -> "35.8k" (EN) / "35,8 mil" (ES); 1200000 -> "1.2M" / "1,2 M"
+pub fn format_compact(value: u64, lang: &Language) -> String {
+    let (scaled, suffix) = match value {
+        0..=999 => return format_integer(value, lang),
+        1_000..=999_999 => (value as f64 / 1_000.0, CompactUnit::Thousands),
+        1_000_000..=999_999_999 => (value as f64 / 1_000_000.0, CompactUnit::Millions),
+        _ => (value as f64 / 1_000_000_000.0, CompactUnit::Billions),
+    };
+
+    // Truncate rather than round so 999_999 never shows as "1000k"
+    let truncated = (scaled * 10.0).floor() / 10.0;
+    let number = format_decimal(truncated, 1, lang);
+
+    match (lang, suffix) {
+        (Language::EN, CompactUnit::Thousands) => format!("{}k", number),
+        (Language::EN, CompactUnit::Millions) => format!("{}M", number),
+        (Language::EN, CompactUnit::Billions) => format!("{}B", number),
+        (Language::ES, CompactUnit::Thousands) => format!("{} mil", number),
+        (Language::ES, CompactUnit::Millions) => format!("{} M", number),
+        (Language::ES, CompactUnit::Billions) => format!("{} mil M", number),
+    }
+}
+
+enum CompactUnit {
+    Thousands,
+    Millions,
+    Billions,
+}
+
+/// Format a percentage (value already in 0-100 range)
+/// e.g., 12.5 -> "12.5%" (EN) / "12,5 %" (ES)
+pub fn format_percent(value: f64, lang: &Language) -> String {
+    let number = format_decimal(value, 1, lang);
+    match lang {
+        Language::EN => format!("{}%", number),
+        Language::ES => format!("{} %", number),
+    }
+}
+
+/// Format a reading time in minutes
+/// e.g., 5 -> "5 min read" (EN) / "5 min de lectura" (ES)
+pub fn format_read_time(minutes: u32, lang: &Language) -> String {
+    format!(
+        "{} {}",
+        format_integer(minutes as u64, lang),
+        lang.dict().blog.min_read
+    )
+}
+
+/// Format a duration in whole years
+/// e.g., 0 -> "< 1 year", 1 -> "1 year", 3 -> "3 years" (EN) / "3 años" (ES)
+pub fn format_years(years: u32, lang: &Language) -> String {
+    let i18n = lang.dict().skills;
+    match years {
+        0 => i18n.years_under_one.to_string(),
+        1 => format!("1 {}", i18n.year_one),
+        _ => format!("{} {}", format_integer(years as u64, lang), i18n.years_many),
+    }
+}
+
+/// Format a calendar date with an abbreviated month
+/// e.g., 2025-06-01 -> "Jun 1, 2025" (EN) / "1 jun 2025" (ES)
+pub fn format_date(date: NaiveDate, lang: &Language) -> String {
+    let month = date.month0() as usize;
+    match lang {
+        Language::EN => format!("{} {}, {}", MONTHS_EN[month], date.day(), date.year()),
+        Language::ES => format!("{} {} {}", date.day(), MONTHS_ES[month], date.year()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_integer() {
+        assert_eq!(format_integer(0, &Language::EN), "0");
+        assert_eq!(format_integer(100, &Language::EN), "100");
+        assert_eq!(format_integer(1000, &Language::EN), "1,000");
+        assert_eq!(format_integer(35847, &Language::EN), "35,847");
+        assert_eq!(format_integer(1234567, &Language::EN), "1,234,567");
+        assert_eq!(format_integer(35847, &Language::ES), "35.847");
+        assert_eq!(format_integer(1234567, &Language::ES), "1.234.567");
+    }
+
+    #[test]
+    fn test_format_decimal() {
+        assert_eq!(format_decimal(12.5, 1, &Language::EN), "12.5");
+        assert_eq!(format_decimal(12.5, 1, &Language::ES), "12,5");
+        assert_eq!(format_decimal(35.0, 1, &Language::EN), "35");
+        assert_eq!(format_decimal(1234.25, 2, &Language::ES), "1.234,25");
+        assert_eq!(format_decimal(-3.5, 1, &Language::EN), "-3.5");
+    }
+
+    #[test]
+    fn test_format_compact() {
+        assert_eq!(format_compact(999, &Language::EN), "999");
+        assert_eq!(format_compact(35847, &Language::EN), "35.8k");
+        assert_eq!(format_compact(35847, &Language::ES), "35,8 mil");
+        assert_eq!(format_compact(35000, &Language::EN), "35k");
+        assert_eq!(format_compact(999_999, &Language::EN), "999.9k");
+        assert_eq!(format_compact(1_200_000, &Language::EN), "1.2M");
+        assert_eq!(format_compact(1_200_000, &Language::ES), "1,2 M");
+    }
+
+    #[test]
+    fn test_format_percent() {
+        assert_eq!(format_percent(90.0, &Language::EN), "90%");
+        assert_eq!(format_percent(12.5, &Language::EN), "12.5%");
+        assert_eq!(format_percent(12.5, &Language::ES), "12,5 %");
+    }
+
+    #[test]
+    fn test_format_read_time() {
+        assert_eq!(format_read_time(5, &Language::EN), "5 min read");
+        assert_eq!(format_read_time(5, &Language::ES), "5 min de lectura");
+    }
+
+    #[test]
+    fn test_format_years() {
+        assert_eq!(format_years(0, &Language::EN), "< 1 year");
+        assert_eq!(format_years(1, &Language::EN), "1 year");
+        assert_eq!(format_years(3, &Language::EN), "3 years");
+        assert_eq!(format_years(1, &Language::ES), "1 año");
+        assert_eq!(format_years(12, &Language::ES), "12 años");
+    }
+
+    #[test]
+    fn test_format_date() {
+        let date = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        assert_eq!(format_date(date, &Language::EN), "Jun 1, 2025");
+        assert_eq!(format_date(date, &Language::ES), "1 jun 2025");
+
+        let date = NaiveDate::from_ymd_opt(2024, 9, 30).unwrap();
+        assert_eq!(format_date(date, &Language::ES), "30 sept 2024");
+    }
+}
@@ -17,6 +17,7 @@ pub const EN: Dictionary = Dictionary {
         cta_projects: "View Projects",
         cta_contact: "Contact Me",
         scroll_indicator: "Scroll",
+        role: "Full-Stack Developer",
     },
     about: crate::i18n::dict::AboutConfig {
         title: "About Me",
@@ -29,6 +30,12 @@ pub const EN: Dictionary = Dictionary {
         stat_projects: "Living Projects",
         stat_technologies: "Technologies",
         stat_loc: "Lines of Code",
+        stat_loc_tooltip_start: "Lines of code across ",
+        stat_loc_tooltip_end: " repositories",
+        stat_projects_tooltip: "Active repositories on GitHub",
+        languages_title: "Code by Language",
+        languages_other: "Other",
+        page_summary: "Software developer passionate about building high-performance applications with Rust and modern web technologies.",
         timeline_title: "Experience Timeline",
         timeline: &[
             crate::i18n::dict::TimelineItemData {
@@ -66,5 +73,139 @@ pub const EN: Dictionary = Dictionary {
         btn_demo_github: "GitHub Demo",
         badge_auto: "API",
         tooltip_auto: "Synced via GitHub API",
+        stars_title: "GitHub stars",
+        languages_title: "Languages",
+        languages_other: "Other",
+        history_title: "Growth",
+        history_since: "since",
+        history_stars: "Stars",
+        history_forks: "Forks",
+        history_issues: "Open issues",
+        stats_title: "Repository",
+        stat_language: "Primary language",
+        stat_license: "License",
+        stat_last_push: "Last push",
+        stat_started: "Started",
+        topics_title: "Topics",
+        gallery_title: "Gallery",
+        gallery_alt: "Screenshot of",
+        related_title: "Related posts",
+        case_challenge: "The Challenge",
+        case_architecture: "Architecture",
+        case_outcome: "Outcome",
+        case_metrics: "Key Metrics",
+        page_title: "All Projects",
+        page_subtitle: "Complete Portfolio",
+        status_featured: "Featured",
+        status_active: "Active",
+        status_completed: "Completed",
+        status_archived: "Archived",
+        filter_search: "Filter projects",
+        filter_search_placeholder: "Name, description or technology",
+        filter_technology: "Technology",
+        filter_status: "Status",
+        filter_all: "All",
+        filter_clear: "Clear filters",
+        filter_showing: "Showing",
+        filter_of: "of",
+        filter_empty: "No projects match these filters.",
+        sort_label: "Sort by",
+        sort_featured: "Featured first",
+        sort_newest: "Newest",
+        sort_stars: "Most stars",
+        sort_name: "Name",
+        meta_description: "Rust, WebAssembly and full-stack projects by enerBydev, with live repository stats.",
+        not_found_title: "Project not found",
+        not_found_start: "The project \"",
+        not_found_end: "\" does not exist.",
+    },
+    skills: crate::i18n::dict::SkillsConfig {
+        title: "Skills & Expertise",
+        subtitle: "What I Work With",
+        featured_title: "Featured Technologies",
+        radar_link: "Explore the Tech Radar →",
+        radar_title: "Tech Radar",
+        radar_subtitle: "Adopt, Trial, Assess, Hold",
+        radar_description: "Every skill placed by category and by how much I rely on it. Select a technology to see the projects built with it.",
+        radar_used_in: "Used in",
+        radar_unused: "Not in a listed project yet",
+        ring_adopt: "Adopt",
+        ring_trial: "Trial",
+        ring_assess: "Assess",
+        ring_hold: "Hold",
+        evidence_years: "Experience",
+        evidence_projects: "Projects",
+        evidence_posts: "Posts",
+        evidence_none: "No listed project or post uses it yet",
+        years_under_one: "< 1 year",
+        year_one: "year",
+        years_many: "years",
+    },
+    blog: crate::i18n::dict::BlogConfig {
+        section_title: "Latest Posts",
+        section_subtitle: "From the Blog",
+        view_all: "View All Posts →",
+        page_title: "All Posts",
+        page_subtitle: "Blog Archive",
+        meta_description: "Articles on Rust, Dioxus, WebAssembly and software architecture.",
+        featured_posts: "⭐ Featured Posts",
+        more_posts: "📝 More Posts",
+        min_read: "min read",
+        min_short: "min",
+        back_to_blog: "← Back to Blog",
+        not_found_title: "Post not found",
+        not_found_start: "The post \"",
+        not_found_end: "\" does not exist.",
+        return_to_archive: "Return to Archive",
+    },
+    contact: crate::i18n::dict::ContactConfig {
+        title: "Get In Touch",
+        subtitle: "Let's Work Together",
+        meta_description: "Get in touch about projects and collaborations.",
+        label_name: "Name",
+        label_email: "Email",
+        label_subject: "Subject",
+        label_message: "Message",
+        placeholder_name: "Your Name",
+        placeholder_email: "your@email.com",
+        placeholder_subject: "What's this about?",
+        placeholder_message: "Your message (min 20 characters)...",
+        btn_send: "Send Message",
+        btn_sending: "Sending...",
+        success_title: "Message Sent!",
+        success_message: "Thank you for reaching out. I'll get back to you soon.",
+        btn_send_another: "Send Another Message",
+        error_title: "Something went wrong",
+        btn_try_again: "Try Again",
+        mailto_title: "Almost There",
+        mailto_message: "Your email app should open with the message ready. Send it from there and I will get back to you soon.",
+        error_network: "Could not reach the server. Check your connection and try again.",
+        error_rejected: "The message was not accepted. Check the fields and try again.",
+        error_unavailable: "The contact service is busy right now. Please try again in a few minutes.",
+        info_title: "Contact Info",
+        info_email: "Email",
+        info_location: "Location",
+        location_value: "Mexico 🇲🇽",
+        info_availability: "Availability",
+        availability_value: "Open for Projects",
+        connect_title: "Connect",
+    },
+    errors: crate::i18n::dict::ErrorsConfig {
+        name_required: "Name is required",
+        email_required: "Email is required",
+        email_invalid: "Please enter a valid email",
+        subject_required: "Subject is required",
+        message_required: "Message is required",
+        message_too_short: "Message must be at least 20 characters",
+        too_long: "Too long: the limit is",
+        characters: "characters",
+        spam_too_fast: "That was quick! Take a moment to review your message and send it again.",
+        spam_rate_limited: "You have sent several messages recently. Please try again later.",
+    },
+    not_found: crate::i18n::dict::NotFoundConfig {
+        title: "Page Not Found",
+        message_start: "The page \"/",
+        message_end: "\" doesn't exist.",
+        back_home: "← Back to Home",
     },
 };
@@ -17,6 +17,7 @@ pub const ES: Dictionary = Dictionary {
         cta_projects: "Ver Proyectos",
         cta_contact: "Contáctame",
         scroll_indicator: "Desplazar",
+        role: "Desarrollador Full-Stack",
     },
     about: crate::i18n::dict::AboutConfig {
         title: "Sobre Mí",
@@ -29,6 +30,12 @@ pub const ES: Dictionary = Dictionary {
         stat_projects: "Proyectos Activos",
         stat_technologies: "Tecnologías",
         stat_loc: "Líneas de Código",
+        stat_loc_tooltip_start: "Líneas de código en ",
+        stat_loc_tooltip_end: " repositorios",
+        stat_projects_tooltip: "Repositorios activos en GitHub",
+        languages_title: "Código por Lenguaje",
+        languages_other: "Otros",
+        page_summary: "Desarrollador de software apasionado por construir aplicaciones de alto rendimiento con Rust y tecnologías web modernas.",
         timeline_title: "Cronología de Experiencia",
         timeline: &[
             crate::i18n::dict::TimelineItemData {
@@ -66,5 +73,139 @@ pub const ES: Dictionary = Dictionary {
         btn_demo_github: "Demo GitHub",
         badge_auto: "API",
         tooltip_auto: "Sincronizado vía GitHub API",
+        stars_title: "Estrellas en GitHub",
+        languages_title: "Lenguajes",
+        languages_other: "Otros",
+        history_title: "Crecimiento",
+        history_since: "desde",
+        history_stars: "Estrellas",
+        history_forks: "Forks",
+        history_issues: "Issues abiertos",
+        stats_title: "Repositorio",
+        stat_language: "Lenguaje principal",
+        stat_license: "Licencia",
+        stat_last_push: "Último push",
+        stat_started: "Inicio",
+        topics_title: "Temas",
+        gallery_title: "Galería",
+        gallery_alt: "Captura de",
+        related_title: "Artículos relacionados",
+        case_challenge: "El Reto",
+        case_architecture: "Arquitectura",
+        case_outcome: "Resultado",
+        case_metrics: "Métricas Clave",
+        page_title: "Todos los Proyectos",
+        page_subtitle: "Portafolio Completo",
+        status_featured: "Destacado",
+        status_active: "Activo",
+        status_completed: "Completado",
+        status_archived: "Archivado",
+        filter_search: "Filtrar proyectos",
+        filter_search_placeholder: "Nombre, descripción o tecnología",
+        filter_technology: "Tecnología",
+        filter_status: "Estado",
+        filter_all: "Todos",
+        filter_clear: "Limpiar filtros",
+        filter_showing: "Mostrando",
+        filter_of: "de",
+        filter_empty: "Ningún proyecto coincide con estos filtros.",
+        sort_label: "Ordenar por",
+        sort_featured: "Destacados primero",
+        sort_newest: "Más recientes",
+        sort_stars: "Más estrellas",
+        sort_name: "Nombre",
+        meta_description: "Proyectos de Rust, WebAssembly y full-stack de enerBydev, con estadísticas de sus repositorios.",
+        not_found_title: "Proyecto no encontrado",
+        not_found_start: "El proyecto \"",
+        not_found_end: "\" no existe.",
+    },
+    skills: crate::i18n::dict::SkillsConfig {
+        title: "Habilidades y Experiencia",
+        subtitle: "Con Qué Trabajo",
+        featured_title: "Tecnologías Destacadas",
+        radar_link: "Explorar el Radar Tecnológico →",
+        radar_title: "Radar Tecnológico",
+        radar_subtitle: "Adoptar, Probar, Evaluar, Resistir",
+        radar_description: "Cada habilidad ubicada por categoría y por cuánto confío en ella. Selecciona una tecnología para ver los proyectos construidos con ella.",
+        radar_used_in: "Usado en",
+        radar_unused: "Aún no está en ningún proyecto listado",
+        ring_adopt: "Adoptar",
+        ring_trial: "Probar",
+        ring_assess: "Evaluar",
+        ring_hold: "Resistir",
+        evidence_years: "Experiencia",
+        evidence_projects: "Proyectos",
+        evidence_posts: "Artículos",
+        evidence_none: "Aún no lo usa ningún proyecto o artículo listado",
+        years_under_one: "< 1 año",
+        year_one: "año",
+        years_many: "años",
+    },
+    blog: crate::i18n::dict::BlogConfig {
+        section_title: "Últimas Publicaciones",
+        section_subtitle: "Desde el Blog",
+        view_all: "Ver Todas las Publicaciones →",
+        page_title: "Todas las Publicaciones",
+        page_subtitle: "Archivo del Blog",
+        meta_description: "Artículos sobre Rust, Dioxus, WebAssembly y arquitectura de software.",
+        featured_posts: "⭐ Publicaciones Destacadas",
+        more_posts: "📝 Más Publicaciones",
+        min_read: "min de lectura",
+        min_short: "min",
+        back_to_blog: "← Volver al Blog",
+        not_found_title: "Publicación no encontrada",
+        not_found_start: "La publicación \"",
+        not_found_end: "\" no existe.",
+        return_to_archive: "Volver al Archivo",
+    },
+    contact: crate::i18n::dict::ContactConfig {
+        title: "Hablemos",
+        subtitle: "Trabajemos Juntos",
+        meta_description: "Ponte en contacto para proyectos y colaboraciones.",
+        label_name: "Nombre",
+        label_email: "Correo",
+        label_subject: "Asunto",
+        label_message: "Mensaje",
+        placeholder_name: "Tu Nombre",
+        placeholder_email: "tu@correo.com",
+        placeholder_subject: "¿De qué se trata?",
+        placeholder_message: "Tu mensaje (mín. 20 caracteres)...",
+        btn_send: "Enviar Mensaje",
+        btn_sending: "Enviando...",
+        success_title: "¡Mensaje Enviado!",
+        success_message: "Gracias por escribir. Te responderé pronto.",
+        btn_send_another: "Enviar Otro Mensaje",
+        error_title: "Algo salió mal",
+        btn_try_again: "Intentar de Nuevo",
+        mailto_title: "Ya Casi",
+        mailto_message: "Tu app de correo debería abrirse con el mensaje listo. Envíalo desde ahí y te responderé pronto.",
+        error_network: "No se pudo conectar con el servidor. Revisa tu conexión e inténtalo de nuevo.",
+        error_rejected: "El mensaje no fue aceptado. Revisa los campos e inténtalo de nuevo.",
+        error_unavailable: "El servicio de contacto está ocupado. Inténtalo de nuevo en unos minutos.",
+        info_title: "Información de Contacto",
+        info_email: "Correo",
+        info_location: "Ubicación",
+        location_value: "México 🇲🇽",
+        info_availability: "Disponibilidad",
+        availability_value: "Disponible para Proyectos",
+        connect_title: "Conecta",
+    },
+    errors: crate::i18n::dict::ErrorsConfig {
+        name_required: "El nombre es obligatorio",
+        email_required: "El correo es obligatorio",
+        email_invalid: "Introduce un correo válido",
+        subject_required: "El asunto es obligatorio",
+        message_required: "El mensaje es obligatorio",
+        message_too_short: "El mensaje debe tener al menos 20 caracteres",
+        too_long: "Demasiado largo: el límite es",
+        characters: "caracteres",
+        spam_too_fast: "¡Qué rápido! Tómate un momento para revisar tu mensaje y envíalo de nuevo.",
+        spam_rate_limited: "Enviaste varios mensajes recientemente. Inténtalo de nuevo más tarde.",
+    },
+    not_found: crate::i18n::dict::NotFoundConfig {
+        title: "Página No Encontrada",
+        message_start: "La página \"/",
+        message_end: "\" no existe.",
+        back_home: "← Volver al Inicio",
     },
 };
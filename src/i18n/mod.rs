@@ -5,6 +5,7 @@ use dioxus::prelude::*;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
 pub mod dict;
+pub mod format;
 pub mod locales;
 
 use dict::Dictionary;
@@ -27,6 +28,17 @@ impl Display for Language {
 }
 
 impl Language {
+    /// Every supported language
+    pub const ALL: [Self; 2] = [Language::EN, Language::ES];
+
+    /// Open Graph locale (`og:locale`)
+    pub fn og_locale(&self) -> &'static str {
+        match self {
+            Language::EN => "en_US",
+            Language::ES => "es_MX",
+        }
+    }
+
     /// Get the dictionary for this language
     pub fn dict(&self) -> Dictionary {
         match self {
@@ -34,6 +46,8 @@ impl Language {
              Language::ES => locales::ES.clone(),
         }
     }
+    /// Parse a locale tag by prefix (e.g., "es-MX" -> ES)
+    #[allow(clippy::should_implement_trait)] // prefix match returning Option, not `FromStr`
     pub fn from_str(s: &str) -> Option<Self> {
         let s = s.to_lowercase();
         if s.starts_with("es") {
@@ -66,6 +80,12 @@ impl I18nState {
     }
 }
 
+impl Default for I18nState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Helper to get the current dictionary in components
 /// Usage: `let i18n = use_i18n(); p { "{i18n.hero.greeting}" }`
 pub fn use_i18n() -> Dictionary {
@@ -1,6 +1,7 @@
 //! Footer Component
 //! Contains copyright, social links, and proper attribution
 
+use crate::components::project_filters::ProjectQuery;
 use crate::config::{SITE, SOCIAL_LINKS};
 use crate::routes::Route;
 use chrono::{Datelike, Utc};
@@ -32,7 +33,7 @@ pub fn Footer() -> Element {
                 div { class: "hidden md:flex gap-6",
                     Link { to: Route::HomePage {}, class: "text-muted hover:text-primary text-sm transition-colors", "{i18n.nav.home}" }
                     Link { to: Route::AboutPage {}, class: "text-muted hover:text-primary text-sm transition-colors", "{i18n.nav.about}" }
-                    Link { to: Route::ProjectsPage {}, class: "text-muted hover:text-primary text-sm transition-colors", "{i18n.nav.projects}" }
+                    Link { to: Route::ProjectsPage { query: ProjectQuery::default() }, class: "text-muted hover:text-primary text-sm transition-colors", "{i18n.nav.projects}" }
                     Link { to: Route::BlogPage {}, class: "text-muted hover:text-primary text-sm transition-colors", "{i18n.nav.blog}" }
                 }
 
@@ -1,6 +1,7 @@
 //! Header Component - Navbar and Navigation
 //! Includes responsive mobile menu and active route styling
 
+use crate::components::project_filters::ProjectQuery;
 use crate::routes::Route;
 use dioxus::prelude::*;
 
@@ -79,7 +80,7 @@ pub fn Header() -> Element {
                     }
                     li {
                         Link {
-                            to: Route::ProjectsPage {},
+                            to: Route::ProjectsPage { query: ProjectQuery::default() },
                             class: base_class,
                             active_class: active_class,
                             "{i18n.nav.projects}"
@@ -181,7 +182,7 @@ pub fn Header() -> Element {
                 ul { class: "flex flex-col p-6 space-y-4 items-center",
                     li { Link { to: Route::HomePage {}, class: "text-lg", onclick: move |_| is_menu_open.set(false), "{i18n.nav.home}" } }
                     li { Link { to: Route::AboutPage {}, class: "text-lg", onclick: move |_| is_menu_open.set(false), "{i18n.nav.about}" } }
-                    li { Link { to: Route::ProjectsPage {}, class: "text-lg", onclick: move |_| is_menu_open.set(false), "{i18n.nav.projects}" } }
+                    li { Link { to: Route::ProjectsPage { query: ProjectQuery::default() }, class: "text-lg", onclick: move |_| is_menu_open.set(false), "{i18n.nav.projects}" } }
                     li { Link { to: Route::BlogPage {}, class: "text-lg", onclick: move |_| is_menu_open.set(false), "{i18n.nav.blog}" } }
                     li { Link { to: Route::ContactPage {}, class: "text-lg", onclick: move |_| is_menu_open.set(false), "{i18n.nav.contact}" } }
                     li { class: "mt-4 scale-125", crate::components::molecules::LanguageToggle {} }
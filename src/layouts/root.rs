@@ -1,6 +1,7 @@
 //! Root Layout
 //! Puts it all together: Header + Main + Footer + Global Overlays
 
+use crate::components::seo::RouteSeoHead;
 use crate::layouts::footer::Footer;
 use crate::layouts::header::Header;
 use crate::routes::Route;
@@ -9,6 +10,9 @@ use dioxus::prelude::*;
 #[component]
 pub fn RootLayout() -> Element {
     rsx! {
+        // Title, description, Open Graph and Twitter tags of the active route
+        RouteSeoHead {}
+
         div { class: "app-wrapper min-h-screen flex flex-col cyber-grid relative overflow-x-hidden",
 
             // Global Overlays (P4-A3, P4-A4)
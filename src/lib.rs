@@ -0,0 +1,71 @@
+//! enerby.dev - Application Library
+//! A Cyberpunk-styled Portfolio built with Dioxus & Rust
+//!
+//! Shared by the web entry point (`main.rs`) and the tooling binaries in `src/bin/`.
+
+use dioxus::prelude::*;
+
+// Module declarations
+pub mod components;
+pub mod config;
+pub mod data;
+pub mod domain;
+pub mod layouts;
+pub mod pages;
+pub mod routes;
+pub mod theme;
+pub mod utils;
+pub mod i18n;
+
+#[cfg(test)]
+mod tests;
+
+// Construction overlay — remove "construction" from default features in Cargo.toml to disable
+#[cfg(feature = "construction")]
+mod construction;
+
+// Re-exports
+#[cfg(not(feature = "construction"))]
+use routes::Route;
+
+// Assets
+const FAVICON: Asset = asset!("/assets/favicon.ico");
+const TAILWIND_CSS: Asset = asset!("/assets/tailwind.css");
+
+/// Main application component
+#[component]
+#[cfg(feature = "construction")]
+pub fn App() -> Element {
+    rsx! {
+        document::Link { rel: "icon", href: FAVICON }
+        document::Stylesheet { href: TAILWIND_CSS }
+        document::Title { "{config::SITE.title}" }
+        document::Meta {
+            name: "description",
+            content: "{config::SITE.description}"
+        }
+        document::Meta {
+            name: "viewport",
+            content: "width=device-width, initial-scale=1.0"
+        }
+
+        construction::ConstructionOverlay {}
+    }
+}
+
+/// Main application component with router
+#[component]
+#[cfg(not(feature = "construction"))]
+pub fn App() -> Element {
+    rsx! {
+        document::Link { rel: "icon", href: FAVICON }
+        document::Stylesheet { href: TAILWIND_CSS }
+        // Title and description come from the route, see `RouteSeoHead`
+        document::Meta {
+            name: "viewport",
+            content: "width=device-width, initial-scale=1.0"
+        }
+
+        Router::<Route> {}
+    }
+}
@@ -2,19 +2,25 @@
 
 use dioxus::prelude::*;
 
+use crate::components::about::LanguageBreakdown;
+
 /// About page component
 #[component]
 pub fn AboutPage() -> Element {
+    let i18n = crate::i18n::use_i18n();
+
     rsx! {
         section { class: "section",
             div { class: "container",
-                h1 { class: "section-title", "About Me" }
+                h1 { class: "section-title", "{i18n.about.title}" }
 
                 div { class: "card",
                     p { class: "text-secondary",
-                        "Software developer passionate about building high-performance applications with Rust and modern web technologies."
+                        "{i18n.about.page_summary}"
                     }
                 }
+
+                LanguageBreakdown {}
             }
         }
     }
@@ -14,20 +14,21 @@ pub fn BlogPage() -> Element {
     let posts = get_published_posts();
     let featured: Vec<_> = posts.iter().filter(|p| p.featured).cloned().collect();
     let regular: Vec<_> = posts.iter().filter(|p| !p.featured).cloned().collect();
+    let i18n = crate::i18n::use_i18n();
 
     rsx! {
         Section { id: "blog-page",
             Container {
                 SectionTitle {
-                    text: "All Posts".to_string(),
-                    subtitle: "Blog Archive".to_string(),
+                    text: i18n.blog.page_title.to_string(),
+                    subtitle: i18n.blog.page_subtitle.to_string(),
                     center: true
                 }
 
                 // Featured Posts
                 if !featured.is_empty() {
                     div { class: "mb-12",
-                        h3 { class: "text-lg font-bold text-primary mb-6", "⭐ Featured Posts" }
+                        h3 { class: "text-lg font-bold text-primary mb-6", "{i18n.blog.featured_posts}" }
                         Grid { cols: 1, md_cols: 2, gap: 6,
                             for post in featured.iter() {
                                 BlogPostPreview { post: post.clone() }
@@ -39,7 +40,7 @@ pub fn BlogPage() -> Element {
                 // All Posts
                 if !regular.is_empty() {
                     div { class: "mb-12",
-                        h3 { class: "text-lg font-bold text-purple-400 mb-6", "📝 More Posts" }
+                        h3 { class: "text-lg font-bold text-purple-400 mb-6", "{i18n.blog.more_posts}" }
                         Grid { cols: 1, md_cols: 3, gap: 6,
                             for post in regular.iter() {
                                 BlogPostPreview { post: post.clone() }
@@ -57,6 +58,7 @@ pub fn BlogPage() -> Element {
 pub fn BlogPostPage(slug: String) -> Element {
     let post = get_post_by_slug(&slug);
     let lang = crate::i18n::I18N_CONFIG.read().language;
+    let i18n = crate::i18n::use_i18n();
     use crate::components::blog::{BlogPostHeader, BlogPostContent, BlogPostNavigator};
 
     rsx! {
@@ -75,14 +77,14 @@ pub fn BlogPostPage(slug: String) -> Element {
                     }
                 } else {
                     div { class: "text-center py-16",
-                        h1 { class: "text-2xl font-bold text-white mb-4", "Post not found" }
-                        p { class: "text-muted", "The post \"{slug}\" does not exist." }
+                        h1 { class: "text-2xl font-bold text-white mb-4", "{i18n.blog.not_found_title}" }
+                        p { class: "text-muted", "{i18n.blog.not_found_start}{slug}{i18n.blog.not_found_end}" }
                         
                         div { class: "mt-8",
                             crate::components::atoms::Button {
                                 variant: crate::components::atoms::ButtonVariant::Primary,
                                 to: Route::BlogPage {},
-                                "Return to Archive"
+                                "{i18n.blog.return_to_archive}"
                             }
                         }
                     }
@@ -5,38 +5,40 @@ use dioxus::prelude::*;
 /// Contact page component
 #[component]
 pub fn ContactPage() -> Element {
+    let i18n = crate::i18n::use_i18n();
+
     rsx! {
         section { class: "section",
             div { class: "container container-narrow",
-                h1 { class: "section-title", "Contact" }
+                h1 { class: "section-title", "{i18n.nav.contact}" }
 
                 div { class: "card",
                     form { class: "contact-form",
                         div { class: "form-group",
-                            label { "Name" }
+                            label { "{i18n.contact.label_name}" }
                             input {
                                 class: "input",
                                 r#type: "text",
-                                placeholder: "Your name",
+                                placeholder: "{i18n.contact.placeholder_name}",
                                 name: "name"
                             }
                         }
 
                         div { class: "form-group",
-                            label { "Email" }
+                            label { "{i18n.contact.label_email}" }
                             input {
                                 class: "input",
                                 r#type: "email",
-                                placeholder: "your@email.com",
+                                placeholder: "{i18n.contact.placeholder_email}",
                                 name: "email"
                             }
                         }
 
                         div { class: "form-group",
-                            label { "Message" }
+                            label { "{i18n.contact.label_message}" }
                             textarea {
                                 class: "input",
-                                placeholder: "Your message...",
+                                placeholder: "{i18n.contact.placeholder_message}",
                                 name: "message",
                                 rows: "5"
                             }
@@ -45,7 +47,7 @@ pub fn ContactPage() -> Element {
                         button {
                             class: "btn btn-primary",
                             r#type: "submit",
-                            "Send Message"
+                            "{i18n.contact.btn_send}"
                         }
                     }
                 }
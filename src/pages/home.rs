@@ -4,8 +4,7 @@ use crate::components::about::AboutSection;
 use crate::components::blog::BlogSection;
 use crate::components::contact::ContactSection;
 use crate::components::hero::HeroSection;
-use crate::components::projects::EnrichedProjectsSection;
-use crate::components::seo::SeoHead;
+use crate::components::projects::ProjectsSection;
 use crate::components::skills::SkillsSection;
 use dioxus::prelude::*;
 
@@ -13,9 +12,6 @@ use dioxus::prelude::*;
 #[component]
 pub fn HomePage() -> Element {
     rsx! {
-        // SEO Tags (P13)
-        SeoHead {}
-
         // Hero Section (P6)
         HeroSection {}
 
@@ -26,7 +22,7 @@ pub fn HomePage() -> Element {
         SkillsSection {}
 
         // Projects Section (P9)
-        EnrichedProjectsSection {}
+        ProjectsSection {}
 
         // Blog Section (P10)
         BlogSection {}
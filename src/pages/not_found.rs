@@ -1,5 +1,6 @@
 //! 404 Not Found Page
 
+use crate::components::seo::RouteSeoHead;
 use crate::routes::Route;
 use dioxus::prelude::*;
 
@@ -7,19 +8,23 @@ use dioxus::prelude::*;
 #[component]
 pub fn NotFoundPage(route: Vec<String>) -> Element {
     let path = route.join("/");
+    let i18n = crate::i18n::use_i18n();
 
     rsx! {
+        // Outside `RootLayout`, so it renders its own (noindex) tags
+        RouteSeoHead {}
+
         section { class: "section",
             div { class: "container",
                 div { class: "not-found-content",
                     h1 { class: "neon-text", "404" }
-                    h2 { "Page Not Found" }
+                    h2 { "{i18n.not_found.title}" }
                     p { class: "text-secondary",
-                        "The page \"/{path}\" doesn't exist."
+                        "{i18n.not_found.message_start}{path}{i18n.not_found.message_end}"
                     }
                     Link { to: Route::HomePage {},
                         class: "btn btn-primary",
-                        "← Back to Home"
+                        "{i18n.not_found.back_home}"
                     }
                 }
             }
@@ -1,71 +1,77 @@
 //! Projects Page - Portfolio showcase
 
+use crate::components::blog::{get_published_posts, related_posts};
+use crate::components::case_study::CaseStudyView;
 use crate::components::layout_components::{Container, Grid, Section};
-use crate::components::molecules::SectionTitle;
-use crate::components::projects::{ProjectCard, ProjectStatus, get_projects};
+use crate::components::molecules::{LanguageBar, SectionTitle, Sparkline};
+use crate::components::project_filters::{ProjectFilters, ProjectQuery};
+use crate::components::projects::{ProjectCard, get_projects_enriched};
+use crate::i18n::I18N_CONFIG;
+use crate::i18n::format::{format_date, format_integer};
+use crate::routes::Route;
+use crate::utils::case_studies::get_case_study;
+use crate::utils::project_images::get_project_gallery;
 use dioxus::prelude::*;
 
+/// Languages listed on the detail page before folding the rest into "Other"
+const DETAIL_LANGUAGE_LIMIT: usize = 8;
+
+/// Blog posts listed under "Related posts"
+const RELATED_POSTS_LIMIT: usize = 3;
+
+/// One labelled value in the repository stats row
+#[component]
+fn RepoStat(label: String, value: String) -> Element {
+    rsx! {
+        div { class: "bg-bg-card border border-white/10 rounded-lg px-4 py-3",
+            dt { class: "text-xs text-muted uppercase tracking-wider", "{label}" }
+            dd { class: "text-white font-mono mt-1", "{value}" }
+        }
+    }
+}
+
 /// Projects listing page - All projects with dynamic data
+/// (hand-written plus repos discovered through their GitHub topics)
+///
+/// Filtered and sorted by the `query` from the URL, see `ProjectQuery`.
 #[component]
-pub fn ProjectsPage() -> Element {
-    let projects = get_projects();
-    let featured: Vec<_> = projects
-        .iter()
-        .filter(|p| p.status == ProjectStatus::Featured)
-        .cloned()
-        .collect();
-    let active: Vec<_> = projects
-        .iter()
-        .filter(|p| p.status == ProjectStatus::Active)
-        .cloned()
-        .collect();
-    let archived: Vec<_> = projects
-        .iter()
-        .filter(|p| p.status == ProjectStatus::Archived)
-        .cloned()
-        .collect();
+pub fn ProjectsPage(query: ProjectQuery) -> Element {
+    let projects = get_projects_enriched();
+    let lang = I18N_CONFIG.read().language;
+    let results = query.apply(&projects, &lang);
+    let i18n = crate::i18n::use_i18n();
 
     rsx! {
         Section { id: "projects-page",
             Container {
                 SectionTitle {
-                    text: "All Projects".to_string(),
-                    subtitle: "Complete Portfolio".to_string(),
+                    text: i18n.projects.page_title.to_string(),
+                    subtitle: i18n.projects.page_subtitle.to_string(),
                     center: true
                 }
 
-                // Featured Projects
-                if !featured.is_empty() {
-                    div { class: "mb-12",
-                        h3 { class: "text-lg font-bold text-primary mb-6", "⭐ Featured Projects" }
-                        Grid { cols: 1, md_cols: 1, gap: 8,
-                            for project in featured.iter() {
-                                ProjectCard { project: project.clone(), featured: true }
-                            }
-                        }
-                    }
-                }
+                ProjectFilters { query: query.clone(), projects: projects.clone() }
 
-                // Active Projects
-                if !active.is_empty() {
-                    div { class: "mb-12",
-                        h3 { class: "text-lg font-bold text-purple-400 mb-6", "🚀 Active Projects" }
-                        Grid { cols: 1, md_cols: 1, gap: 8,
-                            for project in active.iter() {
-                                ProjectCard { project: project.clone(), featured: true }
-                            }
+                // Result count
+                div { class: "flex items-center justify-between mb-6 text-sm text-muted",
+                    p { role: "status",
+                        "{i18n.projects.filter_showing} {format_integer(results.len() as u64, &lang)} {i18n.projects.filter_of} {format_integer(projects.len() as u64, &lang)}"
+                    }
+                    if !query.is_unfiltered() {
+                        Link {
+                            to: Route::ProjectsPage { query: query.cleared() },
+                            class: "text-primary hover:underline",
+                            "{i18n.projects.filter_clear}"
                         }
                     }
                 }
 
-                // Archived Projects
-                if !archived.is_empty() {
-                    div { class: "mb-12",
-                        h3 { class: "text-lg font-bold text-orange-400 mb-6", "📦 Archived Projects" }
-                        Grid { cols: 1, md_cols: 1, gap: 8,
-                            for project in archived.iter() {
-                                ProjectCard { project: project.clone(), featured: true }
-                            }
+                if results.is_empty() {
+                    p { class: "text-center text-muted py-12", "{i18n.projects.filter_empty}" }
+                } else {
+                    Grid { cols: 1, md_cols: 1, gap: 8,
+                        for project in results {
+                            ProjectCard { key: "{project.id}", project, featured: true }
                         }
                     }
                 }
@@ -75,13 +81,32 @@ pub fn ProjectsPage() -> Element {
 }
 
 /// Project detail page
+///
+/// Everything known about one project: the hand-written text and case study,
+/// the repository stats and topics, its images and the blog posts sharing its tags.
 #[component]
 pub fn ProjectDetailPage(slug: String) -> Element {
-    use crate::components::projects::get_project_by_id;
-    use crate::i18n::I18N_CONFIG;
+    use crate::components::projects::get_enriched_project_by_id;
+    use crate::theme::colors;
+    use crate::utils::repo_history::get_repo_history;
 
-    let project = get_project_by_id(&slug);
+    let project = get_enriched_project_by_id(&slug);
+    let history = project
+        .as_ref()
+        .and_then(|p| p.github_url.as_deref())
+        .map(get_repo_history)
+        .unwrap_or_default();
+    let gallery = project
+        .as_ref()
+        .map(get_project_gallery)
+        .unwrap_or_default();
+    let related = project
+        .as_ref()
+        .map(|p| related_posts(p, &get_published_posts(), RELATED_POSTS_LIMIT))
+        .unwrap_or_default();
     let lang = I18N_CONFIG.read().language;
+    let case_study = project.as_ref().and_then(|p| get_case_study(&p.id, &lang));
+    let i18n = crate::i18n::use_i18n();
 
     rsx! {
         Section { id: "project-detail",
@@ -91,7 +116,36 @@ pub fn ProjectDetailPage(slug: String) -> Element {
                         // Header
                         div { class: "mb-8",
                             h1 { class: "text-3xl font-bold text-white mb-4", "{p.title}" }
-                            p { class: "text-muted text-lg", "{p.long_description(&lang)}" }
+                            p { class: "text-muted text-lg", "{p.long_description.get(&lang)}" }
+                        }
+
+                        // Challenge → architecture → outcome, when written up
+                        if let Some(case_study) = case_study {
+                            CaseStudyView { case_study }
+                        }
+
+                        // Repository stats (only once repo data has been applied)
+                        if p.repo_synced {
+                            div { class: "mb-8",
+                                h2 { class: "text-lg font-bold text-white mb-3", "{i18n.projects.stats_title}" }
+                                dl { class: "grid grid-cols-2 sm:grid-cols-4 gap-4",
+                                    RepoStat { label: i18n.projects.history_stars.to_string(), value: format_integer(p.stars as u64, &lang) }
+                                    RepoStat { label: i18n.projects.history_forks.to_string(), value: format_integer(p.forks as u64, &lang) }
+                                    RepoStat { label: i18n.projects.history_issues.to_string(), value: format_integer(p.open_issues as u64, &lang) }
+                                    if let Some(language) = p.language.clone() {
+                                        RepoStat { label: i18n.projects.stat_language.to_string(), value: language }
+                                    }
+                                    if let Some(license) = p.license.clone() {
+                                        RepoStat { label: i18n.projects.stat_license.to_string(), value: license }
+                                    }
+                                    if let Some(pushed) = p.last_push {
+                                        RepoStat { label: i18n.projects.stat_last_push.to_string(), value: format_date(pushed, &lang) }
+                                    }
+                                    if let Some(started) = p.started {
+                                        RepoStat { label: i18n.projects.stat_started.to_string(), value: format_date(started, &lang) }
+                                    }
+                                }
+                            }
                         }
 
                         // Technologies
@@ -101,30 +155,115 @@ pub fn ProjectDetailPage(slug: String) -> Element {
                             }
                         }
 
+                        // Repository topics
+                        if !p.topics.is_empty() {
+                            div { class: "mb-8",
+                                h2 { class: "text-lg font-bold text-white mb-3", "{i18n.projects.topics_title}" }
+                                ul { class: "flex flex-wrap gap-2",
+                                    for topic in p.topics.iter() {
+                                        li { class: "px-3 py-1 border border-white/10 text-muted rounded-full text-xs font-mono", "#{topic}" }
+                                    }
+                                }
+                            }
+                        }
+
+                        // Language breakdown
+                        if !p.languages.is_empty() {
+                            div { class: "mb-8",
+                                h2 { class: "text-lg font-bold text-white mb-3", "{i18n.projects.languages_title}" }
+                                LanguageBar { languages: p.languages.clone(), limit: DETAIL_LANGUAGE_LIMIT }
+                            }
+                        }
+
+                        // Star / fork / issue history (needs two syncs to draw a line)
+                        if history.len() >= 2 {
+                            div { class: "mb-8",
+                                div { class: "flex items-baseline gap-3 mb-3",
+                                    h2 { class: "text-lg font-bold text-white", "{i18n.projects.history_title}" }
+                                    span { class: "text-xs text-muted", "{i18n.projects.history_since} {history[0].date}" }
+                                }
+                                div { class: "grid grid-cols-1 sm:grid-cols-3 gap-4",
+                                    Sparkline {
+                                        label: i18n.projects.history_stars.to_string(),
+                                        values: history.iter().map(|h| h.stars).collect::<Vec<_>>(),
+                                        color: colors::PRIMARY.to_string(),
+                                    }
+                                    Sparkline {
+                                        label: i18n.projects.history_forks.to_string(),
+                                        values: history.iter().map(|h| h.forks).collect::<Vec<_>>(),
+                                        color: colors::SECONDARY_PINK.to_string(),
+                                    }
+                                    Sparkline {
+                                        label: i18n.projects.history_issues.to_string(),
+                                        values: history.iter().map(|h| h.open_issues).collect::<Vec<_>>(),
+                                        color: colors::SECONDARY_PURPLE.to_string(),
+                                    }
+                                }
+                            }
+                        }
+
+                        // Gallery
+                        if !gallery.is_empty() {
+                            div { class: "mb-8",
+                                h2 { class: "text-lg font-bold text-white mb-3", "{i18n.projects.gallery_title}" }
+                                div { class: "grid grid-cols-1 sm:grid-cols-2 gap-4",
+                                    for image in gallery.iter().filter_map(|image| image.url()) {
+                                        a { href: "{image}", target: "_blank", rel: "noopener",
+                                            img {
+                                                src: "{image}",
+                                                alt: "{i18n.projects.gallery_alt} {p.title}",
+                                                loading: "lazy",
+                                                class: "w-full aspect-video object-cover rounded-lg border border-white/10 hover:border-primary transition-colors",
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        // Related blog posts
+                        if !related.is_empty() {
+                            div { class: "mb-8",
+                                h2 { class: "text-lg font-bold text-white mb-3", "{i18n.projects.related_title}" }
+                                ul { class: "space-y-3",
+                                    for post in related {
+                                        li { key: "{post.slug}",
+                                            Link {
+                                                to: Route::BlogPostPage { slug: post.slug.clone() },
+                                                class: "flex items-baseline justify-between gap-4 group",
+                                                span { class: "text-white group-hover:text-primary transition-colors", "{post.title.get(&lang)}" }
+                                                span { class: "text-xs text-muted font-mono shrink-0", "{post.date}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
                         // Links
                         div { class: "flex gap-4",
-                            if let Some(github) = p.github_url {
+                            if let Some(github) = p.github_url.clone() {
                                 a {
                                     href: "{github}",
                                     target: "_blank",
                                     class: "px-6 py-3 bg-bg-card border border-white/10 rounded-lg hover:border-primary transition-colors",
-                                    "View on GitHub"
+                                    "{i18n.projects.btn_github}"
                                 }
                             }
-                            if let Some(demo) = p.demo_url {
+                            if let Some(demo) = p.demo_url.clone() {
                                 a {
                                     href: "{demo}",
                                     target: "_blank",
                                     class: "px-6 py-3 bg-primary text-bg-dark rounded-lg hover:bg-primary/80 transition-colors",
-                                    "Live Demo"
+                                    "{i18n.projects.btn_demo}"
                                 }
                             }
                         }
                     }
                 } else {
                     div { class: "text-center py-16",
-                        h1 { class: "text-2xl font-bold text-white mb-4", "Project not found" }
-                        p { class: "text-muted", "The project \"{slug}\" does not exist." }
+                        h1 { class: "text-2xl font-bold text-white mb-4", "{i18n.projects.not_found_title}" }
+                        p { class: "text-muted", "{i18n.projects.not_found_start}{slug}{i18n.projects.not_found_end}" }
                     }
                 }
             }
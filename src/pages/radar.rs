@@ -0,0 +1,31 @@
+//! Tech Radar Page - Skills placed by category and ring
+
+use crate::components::layout_components::{Container, Section};
+use crate::components::molecules::SectionTitle;
+use crate::components::projects::get_projects_enriched;
+use crate::components::radar::{RadarLegend, TechRadar, radar_blips};
+use crate::components::skills::get_skills;
+use dioxus::prelude::*;
+
+/// Tech radar page
+#[component]
+pub fn RadarPage() -> Element {
+    let blips = radar_blips(get_skills(), &get_projects_enriched());
+    let i18n = crate::i18n::use_i18n();
+
+    rsx! {
+        Section { id: "radar",
+            Container {
+                SectionTitle {
+                    text: i18n.skills.radar_title.to_string(),
+                    subtitle: i18n.skills.radar_subtitle.to_string(),
+                    center: true
+                }
+                p { class: "text-center text-muted max-w-2xl mx-auto mb-10", "{i18n.skills.radar_description}" }
+
+                TechRadar { blips: blips.clone() }
+                RadarLegend { blips }
+            }
+        }
+    }
+}
@@ -3,6 +3,7 @@
 
 use dioxus::prelude::*;
 
+use crate::components::project_filters::ProjectQuery;
 use crate::layouts::root::RootLayout;
 use crate::pages::{
     about::AboutPage,
@@ -11,6 +12,7 @@ use crate::pages::{
     home::HomePage,
     not_found::NotFoundPage,
     projects::{ProjectDetailPage, ProjectsPage},
+    radar::RadarPage,
 };
 
 /// Main application routes
@@ -27,13 +29,17 @@ pub enum Route {
         #[route("/about")]
         AboutPage {},
         
-        // Projects section
-        #[route("/projects")]
-        ProjectsPage {},
+        // Projects section (filters in the query string, see `ProjectQuery`)
+        #[route("/projects?:..query")]
+        ProjectsPage { query: ProjectQuery },
         
         #[route("/projects/:slug")]
         ProjectDetailPage { slug: String },
         
+        // Skills × projects tech radar
+        #[route("/radar")]
+        RadarPage {},
+        
         // Blog section
         #[route("/blog")]
         BlogPage {},
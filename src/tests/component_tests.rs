@@ -2,9 +2,12 @@
 //! Tests for component props and behavior
 
 use crate::components::atoms::ButtonVariant;
-use crate::components::blog::PostStatus;
 use crate::components::contact::FormState;
-use crate::components::projects::ProjectStatus;
+use crate::data::ContactField;
+use crate::domain::{CaseStudySection, PostStatus, ProjectStatus};
+use crate::i18n::Language;
+use crate::utils::contact::{ContactError, Delivery};
+use crate::utils::validation::{MIN_MESSAGE_LEN, ValidationError, is_valid_email, validate_field};
 
 // =============================================================================
 // Enum Variant Tests
@@ -19,19 +22,33 @@ fn test_button_variants_are_distinct() {
 
 #[test]
 fn test_project_status_labels() {
-    assert_eq!(ProjectStatus::Featured.label(), "Featured");
-    assert_eq!(ProjectStatus::Active.label(), "Active");
-    assert_eq!(ProjectStatus::Archived.label(), "Archived");
+    assert_eq!(ProjectStatus::Active.label(&Language::EN), "Active");
+    assert_eq!(ProjectStatus::Completed.label(&Language::EN), "Completed");
+    assert_eq!(ProjectStatus::Archived.label(&Language::EN), "Archived");
+    assert_eq!(ProjectStatus::Completed.label(&Language::ES), "Completado");
 }
 
 #[test]
 fn test_project_status_colors() {
     // Each status should have a color
-    assert!(!ProjectStatus::Featured.color().is_empty());
     assert!(!ProjectStatus::Active.color().is_empty());
+    assert!(!ProjectStatus::Completed.color().is_empty());
     assert!(!ProjectStatus::Archived.color().is_empty());
 }
 
+#[test]
+fn test_case_study_sections_from_either_language() {
+    for section in CaseStudySection::ALL {
+        for heading in section.headings() {
+            assert_eq!(CaseStudySection::from_heading(heading), Some(section));
+        }
+        assert!(!section.label(&Language::EN).is_empty());
+        assert_ne!(section.label(&Language::EN), section.label(&Language::ES));
+    }
+    assert_eq!(CaseStudySection::from_heading("  métricas "), Some(CaseStudySection::Metrics));
+    assert_eq!(CaseStudySection::from_heading("Installation"), None);
+}
+
 #[test]
 fn test_post_status_equality() {
     assert_eq!(PostStatus::Published, PostStatus::Published);
@@ -51,33 +68,55 @@ fn test_form_state_variants() {
     let _idle = FormState::Idle;
     let _loading = FormState::Loading;
     let _success = FormState::Success;
+    let _mail_client = FormState::MailClientOpened;
     let _error = FormState::Error("Test error".to_string());
 }
 
+#[test]
+fn test_form_state_from_delivery_result() {
+    let es = Language::ES.dict().contact;
+
+    assert_eq!(
+        FormState::from_result(Ok(Delivery::Sent), &es),
+        FormState::Success
+    );
+    assert_eq!(
+        FormState::from_result(Ok(Delivery::MailClient), &es),
+        FormState::MailClientOpened
+    );
+    assert_eq!(
+        FormState::from_result(Err(ContactError::ServerError(502)), &es),
+        FormState::Error(es.error_unavailable.to_string())
+    );
+}
+
 // =============================================================================
 // Validation Logic Tests (P16-C4)
 // =============================================================================
 
 #[test]
 fn test_email_validation_logic() {
-    // Simple email validation check (mirrors contact.rs logic)
-    fn is_valid_email(email: &str) -> bool {
-        email.contains('@') && email.contains('.') && email.len() > 5
-    }
-
     assert!(is_valid_email("test@example.com"));
     assert!(is_valid_email("user@domain.org"));
     assert!(!is_valid_email("invalid"));
     assert!(!is_valid_email("no@dot"));
-    assert!(!is_valid_email("a@b.c")); // Too short
+    assert!(!is_valid_email("a@@b.com"));
+    assert_eq!(
+        validate_field(ContactField::Email, "no@dot"),
+        Err(ValidationError::InvalidEmail)
+    );
 }
 
 #[test]
 fn test_message_length_validation() {
-    // Message should be at least 20 characters
     let short_msg = "Hi";
     let valid_msg = "This is a valid message that is long enough";
 
-    assert!(short_msg.len() < 20);
-    assert!(valid_msg.len() >= 20);
+    assert_eq!(
+        validate_field(ContactField::Message, short_msg),
+        Err(ValidationError::TooShort {
+            min: MIN_MESSAGE_LEN
+        })
+    );
+    assert_eq!(validate_field(ContactField::Message, valid_msg), Ok(()));
 }
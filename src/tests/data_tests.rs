@@ -1,11 +1,13 @@
 //! Data Tests (P16-A3, P16-A4)
 //! Tests for data models: Projects, Blog Posts, Skills
 
-use crate::components::blog::{PostStatus, get_blog_posts, get_published_posts};
-use crate::components::projects::{ProjectStatus, get_projects};
-use crate::components::skills::SkillCategory;
+use crate::components::blog::{get_blog_posts, get_published_posts, related_posts};
+use crate::components::projects::{get_projects, get_projects_enriched};
+use crate::components::skills::{get_skills, get_untracked_technologies};
+use crate::domain::{PostStatus, ProjectStatus, SkillCategory};
 
 use crate::i18n::Language;
+use chrono::NaiveDate;
 
 // =============================================================================
 // Project Tests (P16-C2)
@@ -15,6 +17,7 @@ use crate::i18n::Language;
 fn test_projects_exist() {
     let projects = get_projects();
     assert!(!projects.is_empty(), "Should have at least one project");
+    assert!(projects.iter().any(|p| p.featured), "Should feature at least one project");
 }
 
 #[test]
@@ -28,8 +31,14 @@ fn test_projects_have_required_fields() {
             "Project title should not be empty"
         );
         assert!(
-            !project.description(&Language::EN).is_empty(),
-            "Project description should not be empty"
+            project.description.is_complete(),
+            "Project '{}' should have a description in every language",
+            project.id
+        );
+        assert!(
+            project.long_description.is_complete(),
+            "Project '{}' should have a long description in every language",
+            project.id
         );
         assert!(
             !project.technologies.is_empty(),
@@ -38,8 +47,35 @@ fn test_projects_have_required_fields() {
     }
 }
 
-// ... other project tests usually rely on basic fields which are Strings or Enums, so they are fine?
-// Checks unique ids, featured project... seems fine.
+#[test]
+fn test_projects_have_unique_ids() {
+    // The listing includes discovered repos, so check what the site renders
+    let projects = get_projects_enriched();
+    let mut ids: Vec<&str> = projects.iter().map(|p| p.id.as_str()).collect();
+    let original_len = ids.len();
+    ids.sort();
+    ids.dedup();
+
+    assert_eq!(ids.len(), original_len, "Project ids should be unique");
+}
+
+#[test]
+fn test_project_featured_is_independent_of_status() {
+    // Featuring is editorial: repository data never changes the flag or the status
+    for (hand_written, enriched) in get_projects().iter().zip(get_projects_enriched()) {
+        assert_eq!(hand_written.featured, enriched.featured, "{}", hand_written.id);
+        assert_eq!(hand_written.status, enriched.status, "{}", hand_written.id);
+    }
+
+    // Discovered repos are never featured; archived ones keep that status
+    let discovered = &get_projects_enriched()[get_projects().len()..];
+    assert!(discovered.iter().all(|p| !p.featured));
+    assert!(
+        discovered
+            .iter()
+            .all(|p| p.status == ProjectStatus::Active || p.status == ProjectStatus::Archived)
+    );
+}
 
 // =============================================================================
 // Blog Post Tests (P16-C3)
@@ -57,16 +93,29 @@ fn test_blog_posts_have_required_fields() {
 
     for post in &posts {
         assert!(!post.slug.is_empty(), "Post slug should not be empty");
-        // Title and Excerpt are now localized methods
-        assert!(!post.title(&Language::EN).is_empty(), "Post title should not be empty");
-        assert!(!post.excerpt(&Language::EN).is_empty(), "Post excerpt should not be empty");
-        
-        assert!(!post.date.is_empty(), "Post date should not be empty");
+        assert!(post.title.is_complete(), "Post '{}' title should not be empty", post.slug);
+        assert!(post.excerpt.is_complete(), "Post '{}' excerpt should not be empty", post.slug);
+        assert!(post.content.is_complete(), "Post '{}' content should not be empty", post.slug);
         assert!(post.read_time > 0, "Read time should be positive");
     }
 }
 
-// ... unique slugs, published filter, tags ... usually fine.
+#[test]
+fn test_blog_posts_sorted_newest_first() {
+    let posts = get_blog_posts();
+
+    for pair in posts.windows(2) {
+        assert!(
+            pair[0].date >= pair[1].date,
+            "'{}' ({}) should come before '{}' ({})",
+            pair[0].slug,
+            pair[0].date,
+            pair[1].slug,
+            pair[1].date
+        );
+    }
+}
+
 #[test]
 fn test_blog_posts_have_unique_slugs() {
     let posts = get_blog_posts();
@@ -104,15 +153,106 @@ fn test_blog_posts_have_tags() {
         assert!(
             !post.tags.is_empty(),
             "Post '{}' should have at least one tag",
-            post.title(&Language::EN)
+            post.title.get(&Language::EN)
         );
     }
 }
 
+#[test]
+fn test_related_posts_match_technologies_and_id() {
+    let posts = get_blog_posts();
+    let mut project = get_projects()[0].clone();
+
+    // Case and punctuation do not matter ("WebAssembly" matches "webassembly")
+    project.id = "no-such-post-tag".to_string();
+    project.technologies = vec!["rust".to_string(), "webassembly".to_string()];
+    let related = related_posts(&project, &posts, 10);
+    assert!(!related.is_empty());
+    for post in &related {
+        assert!(post.tags.iter().any(|t| t.eq_ignore_ascii_case("rust") || t.eq_ignore_ascii_case("webassembly")));
+    }
+
+    // The project id counts as a tag
+    project.technologies.clear();
+    project.id = "portfolio".to_string();
+    assert!(related_posts(&project, &posts, 10).iter().all(|p| p.tags.iter().any(|t| t == "Portfolio")));
+
+    project.id = "no-such-post-tag".to_string();
+    assert!(related_posts(&project, &posts, 10).is_empty());
+    assert!(related_posts(&get_projects()[0], &posts, 1).len() <= 1);
+}
+
 // =============================================================================
 // Skills Category Tests
 // =============================================================================
 
+#[test]
+fn test_project_technologies_have_skills() {
+    // A new technology needs a skill (or alias) in content/skills.toml, or
+    // an `untracked` entry there if it is not a skill of its own
+    let skills = get_skills();
+    let untracked = get_untracked_technologies();
+
+    for project in get_projects_enriched() {
+        for tech in &project.technologies {
+            assert!(
+                skills.iter().any(|s| s.matches(tech))
+                    || untracked.iter().any(|u| u.eq_ignore_ascii_case(tech)),
+                "project {} uses {:?}, which has no skill entry",
+                project.id,
+                tech
+            );
+        }
+    }
+}
+
+#[test]
+fn test_untracked_technologies_are_not_skills() {
+    let skills = get_skills();
+    for tech in get_untracked_technologies() {
+        assert!(
+            !skills.iter().any(|s| s.matches(tech)),
+            "{} is both a skill and untracked",
+            tech
+        );
+    }
+}
+
+#[test]
+fn test_skill_evidence_links_projects_and_posts() {
+    let skills = get_skills();
+    let rust = skills.iter().find(|s| s.name == "Rust").unwrap();
+    let mut project = get_projects()[0].clone();
+    project.technologies = vec!["rust".to_string()];
+    project.started = NaiveDate::from_ymd_opt(2020, 6, 1);
+
+    let evidence = rust.evidence(std::slice::from_ref(&project), &get_blog_posts());
+    assert_eq!(
+        evidence.projects,
+        vec![(project.id.clone(), project.title.clone())]
+    );
+    assert!(!evidence.posts.is_empty(), "the Rust posts are tagged Rust");
+
+    // The earliest of the project start and post dates
+    assert_eq!(evidence.first_used, project.started);
+    assert_eq!(
+        evidence.years(NaiveDate::from_ymd_opt(2026, 5, 31).unwrap()),
+        Some(5)
+    );
+    assert_eq!(
+        evidence.years(NaiveDate::from_ymd_opt(2026, 6, 1).unwrap()),
+        Some(6)
+    );
+
+    // A hand-set `since` wins when earlier
+    let mut rust = rust.clone();
+    rust.since = NaiveDate::from_ymd_opt(2018, 1, 1);
+    assert_eq!(rust.evidence(&[project], &[]).first_used, rust.since);
+
+    let unused = skills.iter().find(|s| s.name == "Docker").unwrap();
+    assert!(unused.evidence(&[], &get_blog_posts()).is_empty());
+}
+
 #[test]
 fn test_skill_category_labels() {
     assert_eq!(SkillCategory::Languages.label(&Language::EN), "Languages");
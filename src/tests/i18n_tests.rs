@@ -0,0 +1,178 @@
+//! i18n Tests
+//! Renders every route through SSR in both languages and checks that
+//! no hard-coded English copy leaks into the Spanish output.
+
+use std::rc::Rc;
+
+use dioxus::history::{History, MemoryHistory};
+use dioxus::prelude::*;
+
+use crate::components::blog::get_published_posts;
+use crate::components::projects::get_projects;
+use crate::i18n::{I18N_CONFIG, Language};
+use crate::routes::Route;
+
+/// English-only phrases that must never appear in the Spanish render
+const ENGLISH_LITERALS: &[&str] = &[
+    "All Posts",
+    "Blog Archive",
+    "Featured Posts",
+    "More Posts",
+    "Latest Posts",
+    "From the Blog",
+    "View All Posts",
+    "min read",
+    "Back to Blog",
+    "Post not found",
+    "Return to Archive",
+    "All Projects",
+    "Complete Portfolio",
+    "Featured Projects",
+    "Active Projects",
+    "Archived Projects",
+    "Project not found",
+    "Filter projects",
+    "Sort by",
+    "Most stars",
+    "Clear filters",
+    "No projects match",
+    "Primary language",
+    "Last push",
+    "Related posts",
+    "Skills & Expertise",
+    "What I Work With",
+    "Featured Technologies",
+    "Tech Radar",
+    "Used in",
+    "No listed project",
+    "Get In Touch",
+    "Send Message",
+    "Contact Info",
+    "Open for Projects",
+    "Your Name",
+    "Page Not Found",
+    "Back to Home",
+    "About Me",
+    "Full-Stack Developer",
+    "Lines of code across",
+];
+
+#[derive(Props, Clone, PartialEq)]
+struct HarnessProps {
+    path: String,
+    lang: Language,
+}
+
+/// Mounts the router at `path` with the given language preselected
+#[allow(non_snake_case)]
+fn Harness(props: HarnessProps) -> Element {
+    use_hook(|| {
+        provide_context(Rc::new(MemoryHistory::with_initial_path(&props.path)) as Rc<dyn History>);
+        I18N_CONFIG.write().language = props.lang;
+    });
+
+    rsx! { Router::<Route> {} }
+}
+
+fn render_route(path: &str, lang: Language) -> String {
+    let mut dom = VirtualDom::new_with_props(
+        Harness,
+        HarnessProps {
+            path: path.to_string(),
+            lang,
+        },
+    );
+    dom.rebuild_in_place();
+    dioxus::ssr::render(&dom)
+}
+
+fn all_paths() -> Vec<String> {
+    let mut paths: Vec<String> = vec![
+        "/".into(),
+        "/about".into(),
+        "/projects".into(),
+        "/projects?tech=Rust&status=active&sort=stars&q=cli".into(),
+        "/projects?q=no-such-project".into(),
+        "/projects/does-not-exist".into(),
+        "/radar".into(),
+        "/blog".into(),
+        "/blog/does-not-exist".into(),
+        "/contact".into(),
+        "/this/route/does/not/exist".into(),
+    ];
+    paths.extend(get_projects().iter().map(|p| format!("/projects/{}", p.id)));
+    paths.extend(get_published_posts().iter().map(|p| format!("/blog/{}", p.slug)));
+    paths
+}
+
+#[test]
+fn test_every_route_renders_in_both_languages() {
+    for path in all_paths() {
+        let en = render_route(&path, Language::EN);
+        let es = render_route(&path, Language::ES);
+
+        assert!(!en.is_empty(), "EN render of {} is empty", path);
+        assert!(!es.is_empty(), "ES render of {} is empty", path);
+        assert_ne!(en, es, "{} renders identically in EN and ES", path);
+    }
+}
+
+#[test]
+fn test_spanish_routes_have_no_english_literals() {
+    for path in all_paths() {
+        let es = render_route(&path, Language::ES);
+
+        for literal in ENGLISH_LITERALS {
+            assert!(
+                !es.contains(literal),
+                "Spanish render of {} contains English literal {:?}",
+                path,
+                literal
+            );
+        }
+    }
+}
+
+#[test]
+fn test_english_routes_use_english_dictionary() {
+    let home = render_route("/", Language::EN);
+    assert!(home.contains("Latest Posts"));
+    assert!(home.contains("Get In Touch"));
+
+    let filtered = render_route("/projects?tech=Rust&sort=name", Language::EN);
+    assert!(filtered.contains("Clear filters"));
+    assert!(filtered.contains("Showing"));
+
+    let missing = render_route("/nowhere", Language::EN);
+    assert!(missing.contains("Page Not Found"));
+}
+
+#[test]
+fn test_repo_stats_only_render_for_synced_projects() {
+    use crate::components::projects::get_enriched_project_by_id;
+
+    // The stats grid is the only description list on the page
+    for project in get_projects() {
+        let synced = get_enriched_project_by_id(&project.id).is_some_and(|p| p.repo_synced);
+        let html = render_route(&format!("/projects/{}", project.id), Language::EN);
+        assert_eq!(html.contains("<dl"), synced, "{}", project.id);
+    }
+}
+
+#[test]
+fn test_case_study_renders_metrics_after_the_story() {
+    use crate::domain::CaseStudySection;
+
+    for lang in [Language::EN, Language::ES] {
+        let html = render_route("/projects/enerby-dev", lang);
+        // Section headings, not the same words in the description
+        let position = |section: CaseStudySection| {
+            html.find(&format!("{}</h2>", section.label(&lang)))
+                .unwrap()
+        };
+
+        assert!(position(CaseStudySection::Challenge) < position(CaseStudySection::Architecture));
+        assert!(position(CaseStudySection::Architecture) < position(CaseStudySection::Outcome));
+        assert!(position(CaseStudySection::Outcome) < position(CaseStudySection::Metrics));
+    }
+}
@@ -0,0 +1,129 @@
+//! Mock HTTP Server
+//! Canned responses on an ephemeral port, for tests of the native HTTP clients
+//! (`utils::github_sync`, `utils::contact`)
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{Receiver, Sender, channel};
+use std::thread;
+
+/// Canned reply for one path
+#[derive(Clone, Copy, Debug)]
+pub struct MockResponse {
+    /// e.g. "200 OK"
+    pub status: &'static str,
+    /// Extra header lines, each ending in `\r\n`
+    pub headers: &'static str,
+    pub body: &'static str,
+}
+
+impl MockResponse {
+    pub const fn new(status: &'static str, body: &'static str) -> Self {
+        Self {
+            status,
+            headers: "",
+            body,
+        }
+    }
+}
+
+/// Reply for paths missing from the table
+pub const NOT_FOUND: MockResponse =
+    MockResponse::new("404 Not Found", r#"{"message":"Not Found"}"#);
+
+/// Request received by the mock server
+#[derive(Debug)]
+pub struct ReceivedRequest {
+    /// e.g. "POST /hook HTTP/1.1"
+    pub request_line: String,
+    pub headers: Vec<String>,
+    pub body: String,
+}
+
+/// Server answering from a path → response table until the test ends
+pub struct MockServer {
+    /// `http://127.0.0.1:<port>`, without a trailing slash
+    pub base_url: String,
+    received: Receiver<ReceivedRequest>,
+}
+
+impl MockServer {
+    /// Serves `routes`, matched on the full path including the query string
+    pub fn spawn(routes: &'static [(&'static str, MockResponse)]) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind mock server");
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, received) = channel();
+
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                handle_request(stream, routes, &sender);
+            }
+        });
+        Self { base_url, received }
+    }
+
+    /// The oldest request not yet taken, waiting for it if needed
+    pub fn next_request(&self) -> ReceivedRequest {
+        self.received.recv().expect("mock server request")
+    }
+}
+
+/// Base URL of a local port nothing listens on
+pub fn closed_port_url() -> String {
+    // Bind then drop to get a free port
+    let port = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    format!("http://127.0.0.1:{}", port)
+}
+
+fn handle_request(
+    mut stream: TcpStream,
+    routes: &[(&str, MockResponse)],
+    sender: &Sender<ReceivedRequest>,
+) {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).unwrap();
+
+    let mut headers = Vec::new();
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).unwrap_or(0) <= 2 {
+            break;
+        }
+        let line = line.trim_end().to_string();
+        if let Some((name, value)) = line.split_once(':')
+            && name.eq_ignore_ascii_case("content-length")
+        {
+            content_length = value.trim().parse().unwrap_or(0);
+        }
+        headers.push(line);
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).unwrap();
+
+    let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+    let reply = routes
+        .iter()
+        .find(|(route, _)| *route == path)
+        .map_or(NOT_FOUND, |(_, reply)| *reply);
+
+    // Nobody may be listening for requests; that is fine
+    let _ = sender.send(ReceivedRequest {
+        request_line: request_line.trim_end().to_string(),
+        headers,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    });
+    let response = format!(
+        "HTTP/1.1 {}\r\n{}Content-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        reply.status,
+        reply.headers,
+        reply.body.len(),
+        reply.body
+    );
+    let _ = stream.write_all(response.as_bytes());
+}
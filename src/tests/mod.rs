@@ -12,3 +12,9 @@ mod utils_tests;
 
 #[cfg(test)]
 mod component_tests;
+
+#[cfg(test)]
+mod i18n_tests;
+
+#[cfg(test)]
+pub(crate) mod mock_http;
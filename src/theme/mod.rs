@@ -37,6 +37,87 @@ pub mod colors {
     pub const ERROR: &str = "#FF3366";
 }
 
+/// Programming language colors (GitHub linguist palette)
+pub mod languages {
+    use super::colors;
+
+    /// Language → color, as GitHub shows them in repository language bars
+    pub const LANGUAGE_COLORS: &[(&str, &str)] = &[
+        ("Astro", "#ff5a03"),
+        ("C", "#555555"),
+        ("C#", "#178600"),
+        ("C++", "#f34b7d"),
+        ("CSS", "#663399"),
+        ("Dart", "#00B4AB"),
+        ("Dockerfile", "#384d54"),
+        ("Go", "#00ADD8"),
+        ("HTML", "#e34c26"),
+        ("Java", "#b07219"),
+        ("JavaScript", "#f1e05a"),
+        ("Kotlin", "#A97BFF"),
+        ("Lua", "#000080"),
+        ("Makefile", "#427819"),
+        ("Nix", "#7e7eff"),
+        ("PHP", "#4F5D95"),
+        ("Python", "#3572A5"),
+        ("Ruby", "#701516"),
+        ("Rust", "#dea584"),
+        ("SCSS", "#c6538c"),
+        ("Shell", "#89e051"),
+        ("Svelte", "#ff3e00"),
+        ("Swift", "#F05138"),
+        ("TypeScript", "#3178c6"),
+        ("Vim Script", "#199f4b"),
+        ("Vue", "#41b883"),
+    ];
+
+    /// Theme colors for languages missing from the table
+    pub const FALLBACK_COLORS: [&str; 4] = [
+        colors::PRIMARY,
+        colors::SECONDARY_PINK,
+        colors::SECONDARY_PURPLE,
+        colors::SECONDARY_ORANGE,
+    ];
+
+    /// Color of the folded "other" segment
+    pub const OTHER: &str = colors::TEXT_MUTED;
+
+    /// Color for a language (case-insensitive)
+    ///
+    /// Unknown languages get a theme color picked from their name, so the same
+    /// language always has the same color.
+    pub fn language_color(language: &str) -> &'static str {
+        LANGUAGE_COLORS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(language))
+            .map(|(_, color)| *color)
+            .unwrap_or_else(|| {
+                let hash = language.bytes().fold(0usize, |acc, b| {
+                    acc.wrapping_mul(31).wrapping_add(b as usize)
+                });
+                FALLBACK_COLORS[hash % FALLBACK_COLORS.len()]
+            })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_known_language_colors() {
+            assert_eq!(language_color("Rust"), "#dea584");
+            assert_eq!(language_color("typescript"), "#3178c6");
+        }
+
+        #[test]
+        fn test_unknown_language_falls_back_to_theme() {
+            let color = language_color("Brainfuck-ish");
+            assert!(FALLBACK_COLORS.contains(&color));
+            assert_eq!(language_color("Brainfuck-ish"), color);
+        }
+    }
+}
+
 // ============================================
 // Typography
 // ============================================
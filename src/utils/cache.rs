@@ -0,0 +1,847 @@
+//! Generic Cache Module
+//!
+//! `Cache<K, V>` is the key-value store behind `GitHubCache` and any other
+//! data the site caches (screenshots, contributor stats, translations).
+//!
+//! # Features
+//!
+//! - Per-entry TTL. Expired entries stay available as stale fallbacks until
+//!   they are cleaned up or evicted.
+//! - Optional maximum entry count with least-recently-used eviction
+//! - Hit/miss/eviction counters (`CacheStats`)
+//! - Pluggable persistence through `CacheStorage` (file, localStorage, memory)
+//!
+//! Time is read from a `Clock`, so TTLs work in WASM and tests can use a
+//! `MockClock`.
+//!
+//! # Example
+//! ```
+//! use enerby_dev::utils::cache::Cache;
+//!
+//! let mut cache: Cache<String, u32> = Cache::new(60).with_max_entries(2);
+//! cache.insert("a".to_string(), 1);
+//! cache.insert("b".to_string(), 2);
+//! cache.get("a"); // "a" is now the most recently used
+//! cache.insert("c".to_string(), 3); // evicts "b"
+//!
+//! assert!(cache.get("b").is_none());
+//! assert_eq!(cache.stats().evictions, 1);
+//! ```
+
+use serde::de::DeserializeOwned;
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use super::clock::{Clock, SharedClock, default_clock};
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+/// Default cache TTL in seconds (1 hour)
+pub const DEFAULT_TTL_SECONDS: u64 = 3600;
+
+fn default_ttl() -> u64 {
+    DEFAULT_TTL_SECONDS
+}
+
+// ============================================================================
+// CACHE ENTRY
+// ============================================================================
+
+/// A cached entry with timestamp for TTL calculation
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CacheEntry<T> {
+    /// The cached data
+    pub data: T,
+    /// Unix timestamp when the entry was cached
+    pub cached_at: u64,
+    /// TTL in seconds (for this specific entry)
+    pub ttl_seconds: u64,
+    /// HTTP `ETag` of the response, sent back as `If-None-Match`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+}
+
+impl<T> CacheEntry<T> {
+    /// Creates a new cache entry stamped with the clock's current time
+    pub fn new(data: T, ttl_seconds: u64, clock: &dyn Clock) -> Self {
+        Self {
+            data,
+            cached_at: clock.now_seconds(),
+            ttl_seconds,
+            etag: None,
+        }
+    }
+
+    /// Creates a new cache entry with default TTL
+    pub fn with_default_ttl(data: T, clock: &dyn Clock) -> Self {
+        Self::new(data, DEFAULT_TTL_SECONDS, clock)
+    }
+
+    /// Checks if this cache entry has expired
+    pub fn is_expired(&self, clock: &dyn Clock) -> bool {
+        clock.now_seconds() > self.cached_at + self.ttl_seconds
+    }
+
+    /// Returns the age of this cache entry in seconds
+    pub fn age_seconds(&self, clock: &dyn Clock) -> u64 {
+        clock.now_seconds().saturating_sub(self.cached_at)
+    }
+
+    /// Returns remaining TTL in seconds (0 if expired)
+    pub fn remaining_ttl(&self, clock: &dyn Clock) -> u64 {
+        let expiry = self.cached_at + self.ttl_seconds;
+
+        expiry.saturating_sub(clock.now_seconds())
+    }
+}
+
+// ============================================================================
+// STATISTICS
+// ============================================================================
+
+/// Snapshot of a cache's counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Lookups that found a fresh entry
+    pub hits: u64,
+    /// Lookups that found nothing or only a stale entry
+    pub misses: u64,
+    /// Entries dropped to stay within `max_entries`
+    pub evictions: u64,
+}
+
+impl CacheStats {
+    /// Fraction of lookups that were hits (0.0 before any lookup)
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+/// Atomic counters so lookups through `&self` (e.g. behind a read lock) count
+#[derive(Debug, Default)]
+struct Counters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
+}
+
+impl Counters {
+    fn snapshot(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            evictions: self.evictions.load(Ordering::Relaxed),
+        }
+    }
+
+    fn record_lookup(&self, hit: bool) {
+        let counter = if hit { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+// ============================================================================
+// CACHE
+// ============================================================================
+
+/// An entry plus its position in the LRU order
+#[derive(Debug)]
+struct Slot<V> {
+    entry: CacheEntry<V>,
+    /// Value of `Cache::tick` at the last access
+    last_used: AtomicU64,
+}
+
+impl<V: Clone> Clone for Slot<V> {
+    fn clone(&self) -> Self {
+        Self {
+            entry: self.entry.clone(),
+            last_used: AtomicU64::new(self.last_used.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+/// Key-value cache with TTL, optional LRU bound and hit/miss counters
+///
+/// Serializes as `{ "entries": { key: CacheEntry }, "default_ttl": secs }`.
+/// The entry limit, clock and counters are runtime settings and are not
+/// persisted.
+#[derive(Debug)]
+pub struct Cache<K, V> {
+    entries: HashMap<K, Slot<V>>,
+    /// TTL for entries inserted without an explicit one
+    default_ttl: u64,
+    /// Maximum number of entries (`None` = unbounded)
+    max_entries: Option<usize>,
+    /// Time source for TTLs
+    clock: SharedClock,
+    /// Monotonic access counter used for LRU ordering
+    tick: AtomicU64,
+    counters: Counters,
+}
+
+impl<K: Eq + Hash + Clone, V> Cache<K, V> {
+    /// Creates an unbounded cache using the real clock
+    pub fn new(default_ttl: u64) -> Self {
+        Self {
+            entries: HashMap::new(),
+            default_ttl,
+            max_entries: None,
+            clock: default_clock(),
+            tick: AtomicU64::new(0),
+            counters: Counters::default(),
+        }
+    }
+
+    /// Limits the cache to `max_entries` (at least 1), evicting the least recently used
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries.max(1));
+        self
+    }
+
+    /// Reads time from `clock` instead of the real clock
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the TTL used by `insert`
+    pub fn default_ttl(&self) -> u64 {
+        self.default_ttl
+    }
+
+    /// Returns the entry limit, if any
+    pub fn max_entries(&self) -> Option<usize> {
+        self.max_entries
+    }
+
+    /// Returns the cache's time source
+    pub fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
+
+    /// Returns a snapshot of the hit/miss/eviction counters
+    pub fn stats(&self) -> CacheStats {
+        self.counters.snapshot()
+    }
+
+    fn next_tick(&self) -> u64 {
+        self.tick.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    fn mark_used(&self, slot: &Slot<V>) {
+        slot.last_used.store(self.next_tick(), Ordering::Relaxed);
+    }
+
+    // =========================================================================
+    // Lookups
+    // =========================================================================
+
+    /// Gets a value if it exists and is not expired
+    ///
+    /// Counts a hit or a miss and marks the entry as recently used.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_with_stale(key)
+            .and_then(|(data, is_stale)| (!is_stale).then_some(data))
+    }
+
+    /// Gets a value even if expired (for fallback use)
+    ///
+    /// Returns `Some((data, is_stale))` if the entry exists. Stale entries
+    /// count as misses.
+    pub fn get_with_stale<Q>(&self, key: &Q) -> Option<(&V, bool)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self.entries.get(key) {
+            Some(slot) => {
+                self.mark_used(slot);
+                let is_stale = slot.entry.is_expired(self.clock());
+                self.counters.record_lookup(!is_stale);
+                Some((&slot.entry.data, is_stale))
+            }
+            None => {
+                self.counters.record_lookup(false);
+                None
+            }
+        }
+    }
+
+    /// Returns the raw entry (fresh or stale) without touching counters or LRU order
+    pub fn entry<Q>(&self, key: &Q) -> Option<&CacheEntry<V>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.entries.get(key).map(|slot| &slot.entry)
+    }
+
+    /// Checks if a fresh entry exists, without counting a lookup
+    pub fn contains_fresh<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.entry(key)
+            .is_some_and(|entry| !entry.is_expired(self.clock()))
+    }
+
+    /// Checks if any entry (including stale) exists
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.entries.contains_key(key)
+    }
+
+    // =========================================================================
+    // Updates
+    // =========================================================================
+
+    /// Inserts a value with the default TTL
+    pub fn insert(&mut self, key: K, value: V) {
+        self.insert_with_ttl(key, value, self.default_ttl);
+    }
+
+    /// Inserts a value with a custom TTL
+    pub fn insert_with_ttl(&mut self, key: K, value: V, ttl_seconds: u64) {
+        let entry = CacheEntry::new(value, ttl_seconds, self.clock());
+        self.insert_entry(key, entry);
+    }
+
+    /// Inserts a prepared entry as is, evicting the least recently used if full
+    pub fn insert_entry(&mut self, key: K, entry: CacheEntry<V>) {
+        if let Some(max) = self.max_entries {
+            while !self.entries.contains_key(&key) && self.entries.len() >= max {
+                self.evict_lru();
+            }
+        }
+
+        let last_used = AtomicU64::new(self.next_tick());
+        self.entries.insert(key, Slot { entry, last_used });
+    }
+
+    /// Restarts an entry's TTL without changing its data
+    ///
+    /// Returns false if there is no such entry.
+    pub fn touch<Q>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let now = self.clock.now_seconds();
+        match self.entries.get_mut(key) {
+            Some(slot) => {
+                slot.entry.cached_at = now;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes an entry, returning its value
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.entries.remove(key).map(|slot| slot.entry.data)
+    }
+
+    /// Removes all entries (counters are kept)
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Drops the least recently used entry; ties go to the oldest `cached_at`
+    fn evict_lru(&mut self) {
+        let victim = self
+            .entries
+            .iter()
+            .min_by_key(|(_, slot)| (slot.last_used.load(Ordering::Relaxed), slot.entry.cached_at))
+            .map(|(key, _)| key.clone());
+
+        if let Some(key) = victim {
+            self.entries.remove(&key);
+            self.counters.evictions.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    // =========================================================================
+    // Bulk Operations
+    // =========================================================================
+
+    /// Returns the number of entries (including expired)
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks if the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the number of non-expired entries
+    pub fn len_valid(&self) -> usize {
+        let clock = self.clock();
+        self.entries
+            .values()
+            .filter(|slot| !slot.entry.is_expired(clock))
+            .count()
+    }
+
+    /// Removes all expired entries
+    pub fn cleanup_expired(&mut self) {
+        let clock = self.clock.as_ref();
+        self.entries.retain(|_, slot| !slot.entry.is_expired(clock));
+    }
+
+    /// Iterates over all values (including stale ones)
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.entries.values().map(|slot| &slot.entry.data)
+    }
+
+    /// Iterates over all keys and raw entries
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &CacheEntry<V>)> {
+        self.entries.iter().map(|(key, slot)| (key, &slot.entry))
+    }
+
+    /// Merges another cache into this one
+    /// Newer entries (by timestamp) take precedence
+    pub fn merge(&mut self, other: Self) {
+        for (key, slot) in other.entries {
+            match self.entries.get(&key) {
+                Some(existing) if existing.entry.cached_at >= slot.entry.cached_at => {
+                    // Keep existing (newer or same age)
+                }
+                _ => self.insert_entry(key, slot.entry),
+            }
+        }
+    }
+}
+
+impl<K: Clone, V: Clone> Clone for Cache<K, V> {
+    fn clone(&self) -> Self {
+        let counters = self.counters.snapshot();
+        Self {
+            entries: self.entries.clone(),
+            default_ttl: self.default_ttl,
+            max_entries: self.max_entries,
+            clock: Arc::clone(&self.clock),
+            tick: AtomicU64::new(self.tick.load(Ordering::Relaxed)),
+            counters: Counters {
+                hits: AtomicU64::new(counters.hits),
+                misses: AtomicU64::new(counters.misses),
+                evictions: AtomicU64::new(counters.evictions),
+            },
+        }
+    }
+}
+
+// ============================================================================
+// SERIALIZATION
+// ============================================================================
+
+/// Serializes the slot map as `key -> CacheEntry`
+struct EntriesRef<'a, K, V>(&'a HashMap<K, Slot<V>>);
+
+impl<K: Serialize, V: Serialize> Serialize for EntriesRef<'_, K, V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.iter().map(|(key, slot)| (key, &slot.entry)))
+    }
+}
+
+impl<K: Serialize, V: Serialize> Serialize for Cache<K, V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Cache", 2)?;
+        state.serialize_field("entries", &EntriesRef(&self.entries))?;
+        state.serialize_field("default_ttl", &self.default_ttl)?;
+        state.end()
+    }
+}
+
+/// Persisted form of a cache
+#[derive(Deserialize)]
+struct CacheData<K: Eq + Hash, V> {
+    entries: HashMap<K, CacheEntry<V>>,
+    #[serde(default = "default_ttl")]
+    default_ttl: u64,
+}
+
+impl<'de, K, V> Deserialize<'de> for Cache<K, V>
+where
+    K: Deserialize<'de> + Eq + Hash + Clone,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = CacheData::<K, V>::deserialize(deserializer)?;
+        let mut cache = Cache::new(data.default_ttl);
+        for (key, entry) in data.entries {
+            cache.insert_entry(key, entry);
+        }
+        Ok(cache)
+    }
+}
+
+impl<K, V> Cache<K, V>
+where
+    K: Serialize + DeserializeOwned + Eq + Hash + Clone,
+    V: Serialize + DeserializeOwned,
+{
+    /// Serializes the cache to a JSON string
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Deserializes a cache from a JSON string
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Loads a cache from storage; `None` if nothing is stored or it does not parse
+    pub fn load(storage: &dyn CacheStorage) -> Option<Self> {
+        storage.read().and_then(|json| Self::from_json(&json).ok())
+    }
+
+    /// Writes the cache to storage as pretty JSON
+    ///
+    /// Keys are sorted so committed snapshots diff cleanly between runs.
+    pub fn save(&self, storage: &dyn CacheStorage) -> Result<(), String> {
+        // serde_json::Value keeps object keys sorted
+        let value = serde_json::to_value(self).map_err(|e| e.to_string())?;
+        let json = serde_json::to_string_pretty(&value).map_err(|e| e.to_string())?;
+        storage.write(&(json + "\n"))
+    }
+}
+
+// ============================================================================
+// PERSISTENCE
+// ============================================================================
+
+/// Where a serialized cache is kept
+pub trait CacheStorage {
+    /// Returns the stored contents, or `None` if nothing is stored
+    fn read(&self) -> Option<String>;
+
+    /// Replaces the stored contents
+    fn write(&self, contents: &str) -> Result<(), String>;
+}
+
+/// Stores the cache in a file, creating parent directories as needed
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone)]
+pub struct FileStorage {
+    path: PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileStorage {
+    /// Creates a storage backed by `path`
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl CacheStorage for FileStorage {
+    fn read(&self) -> Option<String> {
+        std::fs::read_to_string(&self.path).ok()
+    }
+
+    fn write(&self, contents: &str) -> Result<(), String> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        std::fs::write(&self.path, contents).map_err(|e| e.to_string())
+    }
+}
+
+/// Stores the cache in the browser's `localStorage` under a key
+#[cfg(target_arch = "wasm32")]
+#[derive(Debug, Clone)]
+pub struct BrowserStorage {
+    key: String,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl BrowserStorage {
+    /// Creates a storage backed by `localStorage[key]`
+    pub fn new(key: impl Into<String>) -> Self {
+        Self { key: key.into() }
+    }
+
+    fn local_storage() -> Option<web_sys::Storage> {
+        web_sys::window()?.local_storage().ok()?
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl CacheStorage for BrowserStorage {
+    fn read(&self) -> Option<String> {
+        Self::local_storage()?.get_item(&self.key).ok()?
+    }
+
+    fn write(&self, contents: &str) -> Result<(), String> {
+        Self::local_storage()
+            .ok_or("localStorage unavailable")?
+            .set_item(&self.key, contents)
+            .map_err(|_| "localStorage write failed".to_string())
+    }
+}
+
+/// In-memory storage; clones share the same contents
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStorage {
+    contents: Arc<Mutex<Option<String>>>,
+}
+
+impl CacheStorage for MemoryStorage {
+    fn read(&self) -> Option<String> {
+        self.contents.lock().ok()?.clone()
+    }
+
+    fn write(&self, contents: &str) -> Result<(), String> {
+        let mut stored = self.contents.lock().map_err(|e| e.to_string())?;
+        *stored = Some(contents.to_string());
+        Ok(())
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::clock::MockClock;
+
+    /// Fixed start time for mock clocks
+    const T0: u64 = 1_700_000_000;
+
+    fn mock_cache(ttl: u64) -> (Cache<String, u32>, MockClock) {
+        let clock = MockClock::new(T0);
+        let cache = Cache::new(ttl).with_clock(Arc::new(clock.clone()));
+        (cache, clock)
+    }
+
+    // -------------------------
+    // TTL Tests
+    // -------------------------
+
+    #[test]
+    fn test_ttl_expiry_and_stale_fallback() {
+        let (mut cache, clock) = mock_cache(60);
+        cache.insert("a".to_string(), 1);
+        cache.insert_with_ttl("b".to_string(), 2, 600);
+
+        clock.advance(61);
+        assert_eq!(cache.get("a"), None);
+        assert_eq!(cache.get_with_stale("a"), Some((&1, true)));
+        assert_eq!(cache.get("b"), Some(&2));
+        assert_eq!(cache.len_valid(), 1);
+
+        cache.cleanup_expired();
+        assert!(!cache.contains_key("a"));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_touch_restarts_ttl() {
+        let (mut cache, clock) = mock_cache(60);
+        cache.insert("a".to_string(), 1);
+
+        clock.advance(61);
+        assert!(!cache.contains_fresh("a"));
+        assert!(cache.touch("a"));
+        assert!(cache.contains_fresh("a"));
+        assert!(!cache.touch("missing"));
+    }
+
+    // -------------------------
+    // LRU Tests
+    // -------------------------
+
+    #[test]
+    fn test_lru_evicts_least_recently_used() {
+        let (cache, _clock) = mock_cache(60);
+        let mut cache = cache.with_max_entries(2);
+
+        cache.insert("a".to_string(), 1);
+        cache.insert("b".to_string(), 2);
+        assert_eq!(cache.get("a"), Some(&1));
+        cache.insert("c".to_string(), 3);
+
+        assert!(cache.contains_key("a"));
+        assert!(!cache.contains_key("b"));
+        assert!(cache.contains_key("c"));
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.stats().evictions, 1);
+    }
+
+    #[test]
+    fn test_lru_replacing_key_does_not_evict() {
+        let (cache, _clock) = mock_cache(60);
+        let mut cache = cache.with_max_entries(2);
+
+        cache.insert("a".to_string(), 1);
+        cache.insert("b".to_string(), 2);
+        cache.insert("a".to_string(), 10);
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get("a"), Some(&10));
+        assert_eq!(cache.stats().evictions, 0);
+    }
+
+    #[test]
+    fn test_peeking_does_not_change_lru_order() {
+        let (cache, _clock) = mock_cache(60);
+        let mut cache = cache.with_max_entries(2);
+
+        cache.insert("a".to_string(), 1);
+        cache.insert("b".to_string(), 2);
+        assert!(cache.entry("a").is_some());
+        assert!(cache.contains_fresh("a"));
+        cache.insert("c".to_string(), 3);
+
+        assert!(!cache.contains_key("a"));
+        assert_eq!(cache.stats().hits + cache.stats().misses, 0);
+    }
+
+    #[test]
+    fn test_max_entries_applies_to_merge() {
+        let (cache, clock) = mock_cache(60);
+        let mut cache = cache.with_max_entries(2);
+        cache.insert("a".to_string(), 1);
+
+        let mut other = Cache::new(60).with_clock(Arc::new(clock.clone()));
+        clock.advance(1);
+        other.insert("b".to_string(), 2);
+        other.insert("c".to_string(), 3);
+        cache.merge(other);
+
+        assert_eq!(cache.len(), 2);
+        assert!(!cache.contains_key("a"));
+    }
+
+    // -------------------------
+    // Stats Tests
+    // -------------------------
+
+    #[test]
+    fn test_hit_miss_counters() {
+        let (mut cache, clock) = mock_cache(60);
+        cache.insert("a".to_string(), 1);
+
+        cache.get("a");
+        cache.get("a");
+        cache.get("missing");
+        clock.advance(61);
+        cache.get_with_stale("a");
+
+        let stats = cache.stats();
+        assert_eq!(stats.hits, 2);
+        assert_eq!(stats.misses, 2);
+        assert_eq!(stats.hit_rate(), 0.5);
+        assert_eq!(CacheStats::default().hit_rate(), 0.0);
+    }
+
+    // -------------------------
+    // Merge Tests
+    // -------------------------
+
+    #[test]
+    fn test_merge_newer_entry_wins() {
+        let (mut cache, clock) = mock_cache(60);
+        cache.insert("a".to_string(), 1);
+        cache.insert("b".to_string(), 1);
+
+        let mut other = Cache::new(60).with_clock(Arc::new(clock.clone()));
+        other.insert_entry(
+            "a".to_string(),
+            CacheEntry::new(99, 60, &MockClock::new(T0 - 10)),
+        );
+        clock.advance(10);
+        other.insert("b".to_string(), 42);
+        cache.merge(other);
+
+        assert_eq!(cache.get("a"), Some(&1));
+        assert_eq!(cache.get("b"), Some(&42));
+    }
+
+    // -------------------------
+    // Persistence Tests
+    // -------------------------
+
+    #[test]
+    fn test_json_format() {
+        let (mut cache, _clock) = mock_cache(120);
+        cache.insert("a".to_string(), 1);
+
+        let value: serde_json::Value = serde_json::from_str(&cache.to_json().unwrap()).unwrap();
+        assert_eq!(value["default_ttl"], 120);
+        assert_eq!(value["entries"]["a"]["data"], 1);
+        assert_eq!(value["entries"]["a"]["cached_at"], T0);
+        assert_eq!(value["entries"]["a"]["ttl_seconds"], 120);
+    }
+
+    #[test]
+    fn test_storage_round_trip() {
+        let storage = MemoryStorage::default();
+        assert!(Cache::<String, u32>::load(&storage).is_none());
+
+        let (mut cache, _clock) = mock_cache(60);
+        cache.insert("b".to_string(), 2);
+        cache.insert("a".to_string(), 1);
+        cache.save(&storage).unwrap();
+
+        let json = storage.read().unwrap();
+        assert!(json.find("\"a\"").unwrap() < json.find("\"b\"").unwrap());
+        assert!(json.ends_with('\n'));
+
+        let restored = Cache::<String, u32>::load(&storage).unwrap();
+        assert_eq!(restored.len(), 2);
+        assert_eq!(restored.entry("a").unwrap().cached_at, T0);
+        assert_eq!(restored.default_ttl(), 60);
+    }
+
+    #[test]
+    fn test_load_invalid_json() {
+        let storage = MemoryStorage::default();
+        storage.write("not json").unwrap();
+        assert!(Cache::<String, u32>::load(&storage).is_none());
+    }
+
+    #[test]
+    fn test_file_storage_creates_parent_dirs() {
+        let dir = std::env::temp_dir().join(format!("cache_storage_test_{}", std::process::id()));
+        let storage = FileStorage::new(dir.join("nested").join("cache.json"));
+
+        let written = storage.write("{}");
+        let read = storage.read();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert!(written.is_ok());
+        assert_eq!(read.as_deref(), Some("{}"));
+    }
+}
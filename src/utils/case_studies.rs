@@ -0,0 +1,216 @@
+//! Case Studies Module
+//! Loads the long-form write-up of a project from `content/projects/`
+//!
+//! ## Format
+//! One file per project and language, `<project-id>.<lang>.md`, split into
+//! sections by `##` headings (see `CaseStudySection::headings`):
+//!
+//! ```markdown
+//! ## Challenge
+//! Prose...
+//!
+//! ## Metrics
+//! - WASM bundle: −40%
+//! - First paint: 0.8s
+//! ```
+//!
+//! Text before the first section heading is ignored, other `##` headings stay
+//! inside the current section. Metrics are `label: value` list items.
+//! A missing Spanish file falls back to the English one.
+
+use std::collections::HashMap;
+
+use gray_matter::{Matter, engine::YAML};
+use pulldown_cmark::{Parser, html};
+use rust_embed::RustEmbed;
+
+use crate::domain::{CaseStudy, CaseStudySection, Metric};
+use crate::i18n::Language;
+
+#[derive(RustEmbed)]
+#[folder = "content/projects/"]
+struct ProjectContent;
+
+/// Case study of a project in `lang` (or English), if it has one
+pub fn get_case_study(project_id: &str, lang: &Language) -> Option<CaseStudy> {
+    [
+        format!("{}.{}.md", project_id, lang),
+        format!("{}.en.md", project_id),
+        format!("{}.md", project_id),
+    ]
+    .iter()
+    .find_map(|name| ProjectContent::get(name))
+    .and_then(|file| {
+        std::str::from_utf8(file.data.as_ref())
+            .ok()
+            .map(parse_case_study)
+    })
+    .filter(|case_study| !case_study.is_empty())
+}
+
+/// Splits markdown (with optional frontmatter) into case study sections
+pub fn parse_case_study(markdown: &str) -> CaseStudy {
+    let body = Matter::<YAML>::new().parse(markdown).content;
+
+    let mut sections: HashMap<CaseStudySection, String> = HashMap::new();
+    let mut current: Option<CaseStudySection> = None;
+    let mut in_code_block = false;
+
+    for line in body.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        }
+
+        let heading = line.strip_prefix("## ").filter(|_| !in_code_block);
+        if let Some(section) = heading.and_then(CaseStudySection::from_heading) {
+            current = Some(section);
+            continue;
+        }
+
+        if let Some(section) = current {
+            let text = sections.entry(section).or_default();
+            text.push_str(line);
+            text.push('\n');
+        }
+    }
+
+    let prose = |section| {
+        sections
+            .get(&section)
+            .filter(|text| !text.trim().is_empty())
+            .map(|text| render_markdown(text))
+    };
+
+    CaseStudy {
+        challenge: prose(CaseStudySection::Challenge),
+        architecture: prose(CaseStudySection::Architecture),
+        outcome: prose(CaseStudySection::Outcome),
+        metrics: sections
+            .get(&CaseStudySection::Metrics)
+            .map(|text| parse_metrics(text))
+            .unwrap_or_default(),
+    }
+}
+
+/// `- label: value` list items; other lines are skipped
+fn parse_metrics(text: &str) -> Vec<Metric> {
+    text.lines()
+        .filter_map(|line| {
+            let item = line.trim().strip_prefix(['-', '*'])?;
+            let (label, value) = item.split_once(':')?;
+            let (label, value) = (label.trim(), value.trim());
+            (!label.is_empty() && !value.is_empty()).then(|| Metric {
+                label: label.to_string(),
+                value: value.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn render_markdown(text: &str) -> String {
+    let mut html_output = String::new();
+    html::push_html(&mut html_output, Parser::new(text));
+    html_output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CASE_STUDY: &str = "\
+Intro that is not part of any section.
+
+## Challenge
+Pages took **seconds** to load.
+
+## Architecture
+Rust compiled to WASM.
+
+## Notes
+Kept inside the architecture section.
+
+```text
+## Outcome
+```
+
+## Outcome
+Instant navigation.
+
+## Metrics
+- WASM bundle: −40%
+- First paint: 0.8s
+- not a metric
+* Requests/page: 3
+";
+
+    #[test]
+    fn test_parse_case_study_sections() {
+        let case_study = parse_case_study(CASE_STUDY);
+
+        let challenge = case_study.challenge.unwrap();
+        assert!(challenge.contains("<strong>seconds</strong>"));
+        assert!(!challenge.contains("Intro"));
+
+        // Unknown headings and fenced code stay in the current section
+        let architecture = case_study.architecture.unwrap();
+        assert!(architecture.contains("Notes"));
+        assert!(architecture.contains("## Outcome"));
+
+        assert_eq!(case_study.outcome.unwrap(), "<p>Instant navigation.</p>\n");
+    }
+
+    #[test]
+    fn test_parse_case_study_metrics() {
+        let metrics = parse_case_study(CASE_STUDY).metrics;
+        let pairs: Vec<(&str, &str)> = metrics
+            .iter()
+            .map(|m| (m.label.as_str(), m.value.as_str()))
+            .collect();
+
+        assert_eq!(
+            pairs,
+            vec![
+                ("WASM bundle", "−40%"),
+                ("First paint", "0.8s"),
+                ("Requests/page", "3")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_case_study_spanish_headings_and_frontmatter() {
+        let markdown =
+            "---\nproject: demo\n---\n## Reto\nAlgo difícil.\n\n## métricas\n- Bundle: -40%\n";
+        let case_study = parse_case_study(markdown);
+
+        assert!(case_study.challenge.unwrap().contains("Algo difícil"));
+        assert_eq!(case_study.metrics.len(), 1);
+        assert!(case_study.architecture.is_none());
+    }
+
+    #[test]
+    fn test_parse_case_study_without_sections_is_empty() {
+        assert!(parse_case_study("Just a paragraph.\n\n## Something else\n").is_empty());
+        assert!(parse_case_study("## Challenge\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_get_case_study_missing_project() {
+        assert_eq!(get_case_study("no-such-project", &Language::ES), None);
+    }
+
+    #[test]
+    fn test_shipped_case_study_has_every_section_in_both_languages() {
+        let en = get_case_study("enerby-dev", &Language::EN).unwrap();
+        let es = get_case_study("enerby-dev", &Language::ES).unwrap();
+
+        for case_study in [&en, &es] {
+            assert!(case_study.challenge.is_some());
+            assert!(case_study.architecture.is_some());
+            assert!(case_study.outcome.is_some());
+            assert_eq!(case_study.metrics.len(), 4);
+        }
+        assert_ne!(en.challenge, es.challenge);
+        assert_eq!(es.metrics[0].label, "Rutas");
+    }
+}
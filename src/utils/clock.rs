@@ -0,0 +1,138 @@
+//! Clock Module
+//!
+//! Time source for TTL calculations. `std::time::SystemTime::now()` panics on
+//! `wasm32-unknown-unknown`, so caches read the time through the `Clock` trait:
+//!
+//! - `SystemClock`: native, backed by `SystemTime`
+//! - `JsClock`: browser, backed by JavaScript `Date.now()`
+//! - `MockClock`: manually advanced, for tests
+//!
+//! # Example
+//! ```
+//! use enerby_dev::utils::clock::{Clock, MockClock};
+//!
+//! let clock = MockClock::new(1_000);
+//! clock.advance(60);
+//! assert_eq!(clock.now_seconds(), 1_060);
+//! ```
+
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Source of the current Unix time in seconds
+pub trait Clock: Debug + Send + Sync {
+    /// Returns the current Unix timestamp in seconds
+    fn now_seconds(&self) -> u64;
+}
+
+/// Shared handle to a clock, as stored by caches
+pub type SharedClock = Arc<dyn Clock>;
+
+/// Returns the real clock for the current target
+pub fn default_clock() -> SharedClock {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        Arc::new(SystemClock)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Arc::new(JsClock)
+    }
+}
+
+// ============================================================================
+// REAL CLOCKS
+// ============================================================================
+
+/// Native clock backed by `SystemTime`
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Clock for SystemClock {
+    fn now_seconds(&self) -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs()
+    }
+}
+
+/// Browser clock backed by JavaScript `Date.now()`
+#[cfg(target_arch = "wasm32")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsClock;
+
+#[cfg(target_arch = "wasm32")]
+impl Clock for JsClock {
+    fn now_seconds(&self) -> u64 {
+        (js_sys::Date::now() / 1000.0) as u64
+    }
+}
+
+// ============================================================================
+// MOCK CLOCK
+// ============================================================================
+
+/// Manually controlled clock
+///
+/// Clones share the same time, so a test can keep one handle and pass another
+/// to the code under test.
+#[derive(Debug, Clone, Default)]
+pub struct MockClock {
+    now: Arc<AtomicU64>,
+}
+
+impl MockClock {
+    /// Creates a clock frozen at `now_seconds`
+    pub fn new(now_seconds: u64) -> Self {
+        Self {
+            now: Arc::new(AtomicU64::new(now_seconds)),
+        }
+    }
+
+    /// Moves the clock forward
+    pub fn advance(&self, seconds: u64) {
+        self.now.fetch_add(seconds, Ordering::SeqCst);
+    }
+
+    /// Jumps to an absolute time
+    pub fn set(&self, now_seconds: u64) {
+        self.now.store(now_seconds, Ordering::SeqCst);
+    }
+}
+
+impl Clock for MockClock {
+    fn now_seconds(&self) -> u64 {
+        self.now.load(Ordering::SeqCst)
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_clock_shared_between_clones() {
+        let clock = MockClock::new(100);
+        let handle = clock.clone();
+
+        handle.advance(5);
+        assert_eq!(clock.now_seconds(), 105);
+
+        clock.set(42);
+        assert_eq!(handle.now_seconds(), 42);
+    }
+
+    #[test]
+    fn test_default_clock_is_wall_time() {
+        // 2020-01-01T00:00:00Z
+        assert!(default_clock().now_seconds() > 1_577_836_800);
+    }
+}
@@ -0,0 +1,502 @@
+//! Contact Delivery Module
+//!
+//! Sends the contact form through a `ContactTransport`:
+//!
+//! - `Formspree`: Formspree, or any endpoint speaking its JSON API
+//! - `Webhook`: own endpoint (e.g. a Cloudflare Worker) receiving the form as JSON
+//! - `Mailto`: no server at all; opens the visitor's mail client with the
+//!   message pre-filled
+//!
+//! The active transport is `config::CONTACT_SERVICE`. Transports only build
+//! the request and interpret the response; `send` (browser, `gloo-net`) and
+//! `send_blocking` (native, `ureq`) do the I/O.
+
+use std::fmt;
+
+use serde::Deserialize;
+
+use crate::config::{CONTACT_SERVICE, ContactService};
+use crate::data::ContactForm;
+use crate::i18n::dict::ContactConfig;
+use crate::utils::screenshots::encode_query_value;
+
+/// Why a message could not be delivered
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContactError {
+    /// Network error (offline, DNS, CORS, timeout...)
+    NetworkError(String),
+    /// The endpoint refused the submission (4xx), with its reason if given
+    Rejected(Option<String>),
+    /// Too many submissions (429)
+    RateLimited,
+    /// Generic server error
+    ServerError(u16),
+}
+
+impl fmt::Display for ContactError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContactError::NetworkError(msg) => write!(f, "Network error: {}", msg),
+            ContactError::Rejected(Some(reason)) => write!(f, "Submission rejected: {}", reason),
+            ContactError::Rejected(None) => write!(f, "Submission rejected"),
+            ContactError::RateLimited => write!(f, "Too many submissions"),
+            ContactError::ServerError(code) => write!(f, "Server error: {}", code),
+        }
+    }
+}
+
+impl std::error::Error for ContactError {}
+
+impl ContactError {
+    /// Maps an HTTP error status onto `ContactError`
+    pub fn from_status(status: u16, reason: Option<String>) -> Self {
+        match status {
+            429 => ContactError::RateLimited,
+            400..=499 => ContactError::Rejected(reason),
+            code => ContactError::ServerError(code),
+        }
+    }
+
+    /// Message shown to the visitor, from the active dictionary
+    pub fn message(&self, i18n: &ContactConfig) -> &'static str {
+        match self {
+            ContactError::NetworkError(_) => i18n.error_network,
+            ContactError::Rejected(_) => i18n.error_rejected,
+            ContactError::RateLimited | ContactError::ServerError(_) => i18n.error_unavailable,
+        }
+    }
+}
+
+/// What a successful `send` did with the message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delivery {
+    /// Accepted by the endpoint
+    Sent,
+    /// Handed to the visitor's mail client; sending it is up to them
+    MailClient,
+}
+
+/// How a transport delivers the form
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContactRequest {
+    /// `POST` a JSON body, expecting a JSON reply
+    Post { url: String, body: String },
+    /// Navigate to a URL (`mailto:`)
+    Open(String),
+}
+
+// ============================================================================
+// TRANSPORTS
+// ============================================================================
+
+/// A way to get the contact form to the site owner
+pub trait ContactTransport: Sync {
+    /// Short identifier for logs
+    fn name(&self) -> &'static str;
+
+    /// Request delivering `form`
+    fn request(&self, form: &ContactForm) -> ContactRequest;
+
+    /// Maps the endpoint's response (for `ContactRequest::Post`)
+    fn interpret(&self, status: u16, _body: &str) -> Result<(), ContactError> {
+        match status {
+            200..=299 => Ok(()),
+            code => Err(ContactError::from_status(code, None)),
+        }
+    }
+}
+
+/// Formspree (`https://formspree.io/f/<form id>`) or a compatible endpoint
+#[derive(Debug, Clone, Copy)]
+pub struct Formspree {
+    pub endpoint: &'static str,
+}
+
+/// Formspree error reply: `{"errors": [{"message": ".."}]}` or `{"error": ".."}`
+#[derive(Deserialize)]
+struct FormspreeError {
+    #[serde(default)]
+    errors: Vec<FormspreeErrorItem>,
+    error: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct FormspreeErrorItem {
+    message: String,
+}
+
+impl ContactTransport for Formspree {
+    fn name(&self) -> &'static str {
+        "formspree"
+    }
+
+    fn request(&self, form: &ContactForm) -> ContactRequest {
+        // `email` doubles as the reply-to address; `_subject` is the mail subject
+        let body = serde_json::json!({
+            "name": form.name,
+            "email": form.email,
+            "_subject": form.subject,
+            "message": form.message,
+            // Formspree's own honeypot field
+            "_gotcha": form.website,
+        });
+        ContactRequest::Post {
+            url: self.endpoint.to_string(),
+            body: body.to_string(),
+        }
+    }
+
+    fn interpret(&self, status: u16, body: &str) -> Result<(), ContactError> {
+        if (200..=299).contains(&status) {
+            return Ok(());
+        }
+        let reason = serde_json::from_str::<FormspreeError>(body)
+            .ok()
+            .and_then(|reply| {
+                let messages: Vec<String> = reply.errors.into_iter().map(|e| e.message).collect();
+                if messages.is_empty() {
+                    reply.error
+                } else {
+                    Some(messages.join("; "))
+                }
+            });
+        Err(ContactError::from_status(status, reason))
+    }
+}
+
+/// Own endpoint receiving `{"name", "email", "subject", "message"}`
+///
+/// Any 2xx reply counts as delivered.
+#[derive(Debug, Clone, Copy)]
+pub struct Webhook {
+    pub url: &'static str,
+}
+
+impl ContactTransport for Webhook {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    fn request(&self, form: &ContactForm) -> ContactRequest {
+        ContactRequest::Post {
+            url: self.url.to_string(),
+            body: serde_json::to_string(form).unwrap_or_default(),
+        }
+    }
+}
+
+/// `mailto:` link with subject and body pre-filled
+#[derive(Debug, Clone, Copy)]
+pub struct Mailto {
+    pub to: &'static str,
+}
+
+impl ContactTransport for Mailto {
+    fn name(&self) -> &'static str {
+        "mailto"
+    }
+
+    fn request(&self, form: &ContactForm) -> ContactRequest {
+        // RFC 6068: line breaks in the body are CRLF
+        let body = format!("{}\r\n\r\n{} <{}>", form.message, form.name, form.email)
+            .replace("\r\n", "\n")
+            .replace('\n', "\r\n");
+        ContactRequest::Open(format!(
+            "mailto:{}?subject={}&body={}",
+            self.to,
+            encode_query_value(&form.subject),
+            encode_query_value(&body)
+        ))
+    }
+}
+
+impl ContactTransport for ContactService {
+    fn name(&self) -> &'static str {
+        match *self {
+            Self::Formspree { endpoint } => Formspree { endpoint }.name(),
+            Self::Webhook { url } => Webhook { url }.name(),
+            Self::Mailto { to } => Mailto { to }.name(),
+        }
+    }
+
+    fn request(&self, form: &ContactForm) -> ContactRequest {
+        match *self {
+            Self::Formspree { endpoint } => Formspree { endpoint }.request(form),
+            Self::Webhook { url } => Webhook { url }.request(form),
+            Self::Mailto { to } => Mailto { to }.request(form),
+        }
+    }
+
+    fn interpret(&self, status: u16, body: &str) -> Result<(), ContactError> {
+        match *self {
+            Self::Formspree { endpoint } => Formspree { endpoint }.interpret(status, body),
+            Self::Webhook { url } => Webhook { url }.interpret(status, body),
+            Self::Mailto { to } => Mailto { to }.interpret(status, body),
+        }
+    }
+}
+
+/// The transport selected in `config::CONTACT_SERVICE`
+pub fn configured_transport() -> &'static dyn ContactTransport {
+    &CONTACT_SERVICE
+}
+
+// ============================================================================
+// SENDING
+// ============================================================================
+
+/// Delivers `form` from the browser
+#[cfg(target_arch = "wasm32")]
+pub async fn send(
+    transport: &dyn ContactTransport,
+    form: &ContactForm,
+) -> Result<Delivery, ContactError> {
+    use gloo_net::http::Request;
+
+    match transport.request(form) {
+        ContactRequest::Post { url, body } => {
+            let response = Request::post(&url)
+                .header("Content-Type", "application/json")
+                .header("Accept", "application/json")
+                .body(body)
+                .map_err(|e| ContactError::NetworkError(e.to_string()))?
+                .send()
+                .await
+                .map_err(|e| ContactError::NetworkError(e.to_string()))?;
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            transport.interpret(status, &body).map(|_| Delivery::Sent)
+        }
+        ContactRequest::Open(url) => {
+            let window = web_sys::window()
+                .ok_or_else(|| ContactError::NetworkError("no window".to_string()))?;
+            window
+                .open_with_url_and_target(&url, "_self")
+                .map_err(|_| {
+                    ContactError::NetworkError("could not open mail client".to_string())
+                })?;
+            Ok(Delivery::MailClient)
+        }
+    }
+}
+
+/// Delivers `form` from native code (tooling and tests)
+///
+/// There is no mail client to open outside a browser, so `mailto:` requests
+/// return `Delivery::MailClient` without doing anything.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn send_blocking(
+    transport: &dyn ContactTransport,
+    form: &ContactForm,
+) -> Result<Delivery, ContactError> {
+    match transport.request(form) {
+        ContactRequest::Post { url, body } => {
+            let response = ureq::AgentBuilder::new()
+                .timeout(std::time::Duration::from_secs(10))
+                .build()
+                .post(&url)
+                .set("Content-Type", "application/json")
+                .set("Accept", "application/json")
+                .send_string(&body);
+            let response = match response {
+                Ok(response) => response,
+                Err(ureq::Error::Status(_, response)) => response,
+                Err(ureq::Error::Transport(transport)) => {
+                    return Err(ContactError::NetworkError(transport.to_string()));
+                }
+            };
+            let status = response.status();
+            let body = response.into_string().unwrap_or_default();
+            transport.interpret(status, &body).map(|_| Delivery::Sent)
+        }
+        ContactRequest::Open(_) => Ok(Delivery::MailClient),
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Language;
+    use crate::tests::mock_http::{MockResponse, MockServer, closed_port_url};
+
+    fn form() -> ContactForm {
+        ContactForm {
+            name: "Ada \"Countess\" Lovelace".to_string(),
+            email: "ada@example.com".to_string(),
+            subject: "Hello & welcome".to_string(),
+            message: "First line\nSecond line, 100% sure".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Canned Formspree and webhook responses by path
+    const ROUTES: &[(&str, MockResponse)] = &[
+        ("/f/ok", MockResponse::new("200 OK", r#"{"ok":true}"#)),
+        (
+            "/f/invalid",
+            MockResponse::new(
+                "422 Unprocessable Entity",
+                r#"{"errors":[{"field":"email","message":"should be an email"}]}"#,
+            ),
+        ),
+        (
+            "/f/closed",
+            MockResponse::new("403 Forbidden", r#"{"error":"Form not active"}"#),
+        ),
+        ("/hook", MockResponse::new("204 No Content", "")),
+        ("/hook/busy", MockResponse::new("429 Too Many Requests", "")),
+        (
+            "/hook/down",
+            MockResponse::new("503 Service Unavailable", ""),
+        ),
+    ];
+
+    /// `&'static str` URL for the config-style transports
+    fn leak(url: String) -> &'static str {
+        Box::leak(url.into_boxed_str())
+    }
+
+    // -------------------------
+    // Mock Server Tests
+    // -------------------------
+
+    #[test]
+    fn test_formspree_posts_json() {
+        let server = MockServer::spawn(ROUTES);
+        let transport = Formspree {
+            endpoint: leak(format!("{}/f/ok", server.base_url)),
+        };
+
+        assert_eq!(send_blocking(&transport, &form()), Ok(Delivery::Sent));
+
+        let request = server.next_request();
+        assert_eq!(request.request_line, "POST /f/ok HTTP/1.1");
+        assert!(
+            request
+                .headers
+                .iter()
+                .any(|h| h.eq_ignore_ascii_case("accept: application/json"))
+        );
+        let body: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+        assert_eq!(body["name"], "Ada \"Countess\" Lovelace");
+        assert_eq!(body["email"], "ada@example.com");
+        assert_eq!(body["_subject"], "Hello & welcome");
+        assert_eq!(body["message"], "First line\nSecond line, 100% sure");
+    }
+
+    #[test]
+    fn test_formspree_errors() {
+        let server = MockServer::spawn(ROUTES);
+        let send_to = |path: &str| {
+            let endpoint = leak(format!("{}{}", server.base_url, path));
+            send_blocking(&Formspree { endpoint }, &form())
+        };
+
+        assert_eq!(
+            send_to("/f/invalid"),
+            Err(ContactError::Rejected(Some(
+                "should be an email".to_string()
+            )))
+        );
+        assert_eq!(
+            send_to("/f/closed"),
+            Err(ContactError::Rejected(Some("Form not active".to_string())))
+        );
+        assert_eq!(send_to("/f/missing"), Err(ContactError::Rejected(None)));
+    }
+
+    #[test]
+    fn test_webhook_posts_form() {
+        let server = MockServer::spawn(ROUTES);
+        let transport = Webhook {
+            url: leak(format!("{}/hook", server.base_url)),
+        };
+
+        assert_eq!(send_blocking(&transport, &form()), Ok(Delivery::Sent));
+
+        let request = server.next_request();
+        assert_eq!(request.request_line, "POST /hook HTTP/1.1");
+        let body: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+        assert_eq!(body["subject"], "Hello & welcome");
+        assert_eq!(body["message"], "First line\nSecond line, 100% sure");
+        // The honeypot stays in the browser; no stamp unless configured
+        assert!(body.get("website").is_none());
+        assert!(body.get("proof").is_none());
+    }
+
+    #[test]
+    fn test_webhook_errors() {
+        let server = MockServer::spawn(ROUTES);
+        let send_to = |path: &str| {
+            let url = leak(format!("{}{}", server.base_url, path));
+            send_blocking(&Webhook { url }, &form())
+        };
+
+        assert_eq!(send_to("/hook/busy"), Err(ContactError::RateLimited));
+        assert_eq!(send_to("/hook/down"), Err(ContactError::ServerError(503)));
+    }
+
+    #[test]
+    fn test_network_error() {
+        let transport = Webhook {
+            url: leak(format!("{}/hook", closed_port_url())),
+        };
+
+        assert!(matches!(
+            send_blocking(&transport, &form()),
+            Err(ContactError::NetworkError(_))
+        ));
+    }
+
+    // -------------------------
+    // Mailto
+    // -------------------------
+
+    #[test]
+    fn test_mailto_prefills_subject_and_body() {
+        let transport = Mailto {
+            to: "me@example.com",
+        };
+        let ContactRequest::Open(url) = transport.request(&form()) else {
+            panic!("mailto does not open a URL");
+        };
+
+        assert_eq!(
+            url,
+            "mailto:me@example.com?subject=Hello%20%26%20welcome\
+             &body=First%20line%0D%0ASecond%20line%2C%20100%25%20sure%0D%0A%0D%0A\
+             Ada%20%22Countess%22%20Lovelace%20%3Cada%40example.com%3E"
+        );
+        assert_eq!(send_blocking(&transport, &form()), Ok(Delivery::MailClient));
+    }
+
+    #[test]
+    fn test_configured_transport_follows_config() {
+        let transport = configured_transport();
+        let expected = match CONTACT_SERVICE {
+            ContactService::Formspree { .. } => "formspree",
+            ContactService::Webhook { .. } => "webhook",
+            ContactService::Mailto { .. } => "mailto",
+        };
+        assert_eq!(transport.name(), expected);
+    }
+
+    #[test]
+    fn test_error_messages_come_from_dictionary() {
+        let es = Language::ES.dict().contact;
+        assert_eq!(
+            ContactError::NetworkError("offline".to_string()).message(&es),
+            es.error_network
+        );
+        assert_eq!(ContactError::Rejected(None).message(&es), es.error_rejected);
+        assert_eq!(ContactError::RateLimited.message(&es), es.error_unavailable);
+        assert_eq!(
+            ContactError::ServerError(500).message(&es),
+            es.error_unavailable
+        );
+    }
+}
@@ -0,0 +1,627 @@
+//! Forge Module
+//!
+//! Multi-forge support for project repositories. A `RepoRef` identifies a
+//! repository on GitHub, GitLab, Codeberg or a self-hosted Gitea instance; the
+//! forge's `ForgeProvider` knows its REST endpoint, how to read its JSON, its
+//! preview image and its homepage.
+//!
+//! # Accepted URL forms
+//!
+//! - `https://host/owner/repo`, `http://host/owner/repo`, `host/owner/repo`
+//! - `.git` suffix, trailing slash, extra segments (`/tree/main`, `/-/issues`)
+//! - SSH: `git@host:owner/repo.git` and `ssh://git@host[:port]/owner/repo.git`
+//!
+//! GitLab namespaces may be nested (`group/subgroup/repo`). Any host other
+//! than github.com, gitlab.com and codeberg.org is treated as a self-hosted
+//! Gitea (or Forgejo) instance.
+//!
+//! # Example
+//! ```
+//! use enerby_dev::utils::forge::{Forge, RepoRef};
+//!
+//! let repo = RepoRef::parse("git@gitlab.com:group/sub/tool.git").unwrap();
+//! assert_eq!(repo.forge, Forge::GitLab);
+//! assert_eq!(repo.full_name(), "group/sub/tool");
+//! assert_eq!(repo.web_url(), "https://gitlab.com/group/sub/tool");
+//! ```
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+
+use super::github_api::{
+    ApiError, GITHUB_API_BASE_URL, GitHubRepoInfo, RepoLicense, get_repo_info_for_ref,
+};
+use super::project_images::generate_github_opengraph_url;
+
+// ============================================================================
+// FORGES
+// ============================================================================
+
+/// Supported source forges
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Forge {
+    GitHub,
+    GitLab,
+    /// codeberg.org (Forgejo, Gitea-compatible API)
+    Codeberg,
+    /// Self-hosted Gitea or Forgejo
+    Gitea,
+}
+
+impl Forge {
+    /// Identifies the forge from a lowercase host name
+    pub fn from_host(host: &str) -> Self {
+        match host {
+            "github.com" | "www.github.com" => Self::GitHub,
+            "gitlab.com" | "www.gitlab.com" => Self::GitLab,
+            "codeberg.org" => Self::Codeberg,
+            _ => Self::Gitea,
+        }
+    }
+
+    /// Display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::GitHub => "GitHub",
+            Self::GitLab => "GitLab",
+            Self::Codeberg => "Codeberg",
+            Self::Gitea => "Gitea",
+        }
+    }
+
+    /// Provider handling this forge's API
+    pub fn provider(&self) -> &'static dyn ForgeProvider {
+        match self {
+            Self::GitHub => &GitHubProvider,
+            Self::GitLab => &GitLabProvider,
+            Self::Codeberg | Self::Gitea => &GiteaProvider,
+        }
+    }
+}
+
+// ============================================================================
+// REPO REFERENCE
+// ============================================================================
+
+/// A repository on a specific forge
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RepoRef {
+    pub forge: Forge,
+    /// Lowercase host, including a port for self-hosted web URLs
+    pub host: String,
+    /// Owner or namespace (GitLab namespaces may contain '/')
+    pub owner: String,
+    /// Repository name without `.git`
+    pub name: String,
+}
+
+impl RepoRef {
+    /// References a github.com repository
+    pub fn github(owner: &str, name: &str) -> Self {
+        Self {
+            forge: Forge::GitHub,
+            host: "github.com".to_string(),
+            owner: owner.to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    /// Parses an HTTPS, scheme-less or SSH repository URL
+    ///
+    /// Returns `None` if the URL has no host or no owner/name path.
+    pub fn parse(url: &str) -> Option<Self> {
+        let url = url.trim();
+
+        let (host, path) = if let Some(rest) = url.strip_prefix("ssh://") {
+            // ssh://[user@]host[:port]/path - the SSH port is not the web port
+            let (authority, path) = rest.split_once('/')?;
+            let host = authority.rsplit('@').next()?;
+            (host.split(':').next()?, path)
+        } else if let Some((user_host, path)) = url
+            .split_once(':')
+            .filter(|(user_host, _)| !url.contains("://") && user_host.contains('@'))
+        {
+            // scp-like: git@host:owner/repo.git
+            (user_host.rsplit('@').next()?, path)
+        } else {
+            let rest = url
+                .strip_prefix("https://")
+                .or_else(|| url.strip_prefix("http://"))
+                .unwrap_or(url);
+            let (authority, path) = rest.split_once('/')?;
+            (authority.rsplit('@').next()?, path)
+        };
+
+        let host = host.to_lowercase();
+        if !(host.contains('.') || host.starts_with("localhost")) {
+            return None;
+        }
+        let forge = Forge::from_host(&host);
+
+        let path = path.split(['?', '#']).next().unwrap_or_default();
+        let mut segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+        if forge == Forge::GitLab {
+            // Everything after "/-/" is a sub-page (tree, issues, ...)
+            if let Some(dash) = segments.iter().position(|s| *s == "-") {
+                segments.truncate(dash);
+            }
+        } else {
+            segments.truncate(2);
+        }
+
+        let (name, namespace) = segments.split_last()?;
+        let name = name.strip_suffix(".git").unwrap_or(name);
+        if namespace.is_empty() || name.is_empty() {
+            return None;
+        }
+
+        let host = match forge {
+            Forge::Gitea => host,
+            _ => host.trim_start_matches("www.").to_string(),
+        };
+
+        Some(Self {
+            forge,
+            host,
+            owner: namespace.join("/"),
+            name: name.to_string(),
+        })
+    }
+
+    /// "owner/name"
+    pub fn full_name(&self) -> String {
+        format!("{}/{}", self.owner, self.name)
+    }
+
+    /// Canonical web URL of the repository
+    pub fn web_url(&self) -> String {
+        format!("https://{}/{}/{}", self.host, self.owner, self.name)
+    }
+
+    /// Provider for this repository's forge
+    pub fn provider(&self) -> &'static dyn ForgeProvider {
+        self.forge.provider()
+    }
+}
+
+impl fmt::Display for RepoRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}/{}", self.host, self.owner, self.name)
+    }
+}
+
+// ============================================================================
+// PROVIDERS
+// ============================================================================
+
+/// Per-forge access to repository data
+///
+/// Metadata from every forge is mapped to the shared `GitHubRepoInfo` shape
+/// so the snapshot, cache and enrichment code stay forge-agnostic.
+pub trait ForgeProvider: Sync {
+    /// REST endpoint returning the repository's metadata
+    fn api_url(&self, repo: &RepoRef) -> String;
+
+    /// Maps the forge's JSON response to `GitHubRepoInfo`
+    fn parse_repo(&self, json: &str) -> Result<GitHubRepoInfo, ApiError>;
+
+    /// Social preview image, if the forge publishes one
+    fn preview_image_url(&self, repo: &RepoRef) -> Option<String>;
+
+    /// REST endpoint returning `{"Language": bytes}`, if the forge has one
+    fn languages_url(&self, repo: &RepoRef) -> Option<String>;
+
+    /// Repository metadata from the embedded snapshot
+    fn repo_info(&self, repo: &RepoRef) -> Result<GitHubRepoInfo, ApiError> {
+        get_repo_info_for_ref(repo)
+    }
+
+    /// Homepage configured in the repository settings
+    fn homepage(&self, repo: &RepoRef) -> Option<String> {
+        self.repo_info(repo)
+            .ok()
+            .and_then(|info| info.extract_homepage())
+    }
+}
+
+fn parse_json<T: for<'de> Deserialize<'de>>(json: &str) -> Result<T, ApiError> {
+    serde_json::from_str(json).map_err(|e| ApiError::ParseError(e.to_string()))
+}
+
+/// github.com (REST v3)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GitHubProvider;
+
+impl ForgeProvider for GitHubProvider {
+    fn api_url(&self, repo: &RepoRef) -> String {
+        format!("{}/repos/{}/{}", GITHUB_API_BASE_URL, repo.owner, repo.name)
+    }
+
+    fn parse_repo(&self, json: &str) -> Result<GitHubRepoInfo, ApiError> {
+        parse_json(json)
+    }
+
+    fn preview_image_url(&self, repo: &RepoRef) -> Option<String> {
+        Some(generate_github_opengraph_url(&repo.owner, &repo.name))
+    }
+
+    fn languages_url(&self, repo: &RepoRef) -> Option<String> {
+        Some(format!("{}/languages", self.api_url(repo)))
+    }
+}
+
+/// GitLab (API v4); projects have no homepage field and no preview service
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GitLabProvider;
+
+/// `GET /api/v4/projects/:id` response (relevant fields)
+#[derive(Deserialize)]
+struct GitLabProject {
+    name: String,
+    path_with_namespace: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    star_count: u32,
+    #[serde(default)]
+    forks_count: u32,
+    #[serde(default)]
+    open_issues_count: u32,
+    #[serde(default)]
+    forked_from_project: Option<serde_json::Value>,
+    #[serde(default)]
+    archived: bool,
+    #[serde(default)]
+    topics: Vec<String>,
+    web_url: String,
+    #[serde(default)]
+    created_at: Option<String>,
+}
+
+impl ForgeProvider for GitLabProvider {
+    fn api_url(&self, repo: &RepoRef) -> String {
+        // The project id is the URL-encoded namespace path
+        format!(
+            "https://{}/api/v4/projects/{}",
+            repo.host,
+            repo.full_name().replace('/', "%2F")
+        )
+    }
+
+    fn parse_repo(&self, json: &str) -> Result<GitHubRepoInfo, ApiError> {
+        let project: GitLabProject = parse_json(json)?;
+        Ok(GitHubRepoInfo {
+            name: project.name,
+            full_name: project.path_with_namespace,
+            description: project.description.filter(|d| !d.is_empty()),
+            homepage: None,
+            language: None,
+            stargazers_count: project.star_count,
+            forks_count: project.forks_count,
+            open_issues_count: project.open_issues_count,
+            fork: project.forked_from_project.is_some(),
+            archived: project.archived,
+            topics: project.topics,
+            html_url: project.web_url,
+            created_at: project.created_at,
+            pushed_at: None,
+            license: None,
+            languages: BTreeMap::new(),
+        })
+    }
+
+    fn preview_image_url(&self, _repo: &RepoRef) -> Option<String> {
+        None
+    }
+
+    fn languages_url(&self, _repo: &RepoRef) -> Option<String> {
+        // `/languages` reports percentages, not bytes
+        None
+    }
+}
+
+/// Gitea, Forgejo and Codeberg (API v1); no preview service
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GiteaProvider;
+
+/// `GET /api/v1/repos/{owner}/{repo}` response (relevant fields)
+#[derive(Deserialize)]
+struct GiteaRepo {
+    name: String,
+    full_name: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    website: String,
+    #[serde(default)]
+    language: String,
+    #[serde(default)]
+    stars_count: u32,
+    #[serde(default)]
+    forks_count: u32,
+    #[serde(default)]
+    open_issues_count: u32,
+    #[serde(default)]
+    fork: bool,
+    #[serde(default)]
+    archived: bool,
+    #[serde(default)]
+    topics: Vec<String>,
+    html_url: String,
+    #[serde(default)]
+    created_at: String,
+    /// SPDX identifiers (Gitea 1.22+)
+    #[serde(default)]
+    licenses: Vec<String>,
+}
+
+impl ForgeProvider for GiteaProvider {
+    fn api_url(&self, repo: &RepoRef) -> String {
+        format!(
+            "https://{}/api/v1/repos/{}/{}",
+            repo.host, repo.owner, repo.name
+        )
+    }
+
+    fn parse_repo(&self, json: &str) -> Result<GitHubRepoInfo, ApiError> {
+        // Gitea sends "" rather than null for unset strings
+        let non_empty = |s: String| (!s.is_empty()).then_some(s);
+
+        let repo: GiteaRepo = parse_json(json)?;
+        Ok(GitHubRepoInfo {
+            name: repo.name,
+            full_name: repo.full_name,
+            description: non_empty(repo.description),
+            homepage: non_empty(repo.website),
+            language: non_empty(repo.language),
+            stargazers_count: repo.stars_count,
+            forks_count: repo.forks_count,
+            open_issues_count: repo.open_issues_count,
+            fork: repo.fork,
+            archived: repo.archived,
+            topics: repo.topics,
+            html_url: repo.html_url,
+            created_at: non_empty(repo.created_at),
+            pushed_at: None,
+            license: repo.licenses.into_iter().next().map(|id| RepoLicense {
+                spdx_id: Some(id.clone()),
+                name: id,
+            }),
+            languages: BTreeMap::new(),
+        })
+    }
+
+    fn preview_image_url(&self, _repo: &RepoRef) -> Option<String> {
+        None
+    }
+
+    fn languages_url(&self, repo: &RepoRef) -> Option<String> {
+        Some(format!("{}/languages", self.api_url(repo)))
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parts(url: &str) -> Option<(Forge, String, String, String)> {
+        RepoRef::parse(url).map(|r| (r.forge, r.host, r.owner, r.name))
+    }
+
+    fn expect(
+        forge: Forge,
+        host: &str,
+        owner: &str,
+        name: &str,
+    ) -> Option<(Forge, String, String, String)> {
+        Some((forge, host.to_string(), owner.to_string(), name.to_string()))
+    }
+
+    // -------------------------
+    // Parsing Tests
+    // -------------------------
+
+    #[test]
+    fn test_parse_github_forms() {
+        let enerby = expect(Forge::GitHub, "github.com", "enerBydev", "enerby.dev");
+
+        assert_eq!(parts("https://github.com/enerBydev/enerby.dev"), enerby);
+        assert_eq!(parts("http://github.com/enerBydev/enerby.dev"), enerby);
+        assert_eq!(parts("github.com/enerBydev/enerby.dev"), enerby);
+        assert_eq!(parts("https://www.github.com/enerBydev/enerby.dev"), enerby);
+        assert_eq!(parts("https://github.com/enerBydev/enerby.dev/"), enerby);
+        assert_eq!(parts("https://github.com/enerBydev/enerby.dev.git"), enerby);
+        assert_eq!(
+            parts("  https://github.com/enerBydev/enerby.dev/tree/main  "),
+            enerby
+        );
+        assert_eq!(parts("git@github.com:enerBydev/enerby.dev.git"), enerby);
+        assert_eq!(
+            parts("ssh://git@github.com/enerBydev/enerby.dev.git"),
+            enerby
+        );
+    }
+
+    #[test]
+    fn test_parse_gitlab_nested_namespace() {
+        let tool = expect(Forge::GitLab, "gitlab.com", "group/sub", "tool");
+
+        assert_eq!(parts("https://gitlab.com/group/sub/tool"), tool);
+        assert_eq!(parts("https://gitlab.com/group/sub/tool.git"), tool);
+        assert_eq!(parts("https://gitlab.com/group/sub/tool/-/tree/main"), tool);
+        assert_eq!(parts("git@gitlab.com:group/sub/tool.git"), tool);
+    }
+
+    #[test]
+    fn test_parse_codeberg_and_gitea() {
+        assert_eq!(
+            parts("https://codeberg.org/owner/repo"),
+            expect(Forge::Codeberg, "codeberg.org", "owner", "repo")
+        );
+        assert_eq!(
+            parts("https://git.example.org:3000/owner/repo/src/branch/main"),
+            expect(Forge::Gitea, "git.example.org:3000", "owner", "repo")
+        );
+        assert_eq!(
+            parts("ssh://git@git.example.org:2222/owner/repo.git"),
+            expect(Forge::Gitea, "git.example.org", "owner", "repo")
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(RepoRef::parse("").is_none());
+        assert!(RepoRef::parse("not-a-url").is_none());
+        assert!(RepoRef::parse("https://example.com").is_none());
+        assert!(RepoRef::parse("https://github.com/").is_none());
+        assert!(RepoRef::parse("https://github.com/owner").is_none());
+        assert!(RepoRef::parse("https://github.com/owner/.git").is_none());
+        assert!(RepoRef::parse("owner/repo").is_none());
+    }
+
+    #[test]
+    fn test_urls_and_display() {
+        let repo = RepoRef::parse("git@codeberg.org:owner/repo.git").unwrap();
+        assert_eq!(repo.full_name(), "owner/repo");
+        assert_eq!(repo.web_url(), "https://codeberg.org/owner/repo");
+        assert_eq!(repo.to_string(), "codeberg.org/owner/repo");
+        assert_eq!(
+            RepoRef::github("o", "r").web_url(),
+            "https://github.com/o/r"
+        );
+    }
+
+    // -------------------------
+    // Provider Tests
+    // -------------------------
+
+    #[test]
+    fn test_api_urls() {
+        let github = RepoRef::github("owner", "repo");
+        let gitlab = RepoRef::parse("https://gitlab.com/group/sub/tool").unwrap();
+        let gitea = RepoRef::parse("https://codeberg.org/owner/repo").unwrap();
+
+        assert_eq!(
+            github.provider().api_url(&github),
+            "https://api.github.com/repos/owner/repo"
+        );
+        assert_eq!(
+            gitlab.provider().api_url(&gitlab),
+            "https://gitlab.com/api/v4/projects/group%2Fsub%2Ftool"
+        );
+        assert_eq!(
+            gitea.provider().api_url(&gitea),
+            "https://codeberg.org/api/v1/repos/owner/repo"
+        );
+    }
+
+    #[test]
+    fn test_languages_urls() {
+        let github = RepoRef::github("owner", "repo");
+        let gitlab = RepoRef::parse("https://gitlab.com/owner/repo").unwrap();
+        let gitea = RepoRef::parse("https://codeberg.org/owner/repo").unwrap();
+
+        assert_eq!(
+            github.provider().languages_url(&github),
+            Some("https://api.github.com/repos/owner/repo/languages".to_string())
+        );
+        assert_eq!(gitlab.provider().languages_url(&gitlab), None);
+        assert_eq!(
+            gitea.provider().languages_url(&gitea),
+            Some("https://codeberg.org/api/v1/repos/owner/repo/languages".to_string())
+        );
+    }
+
+    #[test]
+    fn test_preview_images() {
+        let github = RepoRef::github("owner", "repo");
+        let gitlab = RepoRef::parse("https://gitlab.com/owner/repo").unwrap();
+
+        assert_eq!(
+            github.provider().preview_image_url(&github),
+            Some("https://opengraph.githubassets.com/1/owner/repo".to_string())
+        );
+        assert_eq!(gitlab.provider().preview_image_url(&gitlab), None);
+    }
+
+    #[test]
+    fn test_gitlab_parse_repo() {
+        let json = r#"{
+            "name": "tool",
+            "path_with_namespace": "group/sub/tool",
+            "description": "",
+            "star_count": 5,
+            "forks_count": 1,
+            "open_issues_count": 4,
+            "forked_from_project": {"id": 1},
+            "topics": ["rust"],
+            "web_url": "https://gitlab.com/group/sub/tool"
+        }"#;
+        let info = GitLabProvider.parse_repo(json).unwrap();
+
+        assert_eq!(info.full_name, "group/sub/tool");
+        assert_eq!(info.description, None);
+        assert_eq!(info.stargazers_count, 5);
+        assert_eq!(info.open_issues_count, 4);
+        assert!(info.fork);
+        assert_eq!(info.topics, vec!["rust".to_string()]);
+        assert_eq!(info.html_url, "https://gitlab.com/group/sub/tool");
+    }
+
+    #[test]
+    fn test_gitea_parse_repo() {
+        let json = r#"{
+            "name": "repo",
+            "full_name": "owner/repo",
+            "description": "A repo",
+            "website": "https://repo.example",
+            "language": "",
+            "stars_count": 3,
+            "archived": true,
+            "html_url": "https://codeberg.org/owner/repo",
+            "created_at": "2024-03-01T12:00:00+01:00",
+            "licenses": ["MIT"]
+        }"#;
+        let info = GiteaProvider.parse_repo(json).unwrap();
+
+        assert_eq!(info.description.as_deref(), Some("A repo"));
+        assert_eq!(
+            info.extract_homepage(),
+            Some("https://repo.example".to_string())
+        );
+        assert_eq!(info.language, None);
+        assert_eq!(info.stargazers_count, 3);
+        assert!(info.archived);
+        assert_eq!(
+            info.created_at.as_deref(),
+            Some("2024-03-01T12:00:00+01:00")
+        );
+        assert_eq!(info.license.as_ref().map(RepoLicense::label), Some("MIT"));
+        assert!(matches!(
+            GiteaProvider.parse_repo("{}"),
+            Err(ApiError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_snapshot_lookup_by_forge() {
+        let github = RepoRef::parse("https://github.com/enerBydev/enerby.dev").unwrap();
+        assert_eq!(
+            github.provider().homepage(&github),
+            Some("https://enerby.dev".to_string())
+        );
+
+        // Same owner/name on another forge is a different repository
+        let gitlab = RepoRef::parse("https://gitlab.com/enerBydev/enerby.dev").unwrap();
+        assert_eq!(
+            gitlab.provider().repo_info(&gitlab),
+            Err(ApiError::NotFound)
+        );
+    }
+}
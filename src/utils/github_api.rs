@@ -7,7 +7,8 @@
 //!
 //! Due to WASM limitations (no direct HTTP in browser without special handling),
 //! this module uses a **static data approach** where GitHub data is pre-fetched
-//! and embedded at build time. This ensures:
+//! by the `github-sync` binary into `.cache/github_repos.json` and embedded at
+//! build time. This ensures:
 //! - Zero runtime HTTP calls from WASM
 //! - Instant data availability
 //! - Graceful fallback if data is stale
@@ -20,8 +21,14 @@
 //! let demo_url = get_repo_homepage("enerBydev", "enerby.dev");
 //! ```
 
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
+use std::sync::LazyLock;
+
+use super::forge::{Forge, RepoRef};
+use super::github_cache::GitHubCache;
 
 // ============================================================================
 // CONSTANTS & CONFIGURATION
@@ -46,6 +53,8 @@ pub struct GitHubApiConfig {
     pub user_agent: String,
     /// Request timeout in milliseconds
     pub timeout_ms: u64,
+    /// Optional personal access token (raises the rate limit)
+    pub token: Option<String>,
 }
 
 impl Default for GitHubApiConfig {
@@ -54,6 +63,7 @@ impl Default for GitHubApiConfig {
             base_url: GITHUB_API_BASE_URL.to_string(),
             user_agent: USER_AGENT.to_string(),
             timeout_ms: API_TIMEOUT_MS,
+            token: None,
         }
     }
 }
@@ -94,6 +104,52 @@ impl fmt::Display for ApiError {
 
 impl std::error::Error for ApiError {}
 
+impl ApiError {
+    /// Maps an HTTP error status onto `ApiError`
+    ///
+    /// GitHub answers an exhausted quota with 403 (or 429) and
+    /// `X-RateLimit-Remaining: 0`; any other 401/403 is an access problem.
+    pub fn from_status(status: u16, rate_limit: &RateLimit) -> Self {
+        match status {
+            404 => ApiError::NotFound,
+            429 => ApiError::RateLimited,
+            403 if rate_limit.is_exhausted() => ApiError::RateLimited,
+            401 | 403 => ApiError::Forbidden,
+            code => ApiError::ServerError(code),
+        }
+    }
+}
+
+/// Rate limit state parsed from `X-RateLimit-*` response headers
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RateLimit {
+    /// `X-RateLimit-Limit`: requests allowed per window
+    pub limit: Option<u32>,
+    /// `X-RateLimit-Remaining`: requests left in the window
+    pub remaining: Option<u32>,
+    /// `X-RateLimit-Reset`: Unix timestamp when the window resets
+    pub reset_at: Option<u64>,
+}
+
+impl RateLimit {
+    /// Reads the headers through a lookup function (header names are case-insensitive)
+    pub fn from_headers<F>(header: F) -> Self
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        Self {
+            limit: header("x-ratelimit-limit").and_then(|v| v.trim().parse().ok()),
+            remaining: header("x-ratelimit-remaining").and_then(|v| v.trim().parse().ok()),
+            reset_at: header("x-ratelimit-reset").and_then(|v| v.trim().parse().ok()),
+        }
+    }
+
+    /// True when the server reported no requests left
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == Some(0)
+    }
+}
+
 // ============================================================================
 // DATA STRUCTURES
 // ============================================================================
@@ -133,6 +189,10 @@ pub struct GitHubRepoInfo {
     #[serde(default)]
     pub forks_count: u32,
 
+    /// Number of open issues (GitHub counts open pull requests too)
+    #[serde(default)]
+    pub open_issues_count: u32,
+
     /// Whether the repository is a fork
     #[serde(default)]
     pub fork: bool,
@@ -147,6 +207,24 @@ pub struct GitHubRepoInfo {
 
     /// HTML URL to the repository
     pub html_url: String,
+
+    /// Creation timestamp (ISO 8601, e.g. "2025-01-15T10:00:00Z")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    /// Last push timestamp (ISO 8601); GitHub only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pushed_at: Option<String>,
+
+    /// License detected by the forge
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<RepoLicense>,
+
+    /// Bytes of code per language, from `GET /repos/{owner}/{repo}/languages`
+    ///
+    /// Filled in by `github-sync`; empty for forges without a languages endpoint.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub languages: BTreeMap<String, u64>,
 }
 
 impl GitHubRepoInfo {
@@ -175,6 +253,16 @@ impl GitHubRepoInfo {
         })
     }
 
+    /// Day the repository was created, if the forge reported it
+    pub fn created_date(&self) -> Option<NaiveDate> {
+        timestamp_date(self.created_at.as_deref()?)
+    }
+
+    /// Day of the last push, if the forge reported it
+    pub fn pushed_date(&self) -> Option<NaiveDate> {
+        timestamp_date(self.pushed_at.as_deref()?)
+    }
+
     /// Creates a minimal GitHubRepoInfo for testing or fallback
     pub fn minimal(owner: &str, name: &str) -> Self {
         Self {
@@ -185,88 +273,144 @@ impl GitHubRepoInfo {
             language: None,
             stargazers_count: 0,
             forks_count: 0,
+            open_issues_count: 0,
             fork: false,
             archived: false,
             topics: vec![],
             html_url: format!("https://github.com/{}/{}", owner, name),
+            created_at: None,
+            pushed_at: None,
+            license: None,
+            languages: BTreeMap::new(),
         }
     }
 }
 
+/// Repository license (`license` object of the GitHub API)
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct RepoLicense {
+    /// SPDX identifier (e.g. "MIT"); GitHub sends "NOASSERTION" for unrecognized licenses
+    #[serde(default)]
+    pub spdx_id: Option<String>,
+    /// Full name (e.g. "MIT License")
+    #[serde(default)]
+    pub name: String,
+}
+
+impl RepoLicense {
+    /// SPDX identifier when there is a real one, otherwise the name
+    pub fn label(&self) -> &str {
+        match self.spdx_id.as_deref() {
+            Some(id) if !id.is_empty() && id != "NOASSERTION" => id,
+            _ => &self.name,
+        }
+    }
+}
+
+/// Day of an ISO 8601 timestamp ("2025-01-15T10:00:00Z" → 2025-01-15)
+fn timestamp_date(timestamp: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(timestamp.get(..10)?, "%Y-%m-%d").ok()
+}
+
+/// Share of code bytes per language, in percent
+///
+/// Keeps the `limit` largest languages (ties by name) and folds the rest into
+/// a trailing `None` entry ("other"), like GitHub's repository language bar.
+pub fn language_breakdown(
+    languages: &BTreeMap<String, u64>,
+    limit: usize,
+) -> Vec<(Option<&str>, f64)> {
+    let total: u64 = languages.values().sum();
+    if total == 0 {
+        return Vec::new();
+    }
+    let percent = |bytes: u64| bytes as f64 / total as f64 * 100.0;
+
+    let mut sorted: Vec<(&String, &u64)> = languages.iter().filter(|(_, b)| **b > 0).collect();
+    sorted.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+
+    let mut shares: Vec<(Option<&str>, f64)> = sorted
+        .iter()
+        .take(limit)
+        .map(|(language, bytes)| (Some(language.as_str()), percent(**bytes)))
+        .collect();
+
+    let rest: u64 = sorted.iter().skip(limit).map(|(_, bytes)| **bytes).sum();
+    if rest > 0 {
+        shares.push((None, percent(rest)));
+    }
+    shares
+}
+
+// ============================================================================
+// CONDITIONAL REQUESTS
+// ============================================================================
+
+/// Result of a conditional (`If-None-Match`) repository request
+#[derive(Debug, Clone, PartialEq)]
+pub enum FetchOutcome {
+    /// New data, with the `ETag` to send next time
+    Modified {
+        info: Box<GitHubRepoInfo>,
+        etag: Option<String>,
+    },
+    /// 304: the cached copy is still current
+    NotModified,
+}
+
+/// Interprets a `GET /repos/{owner}/{repo}` response
+///
+/// Shared by the native and browser clients so status handling stays identical.
+pub fn interpret_repo_response(
+    status: u16,
+    etag: Option<String>,
+    rate_limit: &RateLimit,
+    body: &str,
+) -> Result<FetchOutcome, ApiError> {
+    match status {
+        200..=299 => serde_json::from_str(body)
+            .map(|info| FetchOutcome::Modified {
+                info: Box::new(info),
+                etag,
+            })
+            .map_err(|e| ApiError::ParseError(e.to_string())),
+        304 => Ok(FetchOutcome::NotModified),
+        _ => Err(ApiError::from_status(status, rate_limit)),
+    }
+}
+
 // ============================================================================
 // STATIC DATA - Pre-fetched GitHub repository information
 // ============================================================================
 // This data is embedded at compile time for WASM compatibility.
-// Update this data periodically or via build script.
+// Refresh it with `cargo run --bin github-sync`, which rewrites the snapshot.
+
+/// Snapshot written by the `github-sync` binary (see `utils::github_sync`)
+const EMBEDDED_SNAPSHOT: &str = include_str!("../../.cache/github_repos.json");
+
+/// The embedded snapshot, parsed once
+///
+/// An unreadable snapshot yields an empty cache rather than a panic.
+static SNAPSHOT: LazyLock<GitHubCache> =
+    LazyLock::new(|| GitHubCache::load_or_empty(EMBEDDED_SNAPSHOT));
+
+/// Repos of the snapshot sorted by full name, collected once
+static STATIC_REPOS: LazyLock<Vec<GitHubRepoInfo>> = LazyLock::new(|| {
+    let mut repos: Vec<GitHubRepoInfo> = SNAPSHOT.repos().cloned().collect();
+    repos.sort_by_key(|r| r.full_name.to_lowercase());
+    repos
+});
+
+/// The embedded snapshot as a cache, entries stamped with their sync time
+pub(crate) fn embedded_snapshot() -> GitHubCache {
+    SNAPSHOT.clone()
+}
 
 /// Static repository data for enerBydev projects
-/// Last updated: 2026-02-05
-fn get_static_repo_data() -> Vec<GitHubRepoInfo> {
-    vec![
-        GitHubRepoInfo {
-            name: "enerby.dev".to_string(),
-            full_name: "enerBydev/enerby.dev".to_string(),
-            description: Some("Personal portfolio website built with Rust + Dioxus".to_string()),
-            homepage: Some("https://enerby.dev".to_string()),
-            language: Some("Rust".to_string()),
-            stargazers_count: 0,
-            forks_count: 0,
-            fork: false,
-            archived: false,
-            topics: vec![
-                "rust".to_string(),
-                "dioxus".to_string(),
-                "portfolio".to_string(),
-                "wasm".to_string(),
-            ],
-            html_url: "https://github.com/enerBydev/enerby.dev".to_string(),
-        },
-        GitHubRepoInfo {
-            name: "oc_diagdoc".to_string(),
-            full_name: "enerBydev/oc_diagdoc".to_string(),
-            description: Some(
-                "command-line-utilities, text-processing, development-tools".to_string(),
-            ),
-            homepage: Some("https://www.google.com".to_string()), // Placeholder confirmed on GitHub
-            language: Some("Rust".to_string()),
-            stargazers_count: 0,
-            forks_count: 0,
-            fork: false,
-            archived: false,
-            topics: vec!["rust".to_string()],
-            html_url: "https://github.com/enerBydev/oc_diagdoc".to_string(),
-        },
-        GitHubRepoInfo {
-            name: "nvim-config".to_string(),
-            full_name: "enerBydev/nvim-config".to_string(),
-            description: Some("Personal Neovim configuration".to_string()),
-            homepage: None,
-            language: Some("Lua".to_string()),
-            stargazers_count: 0,
-            forks_count: 0,
-            fork: false,
-            archived: false,
-            topics: vec![
-                "neovim".to_string(),
-                "lua".to_string(),
-                "dotfiles".to_string(),
-            ],
-            html_url: "https://github.com/enerBydev/nvim-config".to_string(),
-        },
-        GitHubRepoInfo {
-            name: "rust_projects".to_string(),
-            full_name: "enerBydev/rust_projects".to_string(),
-            description: Some("Collection of Rust learning projects".to_string()),
-            homepage: None,
-            language: Some("Rust".to_string()),
-            stargazers_count: 0,
-            forks_count: 0,
-            fork: false,
-            archived: false,
-            topics: vec!["rust".to_string(), "learning".to_string()],
-            html_url: "https://github.com/enerBydev/rust_projects".to_string(),
-        },
-    ]
+///
+/// From the embedded `.cache/github_repos.json` snapshot, sorted by full name.
+fn get_static_repo_data() -> &'static [GitHubRepoInfo] {
+    &STATIC_REPOS
 }
 
 // ============================================================================
@@ -290,8 +434,26 @@ pub fn get_repo_info(owner: &str, repo: &str) -> Result<GitHubRepoInfo, ApiError
     let full_name_lower = full_name.to_lowercase();
 
     get_static_repo_data()
-        .into_iter()
+        .iter()
         .find(|r| r.full_name.to_lowercase() == full_name_lower)
+        .cloned()
+        .ok_or(ApiError::NotFound)
+}
+
+/// Get repository info for a repo on any forge from static data
+///
+/// GitHub repos are matched by full name; repos on other forges by their web
+/// URL, since the same owner/name can exist on several forges.
+pub fn get_repo_info_for_ref(repo: &RepoRef) -> Result<GitHubRepoInfo, ApiError> {
+    if repo.forge == Forge::GitHub {
+        return get_repo_info(&repo.owner, &repo.name);
+    }
+
+    let web_url = repo.web_url();
+    get_static_repo_data()
+        .iter()
+        .find(|r| r.html_url.trim_end_matches('/').eq_ignore_ascii_case(&web_url))
+        .cloned()
         .ok_or(ApiError::NotFound)
 }
 
@@ -315,7 +477,7 @@ pub fn get_repo_homepage(owner: &str, repo: &str) -> Option<String> {
 /// Get all static repository info
 ///
 /// Returns all pre-loaded repository data.
-pub fn get_all_repos() -> Vec<GitHubRepoInfo> {
+pub fn get_all_repos() -> &'static [GitHubRepoInfo] {
     get_static_repo_data()
 }
 
@@ -333,50 +495,19 @@ pub fn fetch_multiple_repos(repos: &[(&str, &str)]) -> Vec<Result<GitHubRepoInfo
         .collect()
 }
 
-/// Parse owner and repo from a GitHub URL
+/// Get homepage from a repository URL string
 ///
-/// # Arguments
-/// * `github_url` - Full GitHub URL (e.g., "https://github.com/owner/repo")
-///
-/// # Returns
-/// * `Some((owner, repo))` if valid GitHub URL
-/// * `None` if invalid format
-pub fn parse_github_url(github_url: &str) -> Option<(String, String)> {
-    let url = github_url.trim();
-
-    // Handle different URL formats
-    let path = if url.starts_with("https://github.com/") {
-        url.strip_prefix("https://github.com/")
-    } else if url.starts_with("http://github.com/") {
-        url.strip_prefix("http://github.com/")
-    } else if url.starts_with("github.com/") {
-        url.strip_prefix("github.com/")
-    } else {
-        None
-    }?;
-
-    // Split path into owner/repo
-    let parts: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
-
-    if parts.len() >= 2 {
-        Some((parts[0].to_string(), parts[1].to_string()))
-    } else {
-        None
-    }
-}
-
-/// Get homepage from a GitHub URL string
-///
-/// Parses the URL, looks up the repo, and extracts homepage if available.
+/// Parses the URL (any supported forge), looks up the repo, and extracts
+/// homepage if available.
 ///
 /// # Arguments
-/// * `github_url` - Full GitHub URL
+/// * `repo_url` - Repository URL (see `forge::RepoRef::parse`)
 ///
 /// # Returns
 /// * `Some(homepage_url)` if found and valid
 /// * `None` otherwise
-pub fn get_homepage_from_url(github_url: &str) -> Option<String> {
-    parse_github_url(github_url).and_then(|(owner, repo)| get_repo_homepage(&owner, &repo))
+pub fn get_homepage_from_url(repo_url: &str) -> Option<String> {
+    RepoRef::parse(repo_url).and_then(|repo| repo.provider().homepage(&repo))
 }
 
 // ============================================================================
@@ -402,6 +533,7 @@ mod tests {
             "language": "Rust",
             "stargazers_count": 42,
             "forks_count": 5,
+            "open_issues_count": 2,
             "fork": false,
             "archived": false,
             "topics": ["rust", "dioxus", "portfolio"],
@@ -420,6 +552,7 @@ mod tests {
         assert_eq!(repo.language, Some("Rust".to_string()));
         assert_eq!(repo.stargazers_count, 42);
         assert_eq!(repo.forks_count, 5);
+        assert_eq!(repo.open_issues_count, 2);
         assert!(!repo.fork);
         assert!(!repo.archived);
         assert_eq!(repo.topics, vec!["rust", "dioxus", "portfolio"]);
@@ -470,10 +603,15 @@ mod tests {
             language: None,
             stargazers_count: 0,
             forks_count: 0,
+            open_issues_count: 0,
             fork: false,
             archived: false,
             topics: vec![],
             html_url: "https://github.com/owner/test".to_string(),
+            created_at: None,
+            pushed_at: None,
+            license: None,
+            languages: BTreeMap::new(),
         };
 
         assert_eq!(
@@ -493,10 +631,15 @@ mod tests {
             language: None,
             stargazers_count: 0,
             forks_count: 0,
+            open_issues_count: 0,
             fork: false,
             archived: false,
             topics: vec![],
             html_url: "https://github.com/owner/test".to_string(),
+            created_at: None,
+            pushed_at: None,
+            license: None,
+            languages: BTreeMap::new(),
         };
 
         assert_eq!(repo.extract_homepage(), None);
@@ -513,10 +656,15 @@ mod tests {
             language: None,
             stargazers_count: 0,
             forks_count: 0,
+            open_issues_count: 0,
             fork: false,
             archived: false,
             topics: vec![],
             html_url: "https://github.com/owner/test".to_string(),
+            created_at: None,
+            pushed_at: None,
+            license: None,
+            languages: BTreeMap::new(),
         };
 
         assert_eq!(repo.extract_homepage(), None);
@@ -533,10 +681,15 @@ mod tests {
             language: None,
             stargazers_count: 0,
             forks_count: 0,
+            open_issues_count: 0,
             fork: false,
             archived: false,
             topics: vec![],
             html_url: "https://github.com/owner/test".to_string(),
+            created_at: None,
+            pushed_at: None,
+            license: None,
+            languages: BTreeMap::new(),
         };
 
         assert_eq!(repo.extract_homepage(), None);
@@ -553,10 +706,15 @@ mod tests {
             language: None,
             stargazers_count: 0,
             forks_count: 0,
+            open_issues_count: 0,
             fork: false,
             archived: false,
             topics: vec![],
             html_url: "https://github.com/owner/test".to_string(),
+            created_at: None,
+            pushed_at: None,
+            license: None,
+            languages: BTreeMap::new(),
         };
 
         assert_eq!(
@@ -565,6 +723,80 @@ mod tests {
         );
     }
 
+    /// Test: languages default to empty and are omitted when empty
+    #[test]
+    fn test_languages_serde() {
+        let mut repo = GitHubRepoInfo::minimal("owner", "test");
+        let json = serde_json::to_string(&repo).unwrap();
+        assert!(!json.contains("languages"));
+
+        repo.languages.insert("Rust".to_string(), 100);
+        let json = serde_json::to_string(&repo).unwrap();
+        assert!(json.contains(r#""languages":{"Rust":100}"#));
+        assert_eq!(serde_json::from_str::<GitHubRepoInfo>(&json).unwrap(), repo);
+    }
+
+    /// Test: created_date keeps the day of the timestamp
+    #[test]
+    fn test_created_date() {
+        let mut repo = GitHubRepoInfo::minimal("owner", "test");
+        assert_eq!(repo.created_date(), None);
+
+        repo.created_at = Some("2025-01-15T10:00:00Z".to_string());
+        assert_eq!(repo.created_date(), NaiveDate::from_ymd_opt(2025, 1, 15));
+
+        repo.created_at = Some("yesterday".to_string());
+        assert_eq!(repo.created_date(), None);
+    }
+
+    /// Test: license and last push come from the GitHub response
+    #[test]
+    fn test_license_and_pushed_at() {
+        let json = r#"{
+            "name": "test",
+            "full_name": "owner/test",
+            "html_url": "https://github.com/owner/test",
+            "pushed_at": "2026-02-03T04:05:06Z",
+            "license": {"key": "mit", "name": "MIT License", "spdx_id": "MIT"}
+        }"#;
+        let repo: GitHubRepoInfo = serde_json::from_str(json).unwrap();
+
+        assert_eq!(repo.pushed_date(), NaiveDate::from_ymd_opt(2026, 2, 3));
+        assert_eq!(repo.license.as_ref().map(RepoLicense::label), Some("MIT"));
+
+        let other = RepoLicense {
+            spdx_id: Some("NOASSERTION".to_string()),
+            name: "Other".to_string(),
+        };
+        assert_eq!(other.label(), "Other");
+    }
+
+    /// Test: language_breakdown sorts by size and folds the tail into "other"
+    #[test]
+    fn test_language_breakdown() {
+        let languages: BTreeMap<String, u64> = [
+            ("CSS", 100),
+            ("HTML", 100),
+            ("Rust", 700),
+            ("Shell", 100),
+        ]
+        .into_iter()
+        .map(|(name, bytes)| (name.to_string(), bytes))
+        .collect();
+
+        let shares = language_breakdown(&languages, 2);
+        assert_eq!(
+            shares,
+            vec![(Some("Rust"), 70.0), (Some("CSS"), 10.0), (None, 20.0)]
+        );
+
+        let all = language_breakdown(&languages, 10);
+        assert_eq!(all.len(), 4);
+        assert!((all.iter().map(|(_, p)| p).sum::<f64>() - 100.0).abs() < 1e-9);
+
+        assert!(language_breakdown(&BTreeMap::new(), 3).is_empty());
+    }
+
     /// Test: GitHubApiConfig default values
     #[test]
     fn test_api_config_default() {
@@ -573,6 +805,7 @@ mod tests {
         assert_eq!(config.base_url, GITHUB_API_BASE_URL);
         assert_eq!(config.user_agent, USER_AGENT);
         assert_eq!(config.timeout_ms, API_TIMEOUT_MS);
+        assert!(config.token.is_none());
     }
 
     // -------------------------
@@ -600,6 +833,84 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_api_error_from_status() {
+        let exhausted = RateLimit {
+            remaining: Some(0),
+            ..RateLimit::default()
+        };
+        let available = RateLimit {
+            remaining: Some(12),
+            ..RateLimit::default()
+        };
+
+        assert_eq!(ApiError::from_status(404, &available), ApiError::NotFound);
+        assert_eq!(ApiError::from_status(429, &available), ApiError::RateLimited);
+        assert_eq!(ApiError::from_status(403, &exhausted), ApiError::RateLimited);
+        assert_eq!(ApiError::from_status(403, &available), ApiError::Forbidden);
+        assert_eq!(ApiError::from_status(403, &RateLimit::default()), ApiError::Forbidden);
+        assert_eq!(ApiError::from_status(401, &available), ApiError::Forbidden);
+        assert_eq!(ApiError::from_status(500, &available), ApiError::ServerError(500));
+    }
+
+    // -------------------------
+    // Rate Limit & Conditional Request Tests
+    // -------------------------
+
+    #[test]
+    fn test_rate_limit_from_headers() {
+        let rate_limit = RateLimit::from_headers(|name| match name {
+            "x-ratelimit-limit" => Some("60".to_string()),
+            "x-ratelimit-remaining" => Some(" 0 ".to_string()),
+            "x-ratelimit-reset" => Some("1770249600".to_string()),
+            _ => None,
+        });
+
+        assert_eq!(rate_limit.limit, Some(60));
+        assert_eq!(rate_limit.remaining, Some(0));
+        assert_eq!(rate_limit.reset_at, Some(1770249600));
+        assert!(rate_limit.is_exhausted());
+
+        let missing = RateLimit::from_headers(|_| None);
+        assert_eq!(missing, RateLimit::default());
+        assert!(!missing.is_exhausted());
+    }
+
+    #[test]
+    fn test_interpret_repo_response() {
+        let body = r#"{"name": "repo", "full_name": "owner/repo", "html_url": "https://github.com/owner/repo"}"#;
+        let none = RateLimit::default();
+
+        match interpret_repo_response(200, Some("\"abc\"".to_string()), &none, body) {
+            Ok(FetchOutcome::Modified { info, etag }) => {
+                assert_eq!(info.full_name, "owner/repo");
+                assert_eq!(etag.as_deref(), Some("\"abc\""));
+            }
+            other => panic!("unexpected outcome: {:?}", other),
+        }
+
+        assert_eq!(
+            interpret_repo_response(304, None, &none, ""),
+            Ok(FetchOutcome::NotModified)
+        );
+        assert_eq!(
+            interpret_repo_response(404, None, &none, "{}"),
+            Err(ApiError::NotFound)
+        );
+        let exhausted = RateLimit {
+            remaining: Some(0),
+            ..RateLimit::default()
+        };
+        assert_eq!(
+            interpret_repo_response(403, None, &exhausted, "{}"),
+            Err(ApiError::RateLimited)
+        );
+        assert!(matches!(
+            interpret_repo_response(200, None, &none, "not json"),
+            Err(ApiError::ParseError(_))
+        ));
+    }
+
     // -------------------------
     // Static Data Tests
     // -------------------------
@@ -644,6 +955,13 @@ mod tests {
         assert_eq!(result, None);
     }
 
+    #[test]
+    fn test_embedded_snapshot_parses() {
+        let cache = GitHubCache::from_json(EMBEDDED_SNAPSHOT);
+        assert!(cache.is_ok(), "embedded snapshot must be valid cache JSON");
+        assert!(!cache.unwrap().is_empty());
+    }
+
     #[test]
     fn test_get_all_repos() {
         let repos = get_all_repos();
@@ -652,41 +970,9 @@ mod tests {
     }
 
     // -------------------------
-    // URL Parsing Tests
+    // URL Lookup Tests
     // -------------------------
 
-    #[test]
-    fn test_parse_github_url_https() {
-        let result = parse_github_url("https://github.com/owner/repo");
-        assert_eq!(result, Some(("owner".to_string(), "repo".to_string())));
-    }
-
-    #[test]
-    fn test_parse_github_url_http() {
-        let result = parse_github_url("http://github.com/owner/repo");
-        assert_eq!(result, Some(("owner".to_string(), "repo".to_string())));
-    }
-
-    #[test]
-    fn test_parse_github_url_no_protocol() {
-        let result = parse_github_url("github.com/owner/repo");
-        assert_eq!(result, Some(("owner".to_string(), "repo".to_string())));
-    }
-
-    #[test]
-    fn test_parse_github_url_with_trailing_slash() {
-        let result = parse_github_url("https://github.com/owner/repo/");
-        assert_eq!(result, Some(("owner".to_string(), "repo".to_string())));
-    }
-
-    #[test]
-    fn test_parse_github_url_invalid() {
-        assert_eq!(parse_github_url("https://gitlab.com/owner/repo"), None);
-        assert_eq!(parse_github_url("not-a-url"), None);
-        assert_eq!(parse_github_url("https://github.com/"), None);
-        assert_eq!(parse_github_url("https://github.com/owner"), None);
-    }
-
     #[test]
     fn test_get_homepage_from_url() {
         let result = get_homepage_from_url("https://github.com/enerBydev/enerby.dev");
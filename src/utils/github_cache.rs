@@ -5,7 +5,7 @@
 //!
 //! # Architecture
 //!
-//! The cache uses a simple key-value store where:
+//! `GitHubCache` wraps the generic `Cache` (see `utils::cache`) where:
 //! - Key: "owner/repo" (lowercase)
 //! - Value: `CacheEntry<GitHubRepoInfo>` with timestamp
 //!
@@ -14,6 +14,16 @@
 //! Default TTL is 1 hour. After expiration, the cache entry is considered stale
 //! but can still be used as a fallback if the API is unavailable.
 //!
+//! Time is read through the cache's `Clock` (see `utils::clock`), so TTLs work
+//! in WASM and tests can drive expiry with a `MockClock`.
+//!
+//! # Persistence
+//!
+//! The cache is stored in `.cache/github_repos.json` on native and in
+//! `localStorage` in WASM. On load, persisted entries are merged over the
+//! static snapshot, which keeps its sync time: newer entries win, and stale
+//! ones stay as fallbacks whose `ETag` lets the next refresh be a 304.
+//!
 //! # Example
 //! ```rust,ignore
 //! use crate::utils::github_cache::GitHubCache;
@@ -24,18 +34,23 @@
 //! ```
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+
+#[cfg(target_arch = "wasm32")]
+use super::cache::BrowserStorage;
+#[cfg(not(target_arch = "wasm32"))]
+use super::cache::FileStorage;
+use super::cache::{Cache, CacheStats, CacheStorage};
+use super::clock::SharedClock;
+use super::github_api::{ApiError, FetchOutcome, GitHubRepoInfo};
 
-use super::github_api::GitHubRepoInfo;
+pub use super::cache::{CacheEntry, DEFAULT_TTL_SECONDS};
 
 // ============================================================================
 // CONSTANTS
 // ============================================================================
 
-/// Default cache TTL in seconds (1 hour)
-pub const DEFAULT_TTL_SECONDS: u64 = 3600;
-
 /// Cache file path (relative to project root)
 pub const CACHE_FILE_PATH: &str = ".cache/github_repos.json";
 
@@ -43,85 +58,46 @@ pub const CACHE_FILE_PATH: &str = ".cache/github_repos.json";
 // DATA STRUCTURES
 // ============================================================================
 
-/// A cached entry with timestamp for TTL calculation
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct CacheEntry<T> {
-    /// The cached data
-    pub data: T,
-    /// Unix timestamp when the entry was cached
-    pub cached_at: u64,
-    /// TTL in seconds (for this specific entry)
-    pub ttl_seconds: u64,
-}
-
-impl<T> CacheEntry<T> {
-    /// Creates a new cache entry with current timestamp
-    pub fn new(data: T, ttl_seconds: u64) -> Self {
-        let cached_at = get_now_seconds();
-
-        Self {
-            data,
-            cached_at,
-            ttl_seconds,
-        }
-    }
-
-    /// Creates a new cache entry with default TTL
-    pub fn with_default_ttl(data: T) -> Self {
-        Self::new(data, DEFAULT_TTL_SECONDS)
-    }
-
-    /// Checks if this cache entry has expired
-    pub fn is_expired(&self) -> bool {
-        let now = get_now_seconds();
-
-        now > self.cached_at + self.ttl_seconds
-    }
-
-    /// Returns the age of this cache entry in seconds
-    pub fn age_seconds(&self) -> u64 {
-        let now = get_now_seconds();
-
-        now.saturating_sub(self.cached_at)
-    }
-
-    /// Returns remaining TTL in seconds (0 if expired)
-    pub fn remaining_ttl(&self) -> u64 {
-        let expiry = self.cached_at + self.ttl_seconds;
-        let now = get_now_seconds();
-
-        expiry.saturating_sub(now)
-    }
-}
-
 /// In-memory cache for GitHub repository data
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct GitHubCache {
     /// Internal storage: key = "owner/repo" (lowercase)
-    entries: HashMap<String, CacheEntry<GitHubRepoInfo>>,
-    /// Default TTL for new entries
-    #[serde(default = "default_ttl")]
-    default_ttl: u64,
+    inner: Cache<String, GitHubRepoInfo>,
+}
+
+/// Outcome of `GitHubCache::refresh_plan`
+#[derive(Debug, Clone, PartialEq)]
+pub enum RefreshPlan {
+    /// Serve this entry without a request
+    Cached(Box<GitHubRepoInfo>),
+    /// Request the repo, sending `etag` as `If-None-Match` if there is one
+    Fetch { etag: Option<String> },
 }
 
-fn default_ttl() -> u64 {
-    DEFAULT_TTL_SECONDS
+impl Default for GitHubCache {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl GitHubCache {
     /// Creates a new empty cache with default TTL
     pub fn new() -> Self {
-        Self {
-            entries: HashMap::new(),
-            default_ttl: DEFAULT_TTL_SECONDS,
-        }
+        Self::with_ttl(DEFAULT_TTL_SECONDS)
     }
 
     /// Creates a new cache with custom TTL
     pub fn with_ttl(ttl_seconds: u64) -> Self {
         Self {
-            entries: HashMap::new(),
-            default_ttl: ttl_seconds,
+            inner: Cache::new(ttl_seconds),
+        }
+    }
+
+    /// Creates a new cache with default TTL that reads time from `clock`
+    pub fn with_clock(clock: SharedClock) -> Self {
+        Self {
+            inner: Cache::new(DEFAULT_TTL_SECONDS).with_clock(clock),
         }
     }
 
@@ -130,37 +106,30 @@ impl GitHubCache {
         format!("{}/{}", owner.to_lowercase(), repo.to_lowercase())
     }
 
+    /// Returns the hit/miss/eviction counters
+    pub fn stats(&self) -> CacheStats {
+        self.inner.stats()
+    }
+
     /// Gets a cached entry if it exists and is not expired
     ///
     /// Returns `None` if:
     /// - Entry doesn't exist
     /// - Entry has expired
     pub fn get(&self, owner: &str, repo: &str) -> Option<&GitHubRepoInfo> {
-        let key = Self::make_key(owner, repo);
-        self.entries.get(&key).and_then(|entry| {
-            if entry.is_expired() {
-                None
-            } else {
-                Some(&entry.data)
-            }
-        })
+        self.inner.get(Self::make_key(owner, repo).as_str())
     }
 
     /// Gets a cached entry even if expired (for fallback use)
     ///
     /// Returns `Some((data, is_stale))` if entry exists
     pub fn get_with_stale(&self, owner: &str, repo: &str) -> Option<(&GitHubRepoInfo, bool)> {
-        let key = Self::make_key(owner, repo);
-        self.entries
-            .get(&key)
-            .map(|entry| (&entry.data, entry.is_expired()))
+        self.inner.get_with_stale(Self::make_key(owner, repo).as_str())
     }
 
     /// Sets a cache entry with default TTL
     pub fn set(&mut self, owner: &str, repo: &str, data: GitHubRepoInfo) {
-        let key = Self::make_key(owner, repo);
-        let entry = CacheEntry::new(data, self.default_ttl);
-        self.entries.insert(key, entry);
+        self.inner.insert(Self::make_key(owner, repo), data);
     }
 
     /// Sets a cache entry with custom TTL
@@ -171,51 +140,116 @@ impl GitHubCache {
         data: GitHubRepoInfo,
         ttl_seconds: u64,
     ) {
-        let key = Self::make_key(owner, repo);
-        let entry = CacheEntry::new(data, ttl_seconds);
-        self.entries.insert(key, entry);
+        self.inner
+            .insert_with_ttl(Self::make_key(owner, repo), data, ttl_seconds);
+    }
+
+    /// Sets a cache entry with default TTL and the response `ETag`
+    pub fn set_with_etag(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        data: GitHubRepoInfo,
+        etag: Option<String>,
+    ) {
+        let mut entry = CacheEntry::new(data, self.inner.default_ttl(), self.inner.clock());
+        entry.etag = etag;
+        self.inner.insert_entry(Self::make_key(owner, repo), entry);
+    }
+
+    /// Returns the stored `ETag` for an entry (fresh or stale)
+    pub fn etag(&self, owner: &str, repo: &str) -> Option<&str> {
+        self.inner
+            .entry(Self::make_key(owner, repo).as_str())
+            .and_then(|e| e.etag.as_deref())
+    }
+
+    /// What a browser refresh of an entry should do
+    ///
+    /// Fresh entries validated by an earlier request (they have an `ETag`) are
+    /// served as is; anything else is fetched, conditionally when an `ETag`
+    /// is stored.
+    pub fn refresh_plan(&self, owner: &str, repo: &str) -> RefreshPlan {
+        let etag = self.etag(owner, repo).map(str::to_string);
+        match (etag, self.get(owner, repo)) {
+            (Some(_), Some(fresh)) => RefreshPlan::Cached(Box::new(fresh.clone())),
+            (etag, _) => RefreshPlan::Fetch { etag },
+        }
+    }
+
+    /// Restarts an entry's TTL without changing its data (HTTP 304)
+    ///
+    /// Returns false if there is no such entry.
+    pub fn touch(&mut self, owner: &str, repo: &str) -> bool {
+        self.inner.touch(Self::make_key(owner, repo).as_str())
+    }
+
+    /// Stores the outcome of a conditional request and returns the current data
+    ///
+    /// `NotModified` only refreshes the TTL; it yields `None` if nothing was cached.
+    pub fn apply_fetch(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        outcome: FetchOutcome,
+    ) -> Option<GitHubRepoInfo> {
+        match outcome {
+            FetchOutcome::Modified { info, etag } => {
+                self.set_with_etag(owner, repo, (*info).clone(), etag);
+                Some(*info)
+            }
+            FetchOutcome::NotModified => {
+                self.touch(owner, repo);
+                self.get(owner, repo).cloned()
+            }
+        }
     }
 
     /// Removes an entry from the cache
     pub fn remove(&mut self, owner: &str, repo: &str) -> Option<GitHubRepoInfo> {
-        let key = Self::make_key(owner, repo);
-        self.entries.remove(&key).map(|e| e.data)
+        self.inner.remove(Self::make_key(owner, repo).as_str())
     }
 
     /// Clears all entries from the cache
     pub fn clear(&mut self) {
-        self.entries.clear();
+        self.inner.clear();
     }
 
     /// Returns the number of entries in the cache (including expired)
     pub fn len(&self) -> usize {
-        self.entries.len()
+        self.inner.len()
     }
 
-    /// Returns true if the cache is empty
+    /// Checks if the cache is empty
     pub fn is_empty(&self) -> bool {
-        self.entries.is_empty()
+        self.inner.is_empty()
     }
 
     /// Returns the number of non-expired entries
     pub fn len_valid(&self) -> usize {
-        self.entries.values().filter(|e| !e.is_expired()).count()
+        self.inner.len_valid()
     }
 
     /// Removes all expired entries from the cache
     pub fn cleanup_expired(&mut self) {
-        self.entries.retain(|_, entry| !entry.is_expired());
+        self.inner.cleanup_expired();
+    }
+
+    /// Iterates over all cached repos (including stale entries)
+    pub fn repos(&self) -> impl Iterator<Item = &GitHubRepoInfo> {
+        self.inner.values()
     }
 
     /// Checks if a fresh (non-expired) entry exists
     pub fn has_fresh(&self, owner: &str, repo: &str) -> bool {
-        self.get(owner, repo).is_some()
+        self.inner
+            .contains_fresh(Self::make_key(owner, repo).as_str())
     }
 
     /// Checks if any entry (including stale) exists
     pub fn has_any(&self, owner: &str, repo: &str) -> bool {
-        let key = Self::make_key(owner, repo);
-        self.entries.contains_key(&key)
+        self.inner
+            .contains_key(Self::make_key(owner, repo).as_str())
     }
 
     // =========================================================================
@@ -240,30 +274,75 @@ impl GitHubCache {
     /// Merges another cache into this one
     /// Newer entries (by timestamp) take precedence
     pub fn merge(&mut self, other: Self) {
-        for (key, entry) in other.entries {
-            match self.entries.get(&key) {
-                Some(existing) if existing.cached_at >= entry.cached_at => {
-                    // Keep existing (newer or same age)
-                }
-                _ => {
-                    // Insert new entry
-                    self.entries.insert(key, entry);
-                }
+        self.inner.merge(other.inner);
+    }
+
+    /// Merges a persisted cache into this one
+    ///
+    /// Persisted entries replace older in-memory ones (see `merge`), expired
+    /// or not: stale data is still a fallback, and its `ETag` revalidates it.
+    pub fn merge_persisted(&mut self, persisted: Self) {
+        self.merge(persisted);
+    }
+
+    /// Returns the entry, refreshing it through `fetch` when missing or expired
+    ///
+    /// If the refresh fails, a stale entry is served instead of the error.
+    pub fn get_or_refresh<F>(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        fetch: F,
+    ) -> Result<GitHubRepoInfo, ApiError>
+    where
+        F: FnOnce(&str, &str) -> Result<GitHubRepoInfo, ApiError>,
+    {
+        if let Some(fresh) = self.get(owner, repo) {
+            return Ok(fresh.clone());
+        }
+
+        match fetch(owner, repo) {
+            Ok(info) => {
+                self.set(owner, repo, info.clone());
+                Ok(info)
             }
+            Err(err) => self
+                .get_with_stale(owner, repo)
+                .map(|(data, _)| data.clone())
+                .ok_or(err),
         }
     }
 
+    /// Loads a cache from storage; `None` if nothing is stored or it does not parse
+    pub fn load(storage: &dyn CacheStorage) -> Option<Self> {
+        Cache::load(storage).map(|inner| Self { inner })
+    }
+
+    /// Writes the cache to storage (pretty JSON, sorted keys)
+    pub fn save(&self, storage: &dyn CacheStorage) -> Result<(), String> {
+        self.inner.save(storage)
+    }
+
+    /// Reads a cache file
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_from_file(path: &Path) -> Option<Self> {
+        Self::load(&FileStorage::new(path))
+    }
+
+    /// Writes a cache file, creating parent directories as needed
+    ///
+    /// Keys are sorted so the committed snapshot diffs cleanly between runs.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save_to_file(&self, path: &Path) -> Result<(), String> {
+        self.save(&FileStorage::new(path))
+    }
+
     /// Pre-populates cache from static data (used for initialization)
+    ///
+    /// Entries keep the snapshot's `cached_at`, so anything persisted since
+    /// the sync is newer and wins in `merge_persisted`.
     pub fn populate_from_static(&mut self) {
-        use super::github_api::get_all_repos;
-
-        for repo in get_all_repos() {
-            // Clone full_name before split to avoid borrow issues
-            let full_name = repo.full_name.clone();
-            if let Some((owner, name)) = full_name.split_once('/') {
-                self.set(owner, name, repo);
-            }
-        }
+        self.merge(super::github_api::embedded_snapshot());
     }
 }
 
@@ -277,12 +356,20 @@ use std::sync::OnceLock;
 static GLOBAL_CACHE: OnceLock<std::sync::RwLock<GitHubCache>> = OnceLock::new();
 
 /// Gets a reference to the global cache
+///
+/// Seeded from static data, then merged with the persisted cache.
 pub fn global_cache() -> &'static std::sync::RwLock<GitHubCache> {
-    GLOBAL_CACHE.get_or_init(|| {
-        let mut cache = GitHubCache::new();
-        cache.populate_from_static();
-        std::sync::RwLock::new(cache)
-    })
+    GLOBAL_CACHE.get_or_init(|| std::sync::RwLock::new(restore_cache(load_persisted())))
+}
+
+/// The static snapshot with `persisted` merged over it (see `global_cache`)
+fn restore_cache(persisted: Option<GitHubCache>) -> GitHubCache {
+    let mut cache = GitHubCache::new();
+    cache.populate_from_static();
+    if let Some(persisted) = persisted {
+        cache.merge_persisted(persisted);
+    }
+    cache
 }
 
 /// Gets a cached repo from global cache
@@ -300,23 +387,62 @@ pub fn set_cached_repo(owner: &str, repo: &str, data: GitHubRepoInfo) {
     }
 }
 
-// Helper for getting current time in seconds
-// In WASM, SystemTime is not supported without bindings, so we return a dummy value (non-zero)
-// This effectively disables TTL/expiration logic in WASM usage.
+/// Gets a repo through the global cache, refreshing it via `fetch` when needed
+///
+/// Successful refreshes are persisted; failed ones fall back to stale data.
+pub fn get_repo_with_fallback<F>(
+    owner: &str,
+    repo: &str,
+    fetch: F,
+) -> Result<GitHubRepoInfo, ApiError>
+where
+    F: FnOnce(&str, &str) -> Result<GitHubRepoInfo, ApiError>,
+{
+    let mut cache = global_cache()
+        .write()
+        .map_err(|_| ApiError::NetworkError("cache lock poisoned".to_string()))?;
+
+    let was_fresh = cache.has_fresh(owner, repo);
+    let result = cache.get_or_refresh(owner, repo, fetch);
+    if !was_fresh && cache.has_fresh(owner, repo) {
+        let _ = save_persisted(&cache);
+    }
+    result
+}
+
+/// Saves the global cache to persistent storage
+pub fn save_global_cache() -> Result<(), String> {
+    let cache = global_cache().read().map_err(|e| e.to_string())?;
+    save_persisted(&cache)
+}
+
+// ============================================================================
+// PERSISTENT STORAGE
+// ============================================================================
+
+/// localStorage key for the browser copy of the cache
+pub const STORAGE_KEY: &str = "enerby_github_cache";
+
+/// Storage for the persisted cache: `CACHE_FILE_PATH` on native
 #[cfg(not(target_arch = "wasm32"))]
-fn get_now_seconds() -> u64 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs()
+fn persistent_storage() -> impl CacheStorage {
+    FileStorage::new(CACHE_FILE_PATH)
 }
 
+/// Storage for the persisted cache: `localStorage[STORAGE_KEY]` in the browser
 #[cfg(target_arch = "wasm32")]
-fn get_now_seconds() -> u64 {
-    // Return a constant starting point.
-    // Since cached_at will also use this, expired checks (now > cached_at + ttl)
-    // will be: 1000 > 1000 + 3600 -> false (not expired).
-    1000
+fn persistent_storage() -> impl CacheStorage {
+    BrowserStorage::new(STORAGE_KEY)
+}
+
+/// Loads the persisted cache
+pub fn load_persisted() -> Option<GitHubCache> {
+    GitHubCache::load(&persistent_storage())
+}
+
+/// Saves the cache to persistent storage
+pub fn save_persisted(cache: &GitHubCache) -> Result<(), String> {
+    cache.save(&persistent_storage())
 }
 
 // ============================================================================
@@ -326,6 +452,18 @@ fn get_now_seconds() -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::cache::MemoryStorage;
+    use crate::utils::clock::MockClock;
+    use std::sync::Arc;
+
+    /// Fixed start time for mock clocks
+    const T0: u64 = 1_700_000_000;
+
+    /// Returns a cache driven by a mock clock, plus a handle to advance it
+    fn mock_cache() -> (GitHubCache, MockClock) {
+        let clock = MockClock::new(T0);
+        (GitHubCache::with_clock(Arc::new(clock.clone())), clock)
+    }
 
     fn create_test_repo(name: &str) -> GitHubRepoInfo {
         GitHubRepoInfo {
@@ -336,10 +474,15 @@ mod tests {
             language: Some("Rust".to_string()),
             stargazers_count: 10,
             forks_count: 2,
+            open_issues_count: 0,
             fork: false,
             archived: false,
             topics: vec!["test".to_string()],
             html_url: format!("https://github.com/owner/{}", name),
+            created_at: None,
+            pushed_at: None,
+            license: None,
+            languages: Default::default(),
         }
     }
 
@@ -349,58 +492,77 @@ mod tests {
 
     #[test]
     fn test_cache_entry_new() {
+        let clock = MockClock::new(T0);
         let repo = create_test_repo("test");
-        let entry = CacheEntry::new(repo.clone(), 3600);
+        let entry = CacheEntry::new(repo.clone(), 3600, &clock);
 
         assert_eq!(entry.data.name, "test");
         assert_eq!(entry.ttl_seconds, 3600);
-        assert!(entry.cached_at > 0);
+        assert_eq!(entry.cached_at, T0);
     }
 
     #[test]
     fn test_cache_entry_with_default_ttl() {
+        let clock = MockClock::new(T0);
         let repo = create_test_repo("test");
-        let entry = CacheEntry::with_default_ttl(repo);
+        let entry = CacheEntry::with_default_ttl(repo, &clock);
 
         assert_eq!(entry.ttl_seconds, DEFAULT_TTL_SECONDS);
     }
 
     #[test]
     fn test_cache_entry_not_expired() {
+        let clock = MockClock::new(T0);
         let repo = create_test_repo("test");
-        let entry = CacheEntry::new(repo, 3600); // 1 hour TTL
+        let entry = CacheEntry::new(repo, 3600, &clock); // 1 hour TTL
 
-        assert!(!entry.is_expired());
+        clock.advance(3600);
+        assert!(!entry.is_expired(&clock));
     }
 
     #[test]
     fn test_cache_entry_expired() {
+        let clock = MockClock::new(T0);
         let repo = create_test_repo("test");
-        let mut entry = CacheEntry::new(repo, 1); // 1 second TTL
-
-        // Force expiration by setting cached_at to the past
-        entry.cached_at = entry.cached_at.saturating_sub(10);
+        let entry = CacheEntry::new(repo, 1, &clock); // 1 second TTL
 
-        assert!(entry.is_expired());
+        clock.advance(2);
+        assert!(entry.is_expired(&clock));
     }
 
     #[test]
     fn test_cache_entry_age() {
+        let clock = MockClock::new(T0);
         let repo = create_test_repo("test");
-        let entry = CacheEntry::new(repo, 3600);
+        let entry = CacheEntry::new(repo, 3600, &clock);
 
-        // Age should be very small (just created)
-        assert!(entry.age_seconds() < 2);
+        assert_eq!(entry.age_seconds(&clock), 0);
+        clock.advance(90);
+        assert_eq!(entry.age_seconds(&clock), 90);
     }
 
     #[test]
     fn test_cache_entry_remaining_ttl() {
+        let clock = MockClock::new(T0);
         let repo = create_test_repo("test");
-        let entry = CacheEntry::new(repo, 3600);
+        let entry = CacheEntry::new(repo, 3600, &clock);
+
+        assert_eq!(entry.remaining_ttl(&clock), 3600);
+        clock.advance(600);
+        assert_eq!(entry.remaining_ttl(&clock), 3000);
+        clock.advance(5000);
+        assert_eq!(entry.remaining_ttl(&clock), 0);
+    }
+
+    #[test]
+    fn test_entry_stamped_before_clock_moves_back() {
+        // A persisted entry from the "future" (clock skew) is never expired early
+        let clock = MockClock::new(T0);
+        let entry = CacheEntry::new(create_test_repo("test"), 60, &clock);
 
-        // Remaining TTL should be close to 3600
-        let remaining = entry.remaining_ttl();
-        assert!(remaining > 3590 && remaining <= 3600);
+        clock.set(T0 - 1000);
+        assert!(!entry.is_expired(&clock));
+        assert_eq!(entry.age_seconds(&clock), 0);
     }
 
     // -------------------------
@@ -417,7 +579,7 @@ mod tests {
     #[test]
     fn test_cache_with_custom_ttl() {
         let cache = GitHubCache::with_ttl(7200);
-        assert_eq!(cache.default_ttl, 7200);
+        assert_eq!(cache.inner.default_ttl(), 7200);
     }
 
     #[test]
@@ -452,7 +614,7 @@ mod tests {
 
     #[test]
     fn test_cache_get_with_stale() {
-        let mut cache = GitHubCache::new();
+        let (mut cache, clock) = mock_cache();
         let repo = create_test_repo("test");
 
         cache.set("owner", "test", repo);
@@ -463,6 +625,10 @@ mod tests {
         let (data, is_stale) = result.unwrap();
         assert_eq!(data.name, "test");
         assert!(!is_stale); // Just created, should not be stale
+
+        clock.advance(DEFAULT_TTL_SECONDS + 1);
+        assert!(cache.get("owner", "test").is_none());
+        assert_eq!(cache.get_with_stale("owner", "test").map(|(_, stale)| stale), Some(true));
     }
 
     #[test]
@@ -492,14 +658,11 @@ mod tests {
 
     #[test]
     fn test_cache_len_valid() {
-        let mut cache = GitHubCache::new();
+        let (mut cache, clock) = mock_cache();
         cache.set("owner", "repo1", create_test_repo("repo1"));
         cache.set_with_ttl("owner", "repo2", create_test_repo("repo2"), 1);
 
-        // Force repo2 to expire
-        if let Some(entry) = cache.entries.get_mut("owner/repo2") {
-            entry.cached_at = entry.cached_at.saturating_sub(10);
-        }
+        clock.advance(10);
 
         assert_eq!(cache.len(), 2);
         assert_eq!(cache.len_valid(), 1);
@@ -507,14 +670,11 @@ mod tests {
 
     #[test]
     fn test_cache_cleanup_expired() {
-        let mut cache = GitHubCache::new();
+        let (mut cache, clock) = mock_cache();
         cache.set("owner", "fresh", create_test_repo("fresh"));
         cache.set_with_ttl("owner", "stale", create_test_repo("stale"), 1);
 
-        // Force stale to expire
-        if let Some(entry) = cache.entries.get_mut("owner/stale") {
-            entry.cached_at = entry.cached_at.saturating_sub(10);
-        }
+        clock.advance(10);
 
         assert_eq!(cache.len(), 2);
         cache.cleanup_expired();
@@ -524,17 +684,14 @@ mod tests {
 
     #[test]
     fn test_cache_has_fresh_and_has_any() {
-        let mut cache = GitHubCache::new();
+        let (mut cache, clock) = mock_cache();
         cache.set_with_ttl("owner", "test", create_test_repo("test"), 1);
 
         // Fresh entry
         assert!(cache.has_fresh("owner", "test"));
         assert!(cache.has_any("owner", "test"));
 
-        // Force expiration
-        if let Some(entry) = cache.entries.get_mut("owner/test") {
-            entry.cached_at = entry.cached_at.saturating_sub(10);
-        }
+        clock.advance(10);
 
         // Stale entry
         assert!(!cache.has_fresh("owner", "test"));
@@ -607,23 +764,228 @@ mod tests {
         let mut cache = GitHubCache::new();
         cache.populate_from_static();
 
-        // Should have the static repos from github_api
+        // Should have the static repos from github_api, with their sync time
         assert!(cache.len() >= 4);
-        assert!(cache.get("enerBydev", "enerby.dev").is_some());
+        assert!(cache.get_with_stale("enerBydev", "enerby.dev").is_some());
+        let snapshot = crate::utils::github_api::embedded_snapshot();
+        let synced_at = |cache: &GitHubCache| {
+            let entry = cache.inner.entry("enerbydev/enerby.dev");
+            entry.map(|e| e.cached_at)
+        };
+        assert_eq!(synced_at(&cache), synced_at(&snapshot));
+    }
+
+    #[test]
+    fn test_merge_persisted_keeps_stale_entries() {
+        let (mut cache, clock) = mock_cache();
+        cache.set("owner", "kept", create_test_repo("kept"));
+
+        let mut persisted = GitHubCache::with_clock(Arc::new(clock.clone()));
+        persisted.set("owner", "fresh", create_test_repo("fresh"));
+        persisted.set_with_etag(
+            "owner",
+            "expired",
+            create_test_repo("expired"),
+            Some("\"1\"".to_string()),
+        );
+        clock.advance(DEFAULT_TTL_SECONDS / 2);
+
+        cache.merge_persisted(persisted);
+
+        assert!(cache.get("owner", "kept").is_some());
+        assert!(cache.get("owner", "fresh").is_some());
+        clock.advance(DEFAULT_TTL_SECONDS);
+        assert!(cache.get_with_stale("owner", "expired").unwrap().1);
+        assert_eq!(cache.etag("owner", "expired"), Some("\"1\""));
+    }
+
+    #[test]
+    fn test_restored_cache_keeps_persisted_data_and_etag() {
+        // Persisted after the snapshot was synced, like a browser refresh
+        let mut refreshed = create_test_repo("enerby.dev");
+        refreshed.full_name = "enerBydev/enerby.dev".to_string();
+        refreshed.stargazers_count = 99;
+        let mut persisted = GitHubCache::new();
+        persisted.set_with_etag(
+            "enerBydev",
+            "enerby.dev",
+            refreshed,
+            Some("\"abc\"".to_string()),
+        );
+
+        let storage = MemoryStorage::default();
+        persisted.save(&storage).unwrap();
+        let cache = restore_cache(GitHubCache::load(&storage));
+
+        let repo = cache.get("enerBydev", "enerby.dev").unwrap();
+        assert_eq!(repo.stargazers_count, 99);
+        assert_eq!(cache.etag("enerBydev", "enerby.dev"), Some("\"abc\""));
+        // Repos never refreshed still come from the snapshot
+        assert!(cache.has_any("enerBydev", "nvim-config"));
+    }
+
+    #[test]
+    fn test_merge_persisted_newer_entry_wins() {
+        let (mut cache, clock) = mock_cache();
+        cache.set("owner", "repo", create_test_repo("repo"));
+        clock.advance(100);
+
+        let mut updated = create_test_repo("repo");
+        updated.stargazers_count = 99;
+        let mut persisted = GitHubCache::with_clock(Arc::new(clock.clone()));
+        persisted.set("owner", "repo", updated);
+
+        cache.merge_persisted(persisted);
+        assert_eq!(cache.get("owner", "repo").unwrap().stargazers_count, 99);
+    }
+
+    #[test]
+    fn test_file_round_trip() {
+        let mut cache = GitHubCache::new();
+        cache.set("owner", "zeta", create_test_repo("zeta"));
+        cache.set("owner", "alpha", create_test_repo("alpha"));
+
+        let dir = std::env::temp_dir().join(format!("github_cache_test_{}", std::process::id()));
+        let path = dir.join("nested").join("github_repos.json");
+        cache.save_to_file(&path).expect("write cache file");
+
+        let json = std::fs::read_to_string(&path).unwrap();
+        let restored = GitHubCache::load_from_file(&path);
+        let _ = std::fs::remove_dir_all(&dir);
+
+        // Sorted keys keep the committed file stable
+        assert!(json.find("owner/alpha").unwrap() < json.find("owner/zeta").unwrap());
+
+        let restored = restored.expect("cache file parses back");
+        assert!(restored.get("owner", "alpha").is_some());
+        assert!(restored.get("owner", "zeta").is_some());
+    }
+
+    #[test]
+    fn test_load_from_missing_file() {
+        let path = std::env::temp_dir().join("github_cache_missing_file.json");
+        assert!(GitHubCache::load_from_file(&path).is_none());
     }
 
     // -------------------------
-    // Global Cache Tests
+    // ETag Tests
     // -------------------------
 
     #[test]
-    fn test_global_cache_get() {
-        // Global cache should be populated with static data
-        let repo = get_cached_repo("enerBydev", "enerby.dev");
-        assert!(repo.is_some());
+    fn test_cache_etag_round_trip() {
+        let mut cache = GitHubCache::new();
+        cache.set_with_etag("Owner", "Repo", create_test_repo("repo"), Some("W/\"1\"".to_string()));
+
+        assert_eq!(cache.etag("owner", "repo"), Some("W/\"1\""));
+
+        let restored = GitHubCache::from_json(&cache.to_json().unwrap()).unwrap();
+        assert_eq!(restored.etag("owner", "repo"), Some("W/\"1\""));
+
+        // Entries without an ETag omit the field and still parse
+        cache.set("owner", "plain", create_test_repo("plain"));
+        assert_eq!(cache.etag("owner", "plain"), None);
+    }
+
+    #[test]
+    fn test_apply_fetch_modified_and_not_modified() {
+        let (mut cache, clock) = mock_cache();
+
+        let modified = FetchOutcome::Modified {
+            info: Box::new(create_test_repo("repo")),
+            etag: Some("\"v1\"".to_string()),
+        };
+        assert!(cache.apply_fetch("owner", "repo", modified).is_some());
+        assert_eq!(cache.etag("owner", "repo"), Some("\"v1\""));
+
+        // 304 refreshes an expired entry and keeps its data and ETag
+        clock.advance(DEFAULT_TTL_SECONDS + 10);
+        assert!(!cache.has_fresh("owner", "repo"));
+
+        let kept = cache.apply_fetch("owner", "repo", FetchOutcome::NotModified);
+        assert_eq!(kept.unwrap().name, "repo");
+        assert!(cache.has_fresh("owner", "repo"));
+        assert_eq!(cache.etag("owner", "repo"), Some("\"v1\""));
+
+        // 304 for an unknown entry yields nothing
+        assert!(cache.apply_fetch("owner", "other", FetchOutcome::NotModified).is_none());
+    }
+
+    // -------------------------
+    // Stale Fallback Tests
+    // -------------------------
+
+    #[test]
+    fn test_get_or_refresh_uses_fresh_entry() {
+        let mut cache = GitHubCache::new();
+        cache.set("owner", "repo", create_test_repo("repo"));
+
+        let result = cache.get_or_refresh("owner", "repo", |_, _| {
+            panic!("fresh entries must not be refetched")
+        });
+        assert_eq!(result.unwrap().name, "repo");
+    }
+
+    #[test]
+    fn test_get_or_refresh_stores_fetched_entry() {
+        let mut cache = GitHubCache::new();
+
+        let result =
+            cache.get_or_refresh("owner", "repo", |o, r| Ok(GitHubRepoInfo::minimal(o, r)));
+
+        assert!(result.is_ok());
+        assert!(cache.has_fresh("owner", "repo"));
+    }
+
+    #[test]
+    fn test_get_or_refresh_serves_stale_on_failure() {
+        let (mut cache, clock) = mock_cache();
+        cache.set_with_ttl("owner", "repo", create_test_repo("repo"), 1);
+        clock.advance(10);
+
+        let result = cache.get_or_refresh("owner", "repo", |_, _| Err(ApiError::RateLimited));
+        assert_eq!(result.unwrap().name, "repo");
+
+        let missing = cache.get_or_refresh("owner", "other", |_, _| Err(ApiError::RateLimited));
+        assert_eq!(missing, Err(ApiError::RateLimited));
+    }
+
+    #[test]
+    fn test_refresh_after_reload_sends_stored_etag() {
+        let (mut cache, _) = mock_cache();
+        let repo = create_test_repo("repo");
+        cache.set_with_etag("owner", "repo", repo.clone(), Some("\"abc\"".to_string()));
         assert_eq!(
-            repo.unwrap().homepage,
-            Some("https://enerby.dev".to_string())
+            cache.refresh_plan("owner", "repo"),
+            RefreshPlan::Cached(Box::new(repo))
         );
+
+        // The reloaded cache reads the real clock, long past the entry's TTL
+        let storage = MemoryStorage::default();
+        cache.save(&storage).unwrap();
+        let reloaded = restore_cache(GitHubCache::load(&storage));
+
+        assert_eq!(
+            reloaded.refresh_plan("owner", "repo"),
+            RefreshPlan::Fetch {
+                etag: Some("\"abc\"".to_string())
+            }
+        );
+        assert_eq!(
+            reloaded.refresh_plan("owner", "unknown"),
+            RefreshPlan::Fetch { etag: None }
+        );
+    }
+
+    // -------------------------
+    // Global Cache Tests
+    // -------------------------
+
+    #[test]
+    fn test_global_cache_get() {
+        // Global cache should be populated with static data (stale once the
+        // snapshot is older than the TTL)
+        let cache = global_cache().read().unwrap();
+        let (repo, _) = cache.get_with_stale("enerBydev", "enerby.dev").unwrap();
+        assert_eq!(repo.homepage, Some("https://enerby.dev".to_string()));
     }
 }
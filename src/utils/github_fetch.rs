@@ -0,0 +1,110 @@
+//! Browser GitHub Client (WASM)
+//!
+//! Refreshes repository data at runtime from the browser. Requests are
+//! conditional (`If-None-Match` with the `ETag` stored in `GitHubCache`), so
+//! unchanged repos cost a 304 instead of a full response.
+//!
+//! # Fallback
+//!
+//! Every failure is returned as an `ApiError`; callers simply keep showing
+//! the embedded snapshot. After a `RateLimited` response no further requests
+//! are sent until the `X-RateLimit-Reset` time.
+
+use std::cell::Cell;
+
+use gloo_net::http::Request;
+
+use super::clock::{Clock, JsClock};
+use super::github_api::{
+    ApiError, FetchOutcome, GitHubApiConfig, GitHubRepoInfo, RateLimit, interpret_repo_response,
+};
+use super::github_cache::{RefreshPlan, global_cache, save_persisted};
+
+/// Back-off used when a rate-limited response has no reset header
+const RATE_LIMIT_FALLBACK_SECONDS: u64 = 15 * 60;
+
+thread_local! {
+    /// Unix time until which requests are skipped after a rate limit
+    static RATE_LIMITED_UNTIL: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Sends a conditional `GET {base_url}/repos/{owner}/{repo}`
+///
+/// `User-Agent` is a forbidden header in browsers, so only `Accept` and
+/// `If-None-Match` are set.
+pub async fn fetch_repo_conditional(
+    config: &GitHubApiConfig,
+    owner: &str,
+    repo: &str,
+    etag: Option<&str>,
+) -> Result<FetchOutcome, ApiError> {
+    let url = format!(
+        "{}/repos/{}/{}",
+        config.base_url.trim_end_matches('/'),
+        owner,
+        repo
+    );
+
+    let mut request = Request::get(&url).header("Accept", "application/vnd.github+json");
+    if let Some(etag) = etag {
+        request = request.header("If-None-Match", etag);
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| ApiError::NetworkError(e.to_string()))?;
+
+    let headers = response.headers();
+    let rate_limit = RateLimit::from_headers(|name| headers.get(name));
+    let status = response.status();
+    let body = if status == 304 {
+        String::new()
+    } else {
+        response
+            .text()
+            .await
+            .map_err(|e| ApiError::NetworkError(e.to_string()))?
+    };
+
+    let result = interpret_repo_response(status, headers.get("etag"), &rate_limit, &body);
+    if result == Err(ApiError::RateLimited) {
+        let until = rate_limit
+            .reset_at
+            .unwrap_or_else(|| JsClock.now_seconds() + RATE_LIMIT_FALLBACK_SECONDS);
+        RATE_LIMITED_UNTIL.with(|cell| cell.set(until));
+    }
+    result
+}
+
+/// Refreshes a repo through the global cache
+///
+/// Skips the network while rate limited or when `GitHubCache::refresh_plan`
+/// serves the cached entry. Successful responses are written back to the
+/// cache and persisted, `ETag` included, so the next page load revalidates.
+pub async fn refresh_repo(owner: &str, repo: &str) -> Result<GitHubRepoInfo, ApiError> {
+    if RATE_LIMITED_UNTIL.with(Cell::get) > JsClock.now_seconds() {
+        return Err(ApiError::RateLimited);
+    }
+
+    let plan = global_cache()
+        .read()
+        .map_err(|_| ApiError::NetworkError("cache lock poisoned".to_string()))?
+        .refresh_plan(owner, repo);
+    let etag = match plan {
+        RefreshPlan::Cached(fresh) => return Ok(*fresh),
+        RefreshPlan::Fetch { etag } => etag,
+    };
+
+    let outcome =
+        fetch_repo_conditional(&GitHubApiConfig::default(), owner, repo, etag.as_deref()).await?;
+
+    let mut cache = global_cache()
+        .write()
+        .map_err(|_| ApiError::NetworkError("cache lock poisoned".to_string()))?;
+    let info = cache
+        .apply_fetch(owner, repo, outcome)
+        .ok_or(ApiError::NotFound)?;
+    let _ = save_persisted(&cache);
+    Ok(info)
+}
@@ -1,8 +1,14 @@
 //! GitHub Statistics Module
 //! Provides Lines of Code statistics for the portfolio
 //!
-//! Current implementation: Static mock with realistic values
-//! Future: GitHub API integration for real-time stats
+//! Counts come from `.cache/loc_stats.json`, generated from local checkouts of
+//! `TRACKED_REPOS` by `cargo run --bin loc-stats` (see `utils::loc_counter`)
+//! and embedded at build time.
+
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::Language;
+use crate::i18n::format::format_integer;
 
 /// Statistics from GitHub repositories
 #[derive(Debug, Clone)]
@@ -11,6 +17,10 @@ pub struct GitHubStats {
     pub total_loc: u64,
     /// List of tracked repository names
     pub repos: Vec<String>,
+    /// Per-repository breakdown
+    pub per_repo: Vec<RepoLocStats>,
+    /// Per-language totals across all repos, largest first
+    pub languages: Vec<LanguageLoc>,
 }
 
 /// Configuration for which repos to track
@@ -21,53 +31,191 @@ pub const TRACKED_REPOS: &[&str] = &[
     "enerBydev/VideoGIniusAI",
 ];
 
-/// Get current GitHub statistics
-///
-/// # Current Implementation
-/// Returns mock data with realistic estimates.
+/// Snapshot file path (relative to project root)
+pub const STATS_FILE_PATH: &str = ".cache/loc_stats.json";
+
+/// Snapshot written by the `loc-stats` binary
+const EMBEDDED_STATS: &str = include_str!("../../.cache/loc_stats.json");
+
+// ============================================================================
+// SNAPSHOT TYPES
+// ============================================================================
+
+/// Code, comment and blank line counts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LineCounts {
+    pub code: u64,
+    pub comments: u64,
+    pub blanks: u64,
+}
+
+impl LineCounts {
+    /// Adds another count into this one
+    pub fn add(&mut self, other: &LineCounts) {
+        self.code += other.code;
+        self.comments += other.comments;
+        self.blanks += other.blanks;
+    }
+
+    /// All lines (code + comments + blanks)
+    pub fn total(&self) -> u64 {
+        self.code + self.comments + self.blanks
+    }
+}
+
+/// Line counts for one language
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LanguageLoc {
+    /// Language name (e.g., "Rust")
+    pub language: String,
+    /// Number of counted files
+    pub files: u64,
+    #[serde(flatten)]
+    pub lines: LineCounts,
+}
+
+/// Line counts for one repository
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepoLocStats {
+    /// Full name (e.g., "enerBydev/enerby-dev")
+    pub repo: String,
+    /// Per-language counts, largest first
+    pub languages: Vec<LanguageLoc>,
+}
+
+impl RepoLocStats {
+    /// Sum over all languages in this repo
+    pub fn totals(&self) -> LineCounts {
+        let mut totals = LineCounts::default();
+        for lang in &self.languages {
+            totals.add(&lang.lines);
+        }
+        totals
+    }
+}
+
+/// Contents of `.cache/loc_stats.json`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LocSnapshot {
+    /// Date the snapshot was generated (YYYY-MM-DD)
+    #[serde(default)]
+    pub generated_at: String,
+    #[serde(default)]
+    pub repos: Vec<RepoLocStats>,
+}
+
+impl LocSnapshot {
+    /// Deserializes a snapshot from JSON
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Per-language totals across all repos, largest code count first
+    pub fn languages(&self) -> Vec<LanguageLoc> {
+        sum_languages(self.repos.iter().flat_map(|r| r.languages.iter()))
+    }
+
+    /// Total code lines across all repos
+    pub fn total_code(&self) -> u64 {
+        self.repos.iter().map(|r| r.totals().code).sum()
+    }
+}
+
+/// Merges language entries by name and sorts them by code lines (desc), then name
+pub fn sum_languages<'a>(entries: impl Iterator<Item = &'a LanguageLoc>) -> Vec<LanguageLoc> {
+    let mut merged: Vec<LanguageLoc> = Vec::new();
+    for entry in entries {
+        match merged.iter_mut().find(|m| m.language == entry.language) {
+            Some(existing) => {
+                existing.files += entry.files;
+                existing.lines.add(&entry.lines);
+            }
+            None => merged.push(entry.clone()),
+        }
+    }
+    merged.sort_by(|a, b| {
+        b.lines
+            .code
+            .cmp(&a.lines.code)
+            .then_with(|| a.language.cmp(&b.language))
+    });
+    merged
+}
+
+// ============================================================================
+// PUBLIC API
+// ============================================================================
+
+/// Get current GitHub statistics from the embedded snapshot
 ///
-/// # Future Implementation
-/// Will use GitHub API to calculate real LOC:
-/// - Fetch repo stats via REST API
-/// - Cache results to avoid rate limiting
-/// - Fallback to static values on error
+/// An unreadable snapshot yields zero counts rather than a panic.
 pub fn get_github_stats() -> GitHubStats {
-    // Mock values based on realistic estimates:
-    // - enerby-dev: ~8,000 LOC (Rust/CSS portfolio)
-    // - oc_diagdoc: ~12,000 LOC (Rust CLI tool)
-    // - Affinity-Legacy-Bridge: ~500 LOC (Shell scripts)
-    // - VideoGIniusAI: ~15,000 LOC (Nuxt 3/Vue)
-    // Total: ~35,500 LOC
+    stats_from_snapshot(&LocSnapshot::from_json(EMBEDDED_STATS).unwrap_or_default())
+}
 
+/// Builds `GitHubStats` from a snapshot
+pub fn stats_from_snapshot(snapshot: &LocSnapshot) -> GitHubStats {
     GitHubStats {
-        total_loc: 35_847, // Realistic mock value based on real repos
-        repos: TRACKED_REPOS.iter().map(|s| s.to_string()).collect(),
+        total_loc: snapshot.total_code(),
+        repos: snapshot.repos.iter().map(|r| r.repo.clone()).collect(),
+        per_repo: snapshot.repos.clone(),
+        languages: snapshot.languages(),
     }
 }
 
-/// Format LOC with thousand separators
-/// e.g., 42847 -> "42,847"
-pub fn format_loc(loc: u64) -> String {
-    let s = loc.to_string();
-    let chars: Vec<char> = s.chars().rev().collect();
-    let formatted: String = chars
-        .chunks(3)
-        .map(|chunk| chunk.iter().collect::<String>())
-        .collect::<Vec<_>>()
-        .join(",");
-    formatted.chars().rev().collect()
+/// Share of code lines per language, in whole percent
+///
+/// Keeps the `limit` largest languages and folds the rest into a trailing
+/// `None` entry ("other"). Languages are expected largest first.
+pub fn language_shares(languages: &[LanguageLoc], limit: usize) -> Vec<(Option<&str>, u8)> {
+    let total: u64 = languages.iter().map(|l| l.lines.code).sum();
+    if total == 0 {
+        return Vec::new();
+    }
+    let percent = |code: u64| ((code as f64 / total as f64) * 100.0).round() as u8;
+
+    let mut shares: Vec<(Option<&str>, u8)> = languages
+        .iter()
+        .take(limit)
+        .map(|l| (Some(l.language.as_str()), percent(l.lines.code)))
+        .collect();
+
+    let rest: u64 = languages.iter().skip(limit).map(|l| l.lines.code).sum();
+    if rest > 0 {
+        shares.push((None, percent(rest)));
+    }
+    shares
+}
+
+/// Format LOC with locale-aware thousand separators
+/// e.g., 42847 -> "42,847" (EN) / "42.847" (ES)
+pub fn format_loc(loc: u64, lang: &Language) -> String {
+    format_integer(loc, lang)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn lang(name: &str, files: u64, code: u64) -> LanguageLoc {
+        LanguageLoc {
+            language: name.to_string(),
+            files,
+            lines: LineCounts {
+                code,
+                comments: 1,
+                blanks: 2,
+            },
+        }
+    }
+
     #[test]
     fn test_format_loc() {
-        assert_eq!(format_loc(1000), "1,000");
-        assert_eq!(format_loc(42847), "42,847");
-        assert_eq!(format_loc(1234567), "1,234,567");
-        assert_eq!(format_loc(100), "100");
+        assert_eq!(format_loc(1000, &Language::EN), "1,000");
+        assert_eq!(format_loc(42847, &Language::EN), "42,847");
+        assert_eq!(format_loc(1234567, &Language::EN), "1,234,567");
+        assert_eq!(format_loc(100, &Language::EN), "100");
+        assert_eq!(format_loc(35847, &Language::ES), "35.847");
     }
 
     #[test]
@@ -75,5 +223,87 @@ mod tests {
         let stats = get_github_stats();
         assert!(stats.total_loc > 0);
         assert!(!stats.repos.is_empty());
+        assert!(!stats.languages.is_empty());
+    }
+
+    #[test]
+    fn test_embedded_snapshot_covers_only_tracked_repos() {
+        let snapshot = LocSnapshot::from_json(EMBEDDED_STATS).expect("valid loc_stats.json");
+        for repo in &snapshot.repos {
+            assert!(
+                TRACKED_REPOS
+                    .iter()
+                    .any(|t| t.eq_ignore_ascii_case(&repo.repo)),
+                "{} is not in TRACKED_REPOS",
+                repo.repo
+            );
+        }
+    }
+
+    #[test]
+    fn test_stats_from_snapshot_breakdowns() {
+        let snapshot = LocSnapshot {
+            generated_at: "2026-01-01".to_string(),
+            repos: vec![
+                RepoLocStats {
+                    repo: "owner/a".to_string(),
+                    languages: vec![lang("Rust", 10, 1000), lang("CSS", 2, 50)],
+                },
+                RepoLocStats {
+                    repo: "owner/b".to_string(),
+                    languages: vec![lang("Rust", 3, 200), lang("Lua", 4, 300)],
+                },
+            ],
+        };
+
+        let stats = stats_from_snapshot(&snapshot);
+
+        assert_eq!(stats.total_loc, 1550);
+        assert_eq!(stats.repos, vec!["owner/a", "owner/b"]);
+        assert_eq!(stats.per_repo[1].totals().code, 500);
+
+        let names: Vec<&str> = stats
+            .languages
+            .iter()
+            .map(|l| l.language.as_str())
+            .collect();
+        assert_eq!(names, vec!["Rust", "Lua", "CSS"]);
+        assert_eq!(stats.languages[0].files, 13);
+        assert_eq!(stats.languages[0].lines.code, 1200);
+        assert_eq!(stats.languages[0].lines.blanks, 4);
+    }
+
+    #[test]
+    fn test_language_shares() {
+        let languages = vec![
+            lang("Rust", 1, 700),
+            lang("CSS", 1, 200),
+            lang("Lua", 1, 60),
+            lang("Shell", 1, 40),
+        ];
+
+        let shares = language_shares(&languages, 2);
+        assert_eq!(
+            shares,
+            vec![(Some("Rust"), 70), (Some("CSS"), 20), (None, 10)]
+        );
+
+        assert_eq!(language_shares(&languages, 10).len(), 4);
+        assert!(language_shares(&[], 5).is_empty());
+    }
+
+    #[test]
+    fn test_snapshot_json_shape() {
+        let json = r#"{
+            "generated_at": "2026-01-01",
+            "repos": [{
+                "repo": "owner/a",
+                "languages": [{ "language": "Rust", "files": 1, "code": 10, "comments": 2, "blanks": 3 }]
+            }]
+        }"#;
+
+        let snapshot = LocSnapshot::from_json(json).unwrap();
+        assert_eq!(snapshot.repos[0].languages[0].lines.total(), 15);
+        assert_eq!(snapshot.total_code(), 10);
     }
 }
@@ -0,0 +1,522 @@
+//! GitHub Sync Module
+//!
+//! Native-only REST client behind the `github-sync` binary. It fetches every
+//! repository the site references and writes the snapshot that
+//! `github_api` embeds into the WASM build (`.cache/github_repos.json`).
+//!
+//! # Sources
+//!
+//! - `github_stats::TRACKED_REPOS`
+//! - `github_url` of every entry in `get_projects()` (any forge, see `forge`)
+//! - GitHub repos of `portfolio_owner()` tagged `projects::PORTFOLIO_TOPIC`,
+//!   found through the search API (`discover_targets`)
+//!
+//! Each repository also gets its per-language byte counts (`/languages`),
+//! shown as the language bar on project cards.
+//!
+//! GitHub requests go to `GitHubApiConfig.base_url`, so tests run the client
+//! against a local mock server instead of api.github.com. Other forges are
+//! queried at the endpoint their `ForgeProvider` reports.
+//!
+//! # Example
+//! ```rust,ignore
+//! use crate::utils::github_sync::{sync_repos, sync_targets, GitHubClient};
+//!
+//! let client = GitHubClient::new(GitHubApiConfig::default());
+//! let report = sync_repos(&client, &sync_targets());
+//! report.cache.save_to_file(Path::new(CACHE_FILE_PATH))?;
+//! ```
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use super::forge::{Forge, RepoRef};
+use super::github_api::{ApiError, GitHubApiConfig, GitHubRepoInfo, RateLimit};
+use super::github_cache::GitHubCache;
+use super::github_stats::TRACKED_REPOS;
+use crate::components::projects::{PORTFOLIO_TOPIC, get_projects};
+use crate::config::SITE;
+
+// ============================================================================
+// HTTP CLIENT
+// ============================================================================
+
+/// Blocking GitHub REST client
+pub struct GitHubClient {
+    config: GitHubApiConfig,
+    agent: ureq::Agent,
+}
+
+impl GitHubClient {
+    /// Creates a client for the given configuration
+    pub fn new(config: GitHubApiConfig) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_millis(config.timeout_ms))
+            .user_agent(&config.user_agent)
+            .build();
+
+        Self { config, agent }
+    }
+
+    /// Returns the configuration this client was built with
+    pub fn config(&self) -> &GitHubApiConfig {
+        &self.config
+    }
+
+    /// Fetches `GET {base_url}/repos/{owner}/{repo}`
+    ///
+    /// # Returns
+    /// * `Ok(GitHubRepoInfo)` on a 2xx response with a valid body
+    /// * `Err(ApiError)` mapped from the HTTP status or transport failure
+    pub fn fetch_repo(&self, owner: &str, repo: &str) -> Result<GitHubRepoInfo, ApiError> {
+        let url = format!(
+            "{}/repos/{}/{}",
+            self.config.base_url.trim_end_matches('/'),
+            owner,
+            repo
+        );
+
+        let body = Self::read_body(self.github_request(&url))?;
+        serde_json::from_str(&body).map_err(|e| ApiError::ParseError(e.to_string()))
+    }
+
+    /// Searches `GET {base_url}/search/repositories` for an owner's non-fork repos with a topic
+    ///
+    /// Reads the first page only (100 results), far more than a portfolio lists.
+    pub fn search_topic(&self, owner: &str, topic: &str) -> Result<Vec<GitHubRepoInfo>, ApiError> {
+        #[derive(Deserialize)]
+        struct SearchResults {
+            items: Vec<GitHubRepoInfo>,
+        }
+
+        let url = format!(
+            "{}/search/repositories?q=user:{}+topic:{}+fork:false&per_page=100",
+            self.config.base_url.trim_end_matches('/'),
+            owner,
+            topic
+        );
+        let body = Self::read_body(self.github_request(&url))?;
+        serde_json::from_str::<SearchResults>(&body)
+            .map(|results| results.items)
+            .map_err(|e| ApiError::ParseError(e.to_string()))
+    }
+
+    /// GET request with the GitHub media type and, if configured, the token
+    fn github_request(&self, url: &str) -> ureq::Request {
+        let request = self
+            .agent
+            .get(url)
+            .set("Accept", "application/vnd.github+json");
+        match &self.config.token {
+            Some(token) => request.set("Authorization", &format!("Bearer {}", token)),
+            None => request,
+        }
+    }
+
+    /// Fetches a repository on any forge
+    ///
+    /// GitHub goes through `fetch_repo` (configured base URL and token); other
+    /// forges are queried anonymously at their provider's endpoint.
+    pub fn fetch_ref(&self, repo: &RepoRef) -> Result<GitHubRepoInfo, ApiError> {
+        if repo.forge == Forge::GitHub {
+            return self.fetch_repo(&repo.owner, &repo.name);
+        }
+
+        let provider = repo.provider();
+        let request = self
+            .agent
+            .get(&provider.api_url(repo))
+            .set("Accept", "application/json");
+        provider.parse_repo(&Self::read_body(request)?)
+    }
+
+    /// Fetches bytes of code per language for a repository on any forge
+    ///
+    /// GitHub uses `GET {base_url}/repos/{owner}/{repo}/languages`; forges
+    /// without a languages endpoint yield an empty map.
+    pub fn fetch_languages(&self, repo: &RepoRef) -> Result<BTreeMap<String, u64>, ApiError> {
+        let request = if repo.forge == Forge::GitHub {
+            let url = format!(
+                "{}/repos/{}/{}/languages",
+                self.config.base_url.trim_end_matches('/'),
+                repo.owner,
+                repo.name
+            );
+            self.github_request(&url)
+        } else {
+            let Some(url) = repo.provider().languages_url(repo) else {
+                return Ok(BTreeMap::new());
+            };
+            self.agent.get(&url).set("Accept", "application/json")
+        };
+
+        let body = Self::read_body(request)?;
+        serde_json::from_str(&body).map_err(|e| ApiError::ParseError(e.to_string()))
+    }
+
+    fn read_body(request: ureq::Request) -> Result<String, ApiError> {
+        request
+            .call()
+            .map_err(map_ureq_error)?
+            .into_string()
+            .map_err(|e| ApiError::NetworkError(e.to_string()))
+    }
+}
+
+fn map_ureq_error(err: ureq::Error) -> ApiError {
+    match err {
+        ureq::Error::Status(code, response) => {
+            let rate_limit = RateLimit::from_headers(|name| response.header(name).map(str::to_string));
+            ApiError::from_status(code, &rate_limit)
+        }
+        ureq::Error::Transport(transport) => ApiError::NetworkError(transport.to_string()),
+    }
+}
+
+// ============================================================================
+// SYNC
+// ============================================================================
+
+/// Outcome of a sync run
+#[derive(Debug, Default)]
+pub struct SyncReport {
+    /// Successfully fetched repos
+    pub cache: GitHubCache,
+    /// Repos that failed, as ("host/owner/repo", error)
+    pub failures: Vec<(String, ApiError)>,
+}
+
+/// Repositories to sync: `TRACKED_REPOS` plus every project's `github_url`
+///
+/// Deduplicated case-insensitively, in first-seen order.
+pub fn sync_targets() -> Vec<RepoRef> {
+    let tracked = TRACKED_REPOS
+        .iter()
+        .filter_map(|full_name| full_name.split_once('/'))
+        .map(|(owner, repo)| RepoRef::github(owner, repo));
+
+    let projects = get_projects()
+        .into_iter()
+        .filter_map(|p| p.github_url)
+        .filter_map(|url| RepoRef::parse(&url));
+
+    add_targets(Vec::new(), tracked.chain(projects))
+}
+
+/// Appends repos not already in `targets` (case-insensitive, same host)
+pub fn add_targets(
+    mut targets: Vec<RepoRef>,
+    repos: impl IntoIterator<Item = RepoRef>,
+) -> Vec<RepoRef> {
+    for repo in repos {
+        let already_listed = targets.iter().any(|t| {
+            t.host == repo.host && t.full_name().eq_ignore_ascii_case(&repo.full_name())
+        });
+        if !already_listed {
+            targets.push(repo);
+        }
+    }
+    targets
+}
+
+/// GitHub account whose repos are searched for `PORTFOLIO_TOPIC` (from `SITE.github`)
+pub fn portfolio_owner() -> &'static str {
+    SITE.github
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or_default()
+}
+
+/// Repos of `owner` tagged `PORTFOLIO_TOPIC`, so new ones are synced without code changes
+pub fn discover_targets(client: &GitHubClient, owner: &str) -> Result<Vec<RepoRef>, ApiError> {
+    Ok(client
+        .search_topic(owner, PORTFOLIO_TOPIC)?
+        .into_iter()
+        .filter_map(|info| {
+            let (owner, name) = info.full_name.split_once('/')?;
+            Some(RepoRef::github(owner, name))
+        })
+        .collect())
+}
+
+/// Fetches every target, collecting successes into a cache and failures into the report
+pub fn sync_repos(client: &GitHubClient, targets: &[RepoRef]) -> SyncReport {
+    let mut report = SyncReport::default();
+
+    for target in targets {
+        let fetched = client.fetch_ref(target).and_then(|mut info| {
+            info.languages = client.fetch_languages(target)?;
+            Ok(info)
+        });
+        match fetched {
+            Ok(info) if target.forge == Forge::GitHub => {
+                // Key by the canonical name GitHub returns (renamed repos redirect)
+                let full_name = info.full_name.clone();
+                let (owner, repo) = full_name
+                    .split_once('/')
+                    .unwrap_or((&target.owner, &target.name));
+                report.cache.set(owner, repo, info);
+            }
+            Ok(info) => {
+                // Other forges are keyed by host so equal names never collide
+                let namespace = format!("{}/{}", target.host, target.owner);
+                report.cache.set(&namespace, &target.name, info);
+            }
+            Err(err) => report.failures.push((target.to_string(), err)),
+        }
+    }
+    report
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock_http::{MockResponse, MockServer, closed_port_url};
+
+    const REPO_JSON: &str = r#"{
+        "name": "repo",
+        "full_name": "owner/repo",
+        "description": "Mock repo",
+        "homepage": "https://example.com",
+        "stargazers_count": 7,
+        "html_url": "https://github.com/owner/repo"
+    }"#;
+
+    const LANGUAGES_JSON: &str = r#"{"Rust": 9000, "CSS": 1000}"#;
+
+    const SEARCH_JSON: &str = r#"{
+        "total_count": 2,
+        "items": [
+            {"name": "repo", "full_name": "owner/repo", "topics": ["portfolio"], "html_url": "https://github.com/owner/repo"},
+            {"name": "new-app", "full_name": "owner/new-app", "topics": ["portfolio"], "html_url": "https://github.com/owner/new-app"}
+        ]
+    }"#;
+
+    /// Canned GitHub responses by path
+    const ROUTES: &[(&str, MockResponse)] = &[
+        (
+            "/search/repositories?q=user:owner+topic:portfolio+fork:false&per_page=100",
+            MockResponse::new("200 OK", SEARCH_JSON),
+        ),
+        ("/repos/owner/repo", MockResponse::new("200 OK", REPO_JSON)),
+        (
+            "/repos/owner/repo/languages",
+            MockResponse::new("200 OK", LANGUAGES_JSON),
+        ),
+        (
+            "/repos/owner/nolang",
+            MockResponse::new("200 OK", REPO_JSON),
+        ),
+        (
+            "/repos/owner/limited",
+            MockResponse {
+                status: "403 Forbidden",
+                headers: "X-RateLimit-Remaining: 0\r\n",
+                body: "{}",
+            },
+        ),
+        (
+            "/repos/owner/private",
+            MockResponse {
+                status: "403 Forbidden",
+                headers: "X-RateLimit-Remaining: 42\r\n",
+                body: "{}",
+            },
+        ),
+        (
+            "/repos/owner/broken",
+            MockResponse::new("502 Bad Gateway", ""),
+        ),
+        (
+            "/repos/owner/garbage",
+            MockResponse::new("200 OK", "not json"),
+        ),
+    ];
+
+    /// Serves `ROUTES` on an ephemeral port, returns its base URL
+    fn spawn_mock_server() -> String {
+        MockServer::spawn(ROUTES).base_url
+    }
+
+    fn mock_client(base_url: String) -> GitHubClient {
+        GitHubClient::new(GitHubApiConfig {
+            base_url,
+            ..GitHubApiConfig::default()
+        })
+    }
+
+    // -------------------------
+    // Mock Server Tests
+    // -------------------------
+
+    #[test]
+    fn test_fetch_repo_success() {
+        let client = mock_client(spawn_mock_server());
+        let repo = client.fetch_repo("owner", "repo").expect("fetch from mock");
+
+        assert_eq!(repo.full_name, "owner/repo");
+        assert_eq!(repo.stargazers_count, 7);
+        assert_eq!(
+            repo.extract_homepage(),
+            Some("https://example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_fetch_repo_error_mapping() {
+        let client = mock_client(spawn_mock_server() + "/");
+
+        assert_eq!(
+            client.fetch_repo("owner", "missing"),
+            Err(ApiError::NotFound)
+        );
+        assert_eq!(
+            client.fetch_repo("owner", "limited"),
+            Err(ApiError::RateLimited)
+        );
+        assert_eq!(
+            client.fetch_repo("owner", "private"),
+            Err(ApiError::Forbidden)
+        );
+        assert_eq!(
+            client.fetch_repo("owner", "broken"),
+            Err(ApiError::ServerError(502))
+        );
+        assert!(matches!(
+            client.fetch_repo("owner", "garbage"),
+            Err(ApiError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_fetch_repo_network_error() {
+        let client = mock_client(closed_port_url());
+
+        assert!(matches!(
+            client.fetch_repo("owner", "repo"),
+            Err(ApiError::NetworkError(_))
+        ));
+    }
+
+    #[test]
+    fn test_sync_repos_collects_failures() {
+        let client = mock_client(spawn_mock_server());
+        let targets = vec![
+            RepoRef::github("owner", "repo"),
+            RepoRef::github("owner", "missing"),
+        ];
+
+        let report = sync_repos(&client, &targets);
+
+        let repo = report.cache.get("owner", "repo").expect("synced repo");
+        assert_eq!(repo.languages.get("Rust"), Some(&9000));
+        assert_eq!(
+            report.failures,
+            vec![("github.com/owner/missing".to_string(), ApiError::NotFound)]
+        );
+    }
+
+    #[test]
+    fn test_fetch_languages() {
+        let client = mock_client(spawn_mock_server());
+
+        let languages = client
+            .fetch_languages(&RepoRef::github("owner", "repo"))
+            .expect("languages from mock");
+        assert_eq!(
+            languages.into_iter().collect::<Vec<_>>(),
+            vec![("CSS".to_string(), 1000), ("Rust".to_string(), 9000)]
+        );
+
+        // GitLab has no bytes-per-language endpoint: nothing to request
+        let gitlab = RepoRef::parse("https://gitlab.com/owner/repo").unwrap();
+        assert_eq!(client.fetch_languages(&gitlab), Ok(BTreeMap::new()));
+    }
+
+    #[test]
+    fn test_sync_repos_fails_target_without_languages() {
+        // Metadata alone would overwrite a snapshot entry that still has languages
+        let client = mock_client(spawn_mock_server());
+        let report = sync_repos(&client, &[RepoRef::github("owner", "nolang")]);
+
+        assert!(report.cache.get("owner", "nolang").is_none());
+        assert_eq!(
+            report.failures,
+            vec![("github.com/owner/nolang".to_string(), ApiError::NotFound)]
+        );
+    }
+
+    #[test]
+    fn test_discover_targets_adds_tagged_repos() {
+        let client = mock_client(spawn_mock_server());
+
+        let discovered = discover_targets(&client, "owner").unwrap();
+        assert_eq!(
+            discovered,
+            vec![
+                RepoRef::github("owner", "repo"),
+                RepoRef::github("owner", "new-app")
+            ]
+        );
+
+        // Already-listed repos are not added twice
+        let targets = add_targets(vec![RepoRef::github("Owner", "Repo")], discovered);
+        assert_eq!(
+            targets,
+            vec![
+                RepoRef::github("Owner", "Repo"),
+                RepoRef::github("owner", "new-app")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_discover_targets_propagates_errors() {
+        let client = mock_client(spawn_mock_server());
+        assert_eq!(
+            discover_targets(&client, "nobody"),
+            Err(ApiError::NotFound)
+        );
+    }
+
+    // -------------------------
+    // Targets Tests
+    // -------------------------
+
+    #[test]
+    fn test_portfolio_owner_from_site_config() {
+        assert_eq!(portfolio_owner(), "enerBydev");
+    }
+
+    #[test]
+    fn test_sync_targets_covers_tracked_repos_and_projects() {
+        let targets = sync_targets();
+        let has = |repo: &RepoRef| {
+            targets.iter().any(|t| {
+                t.host == repo.host && t.full_name().eq_ignore_ascii_case(&repo.full_name())
+            })
+        };
+
+        for tracked in TRACKED_REPOS {
+            let (owner, repo) = tracked.split_once('/').unwrap();
+            assert!(has(&RepoRef::github(owner, repo)), "missing tracked repo {}", tracked);
+        }
+        for url in get_projects().iter().filter_map(|p| p.github_url.as_deref()) {
+            let repo = RepoRef::parse(url).unwrap();
+            assert!(has(&repo), "missing project repo {}", url);
+        }
+
+        // No duplicates
+        for (i, t) in targets.iter().enumerate() {
+            assert!(!targets[i + 1..].iter().any(|t2| t2.host == t.host
+                && t2.full_name().eq_ignore_ascii_case(&t.full_name())));
+        }
+    }
+}
@@ -0,0 +1,404 @@
+//! Image Pipeline Module
+//!
+//! Turns each project's resolved `ImageSource` into the responsive variants
+//! described in `utils::responsive_images`. Used by `cargo run --bin image-pipeline`.
+//!
+//! Two steps:
+//! 1. `fetch` (online): downloads remote sources into `.cache/images/<project-id>.<ext>`
+//! 2. build (offline): decodes cached or committed sources, writes
+//!    `assets/projects/<project-id>-<width>.<avif|webp>`, and returns manifest
+//!    entries with size and blurhash placeholder
+//!
+//! Both formats are lossy: WebP through libwebp (`image` only writes lossless
+//! WebP, which comes out larger than the JPEG/PNG sources), AVIF through rav1e.
+
+use std::fs;
+use std::io::{BufWriter, Cursor, Read, Write};
+use std::path::{Path, PathBuf};
+
+use base64::Engine;
+use image::codecs::avif::AvifEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageFormat, RgbaImage};
+
+use super::project_images::ImageSource;
+use super::responsive_images::{IMAGE_WIDTHS, ProjectImage, VariantFormat, variant_file_name};
+
+/// Downloaded source images (relative to project root)
+pub const SOURCE_CACHE_DIR: &str = ".cache/images";
+
+/// Width of the decoded placeholder image in pixels
+pub const PLACEHOLDER_WIDTH: u32 = 32;
+
+/// Blurhash components (x, y); 4×3 suits landscape cards
+const BLURHASH_COMPONENTS: (u32, u32) = (4, 3);
+
+/// rav1e speed (0 slowest – 10 fastest) and quality (1–100)
+const AVIF_SPEED: u8 = 6;
+const AVIF_QUALITY: u8 = 60;
+
+/// libwebp quality (0–100)
+const WEBP_QUALITY: f32 = 75.0;
+
+/// Largest image the fetch step accepts
+const MAX_SOURCE_BYTES: u64 = 20 * 1024 * 1024;
+
+// ============================================================================
+// SOURCES
+// ============================================================================
+
+/// Where a project's source image lives
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SourceLocation {
+    /// Remote URL, read from the cache by the offline step
+    Remote(String),
+    /// File in the repository
+    Local(PathBuf),
+}
+
+/// Locates the source for a resolved image, or `None` for emoji fallbacks
+///
+/// Relative override paths (e.g. "/assets/foo.png") are resolved from `root`.
+pub fn source_location(
+    project_id: &str,
+    source: &ImageSource,
+    root: &Path,
+) -> Option<SourceLocation> {
+    match source {
+        ImageSource::LocalScreenshot(_) => Some(SourceLocation::Local(
+            root.join("assets/screenshots")
+                .join(format!("{}.webp", project_id)),
+        )),
+        ImageSource::Fallback(_) => None,
+        _ => {
+            let url = source.url()?;
+            if url.starts_with("http://") || url.starts_with("https://") {
+                Some(SourceLocation::Remote(url.to_string()))
+            } else {
+                Some(SourceLocation::Local(
+                    root.join(url.trim_start_matches('/')),
+                ))
+            }
+        }
+    }
+}
+
+/// Cached download for a project, whatever its extension
+pub fn find_cached_source(cache_dir: &Path, project_id: &str) -> Option<PathBuf> {
+    ["png", "jpg", "webp"]
+        .iter()
+        .map(|ext| cache_dir.join(format!("{}.{}", project_id, ext)))
+        .find(|path| path.is_file())
+}
+
+/// Downloads `url` into `cache_dir/<project-id>.<ext>`, replacing older copies
+pub fn fetch_source(
+    agent: &ureq::Agent,
+    url: &str,
+    cache_dir: &Path,
+    project_id: &str,
+) -> Result<PathBuf, String> {
+    let response = agent.get(url).call().map_err(|e| e.to_string())?;
+    let mut bytes = Vec::new();
+    response
+        .into_reader()
+        .take(MAX_SOURCE_BYTES)
+        .read_to_end(&mut bytes)
+        .map_err(|e| e.to_string())?;
+    save_source(&bytes, cache_dir, project_id)
+}
+
+/// Stores downloaded bytes under the extension of their detected format
+pub fn save_source(bytes: &[u8], cache_dir: &Path, project_id: &str) -> Result<PathBuf, String> {
+    let ext = match image::guess_format(bytes).map_err(|e| e.to_string())? {
+        ImageFormat::Png => "png",
+        ImageFormat::Jpeg => "jpg",
+        ImageFormat::WebP => "webp",
+        other => return Err(format!("unsupported image format {:?}", other)),
+    };
+
+    fs::create_dir_all(cache_dir).map_err(|e| e.to_string())?;
+    while let Some(old) = find_cached_source(cache_dir, project_id) {
+        fs::remove_file(&old).map_err(|e| e.to_string())?;
+    }
+    let path = cache_dir.join(format!("{}.{}", project_id, ext));
+    fs::write(&path, bytes).map_err(|e| e.to_string())?;
+    Ok(path)
+}
+
+// ============================================================================
+// PROCESSING
+// ============================================================================
+
+/// Widths to generate: every `IMAGE_WIDTHS` entry up to the source width,
+/// or just the source width for images smaller than all of them
+pub fn target_widths(source_width: u32) -> Vec<u32> {
+    let widths: Vec<u32> = IMAGE_WIDTHS
+        .into_iter()
+        .filter(|w| *w <= source_width)
+        .collect();
+    if widths.is_empty() {
+        vec![source_width]
+    } else {
+        widths
+    }
+}
+
+/// Writes all variants of `image` into `out_dir` and returns its manifest entry
+pub fn process_image(
+    project_id: &str,
+    source: &str,
+    image: &DynamicImage,
+    out_dir: &Path,
+) -> Result<ProjectImage, String> {
+    if image.width() == 0 || image.height() == 0 {
+        return Err("empty image".to_string());
+    }
+    fs::create_dir_all(out_dir).map_err(|e| e.to_string())?;
+
+    let widths = target_widths(image.width());
+    let mut size = (0, 0);
+    for &width in &widths {
+        let resized = if width == image.width() {
+            DynamicImage::ImageRgba8(image.to_rgba8())
+        } else {
+            DynamicImage::ImageRgba8(
+                image
+                    .resize(width, u32::MAX, FilterType::Lanczos3)
+                    .to_rgba8(),
+            )
+        };
+        size = (resized.width(), resized.height());
+
+        for format in VariantFormat::ALL {
+            let path = out_dir.join(variant_file_name(project_id, width, format));
+            let mut file = BufWriter::new(fs::File::create(&path).map_err(|e| e.to_string())?);
+            let result = match format {
+                VariantFormat::Webp => encode_webp(&resized)
+                    .and_then(|bytes| file.write_all(&bytes).map_err(|e| e.to_string())),
+                VariantFormat::Avif => resized
+                    .write_with_encoder(AvifEncoder::new_with_speed_quality(
+                        file,
+                        AVIF_SPEED,
+                        AVIF_QUALITY,
+                    ))
+                    .map_err(|e| e.to_string()),
+            };
+            result.map_err(|e| format!("{}: {}", path.display(), e))?;
+        }
+    }
+
+    let blurhash = encode_blurhash(image)?;
+    Ok(ProjectImage {
+        source: source.to_string(),
+        width: size.0,
+        height: size.1,
+        placeholder: placeholder_data_uri(&blurhash, image.width(), image.height())?,
+        widths,
+        blurhash,
+    })
+}
+
+/// Lossy WebP of an RGBA image
+fn encode_webp(image: &DynamicImage) -> Result<Vec<u8>, String> {
+    let rgba = image.to_rgba8();
+    webp::Encoder::from_rgba(rgba.as_raw(), rgba.width(), rgba.height())
+        .encode_simple(false, WEBP_QUALITY)
+        .map(|memory| memory.to_vec())
+        .map_err(|e| format!("WebP encoding failed: {:?}", e))
+}
+
+/// Deletes every variant file in `out_dir`, returning how many were removed
+///
+/// Run before a full rebuild so projects without a source lose stale variants.
+pub fn clear_variants(out_dir: &Path) -> Result<usize, String> {
+    let Ok(entries) = fs::read_dir(out_dir) else {
+        return Ok(0);
+    };
+    let mut removed = 0;
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if is_variant_file_name(&name) {
+            fs::remove_file(entry.path()).map_err(|e| e.to_string())?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+/// Matches `<project-id>-<width>.<avif|webp>`
+fn is_variant_file_name(name: &str) -> bool {
+    let Some((stem, ext)) = name.rsplit_once('.') else {
+        return false;
+    };
+    let Some((_, width)) = stem.rsplit_once('-') else {
+        return false;
+    };
+    VariantFormat::from_extension(ext).is_some() && width.parse::<u32>().is_ok()
+}
+
+/// Blurhash computed on a small thumbnail (the hash only keeps low frequencies)
+fn encode_blurhash(image: &DynamicImage) -> Result<String, String> {
+    let thumb = image.thumbnail(64, 64).to_rgba8();
+    let (x, y) = BLURHASH_COMPONENTS;
+    blurhash::encode(x, y, thumb.width(), thumb.height(), thumb.as_raw()).map_err(|e| e.to_string())
+}
+
+/// Decodes the blurhash at `PLACEHOLDER_WIDTH` with the source aspect ratio
+/// and returns it as a PNG data URI
+fn placeholder_data_uri(blurhash: &str, width: u32, height: u32) -> Result<String, String> {
+    let ph_height =
+        ((PLACEHOLDER_WIDTH as f64 * height as f64 / width as f64).round() as u32).max(1);
+    let pixels =
+        blurhash::decode(blurhash, PLACEHOLDER_WIDTH, ph_height, 1.0).map_err(|e| e.to_string())?;
+    let image = RgbaImage::from_raw(PLACEHOLDER_WIDTH, ph_height, pixels)
+        .ok_or("blurhash decoded to the wrong size")?;
+
+    let mut png = Cursor::new(Vec::new());
+    image
+        .write_to(&mut png, ImageFormat::Png)
+        .map_err(|e| e.to_string())?;
+    Ok(format!(
+        "data:image/png;base64,{}",
+        base64::engine::general_purpose::STANDARD.encode(png.into_inner())
+    ))
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::forge::Forge;
+
+    /// Fresh scratch directory under the system temp dir
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("enerby_image_pipeline_{}", name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// Horizontal gradient, so the blurhash is not flat
+    fn gradient(width: u32, height: u32) -> DynamicImage {
+        DynamicImage::ImageRgba8(RgbaImage::from_fn(width, height, |x, _| {
+            let v = (x * 255 / width.max(1)) as u8;
+            image::Rgba([v, 128, 255 - v, 255])
+        }))
+    }
+
+    #[test]
+    fn test_target_widths() {
+        assert_eq!(target_widths(2000), vec![400, 800, 1200]);
+        assert_eq!(target_widths(1200), vec![400, 800, 1200]);
+        assert_eq!(target_widths(900), vec![400, 800]);
+        assert_eq!(target_widths(300), vec![300]);
+    }
+
+    #[test]
+    fn test_source_location() {
+        let root = Path::new("/repo");
+        let remote = ImageSource::RepoPreview {
+            forge: Forge::GitHub,
+            url: "https://opengraph.githubassets.com/1/o/r".to_string(),
+        };
+        assert_eq!(
+            source_location("p", &remote, root),
+            Some(SourceLocation::Remote(
+                "https://opengraph.githubassets.com/1/o/r".to_string()
+            ))
+        );
+
+        let local = ImageSource::LocalScreenshot("/assets/p-dxh1.webp".to_string());
+        assert_eq!(
+            source_location("p", &local, root),
+            Some(SourceLocation::Local(PathBuf::from(
+                "/repo/assets/screenshots/p.webp"
+            )))
+        );
+
+        let relative = ImageSource::Override("/assets/images/p.png".to_string());
+        assert_eq!(
+            source_location("p", &relative, root),
+            Some(SourceLocation::Local(PathBuf::from(
+                "/repo/assets/images/p.png"
+            )))
+        );
+
+        assert_eq!(
+            source_location("p", &ImageSource::Fallback("🧪".to_string()), root),
+            None
+        );
+    }
+
+    #[test]
+    fn test_save_source_detects_format_and_replaces_old() {
+        let dir = temp_dir("save");
+        fs::write(dir.join("p.jpg"), b"old").unwrap();
+
+        let mut png = Cursor::new(Vec::new());
+        gradient(4, 4).write_to(&mut png, ImageFormat::Png).unwrap();
+        let path = save_source(png.get_ref(), &dir, "p").unwrap();
+
+        assert_eq!(path, dir.join("p.png"));
+        assert!(!dir.join("p.jpg").exists());
+        assert_eq!(find_cached_source(&dir, "p"), Some(path));
+        assert!(save_source(b"not an image", &dir, "p").is_err());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_process_image_writes_variants() {
+        let dir = temp_dir("process");
+        let entry =
+            process_image("p", "https://example.com/p.png", &gradient(900, 450), &dir).unwrap();
+
+        assert_eq!(entry.widths, vec![400, 800]);
+        assert_eq!((entry.width, entry.height), (800, 400));
+        assert!(entry.placeholder.starts_with("data:image/png;base64,"));
+        assert!(blurhash::decode(&entry.blurhash, 4, 4, 1.0).is_ok());
+
+        for width in [400, 800] {
+            let webp = image::open(dir.join(format!("p-{}.webp", width))).unwrap();
+            assert_eq!(webp.width(), width);
+            assert_eq!(webp.height(), width / 2);
+            assert!(dir.join(format!("p-{}.avif", width)).is_file());
+        }
+
+        // Unrelated files survive a clear
+        fs::write(dir.join("README.md"), "keep").unwrap();
+        assert_eq!(clear_variants(&dir).unwrap(), 4);
+        assert!(dir.join("README.md").exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_webp_is_lossy_and_smaller_than_png_source() {
+        // Screenshot-like noise, which lossless WebP barely compresses
+        let noisy = DynamicImage::ImageRgba8(RgbaImage::from_fn(400, 200, |x, y| {
+            let v = (x.wrapping_mul(2_654_435_761) ^ y.wrapping_mul(40_503)) as u8;
+            image::Rgba([v, v.wrapping_add(x as u8), 255 - v, 255])
+        }));
+        let mut png = Cursor::new(Vec::new());
+        noisy.write_to(&mut png, ImageFormat::Png).unwrap();
+
+        let webp = encode_webp(&noisy).unwrap();
+        assert!(webp.len() < png.into_inner().len());
+
+        let decoded = image::load_from_memory(&webp).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (400, 200));
+    }
+
+    #[test]
+    fn test_is_variant_file_name() {
+        assert!(is_variant_file_name("enerby-dev-400.webp"));
+        assert!(is_variant_file_name("p-1200.avif"));
+        assert!(!is_variant_file_name("enerby-dev.webp"));
+        assert!(!is_variant_file_name("p-400.png"));
+        assert!(!is_variant_file_name(".gitkeep"));
+    }
+}
@@ -0,0 +1,527 @@
+//! Lines of Code Counter
+//!
+//! Native-only counter behind the `loc-stats` binary. Walks local checkouts of
+//! `TRACKED_REPOS` (paths come from `loc_stats.toml`), classifies each line as
+//! code, comment or blank per language, and produces the `LocSnapshot` that
+//! `github_stats` embeds.
+//!
+//! Vendored and build directories (`target/`, `node_modules/`, `vendor/`, ...)
+//! are skipped, as are generated or minified files.
+//!
+//! # Config
+//! ```toml
+//! [[repo]]
+//! name = "enerBydev/enerby-dev"
+//! path = "."
+//! exclude = ["assets/tailwind.css"]
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use super::github_stats::{LanguageLoc, LineCounts, RepoLocStats, sum_languages};
+
+// ============================================================================
+// LANGUAGE TABLE
+// ============================================================================
+
+/// Comment syntax for a counted language
+#[derive(Debug)]
+pub struct LanguageSyntax {
+    pub name: &'static str,
+    pub extensions: &'static [&'static str],
+    pub line_comments: &'static [&'static str],
+    /// (open, close) delimiter pairs
+    pub block_comments: &'static [(&'static str, &'static str)],
+}
+
+const C_BLOCK: &[(&str, &str)] = &[("/*", "*/")];
+const HTML_BLOCK: &[(&str, &str)] = &[("<!--", "-->")];
+
+/// Languages counted by the generator; anything else is ignored
+pub const LANGUAGES: &[LanguageSyntax] = &[
+    LanguageSyntax {
+        name: "Rust",
+        extensions: &["rs"],
+        line_comments: &["//"],
+        block_comments: C_BLOCK,
+    },
+    LanguageSyntax {
+        name: "TypeScript",
+        extensions: &["ts", "tsx", "mts", "cts"],
+        line_comments: &["//"],
+        block_comments: C_BLOCK,
+    },
+    LanguageSyntax {
+        name: "JavaScript",
+        extensions: &["js", "jsx", "mjs", "cjs"],
+        line_comments: &["//"],
+        block_comments: C_BLOCK,
+    },
+    LanguageSyntax {
+        name: "Vue",
+        extensions: &["vue"],
+        line_comments: &["//"],
+        block_comments: &[("<!--", "-->"), ("/*", "*/")],
+    },
+    LanguageSyntax {
+        name: "CSS",
+        extensions: &["css"],
+        line_comments: &[],
+        block_comments: C_BLOCK,
+    },
+    LanguageSyntax {
+        name: "SCSS",
+        extensions: &["scss"],
+        line_comments: &["//"],
+        block_comments: C_BLOCK,
+    },
+    LanguageSyntax {
+        name: "HTML",
+        extensions: &["html", "htm"],
+        line_comments: &[],
+        block_comments: HTML_BLOCK,
+    },
+    LanguageSyntax {
+        name: "Lua",
+        extensions: &["lua"],
+        line_comments: &["--"],
+        block_comments: &[("--[[", "]]")],
+    },
+    LanguageSyntax {
+        name: "Shell",
+        extensions: &["sh", "bash", "zsh"],
+        line_comments: &["#"],
+        block_comments: &[],
+    },
+    LanguageSyntax {
+        name: "Python",
+        extensions: &["py"],
+        line_comments: &["#"],
+        block_comments: &[],
+    },
+    LanguageSyntax {
+        name: "Go",
+        extensions: &["go"],
+        line_comments: &["//"],
+        block_comments: C_BLOCK,
+    },
+    LanguageSyntax {
+        name: "C",
+        extensions: &["c", "h"],
+        line_comments: &["//"],
+        block_comments: C_BLOCK,
+    },
+    LanguageSyntax {
+        name: "C++",
+        extensions: &["cpp", "cc", "cxx", "hpp"],
+        line_comments: &["//"],
+        block_comments: C_BLOCK,
+    },
+];
+
+/// Directories never descended into (vendored code and build output)
+pub const SKIPPED_DIRS: &[&str] = &[
+    ".git",
+    ".cache",
+    ".next",
+    ".nuxt",
+    ".output",
+    "build",
+    "coverage",
+    "dist",
+    "node_modules",
+    "target",
+    "vendor",
+];
+
+/// Markers in the first lines of a file that flag it as generated
+const GENERATED_MARKERS: &[&str] = &[
+    "@generated",
+    "DO NOT EDIT",
+    "auto-generated",
+    "autogenerated",
+];
+
+/// Lines longer than this mark a file as minified
+const MINIFIED_LINE_LEN: usize = 1000;
+
+/// Looks up the language for a file by extension
+pub fn detect_language(path: &Path) -> Option<&'static LanguageSyntax> {
+    let ext = path.extension()?.to_str()?.to_lowercase();
+    LANGUAGES
+        .iter()
+        .find(|l| l.extensions.contains(&ext.as_str()))
+}
+
+/// Checks whether a file looks generated or minified
+pub fn is_generated(path: &Path, source: &str) -> bool {
+    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    if file_name.contains(".min.") {
+        return true;
+    }
+
+    source
+        .lines()
+        .take(5)
+        .any(|line| GENERATED_MARKERS.iter().any(|m| line.contains(m)))
+        || source.lines().any(|line| line.len() > MINIFIED_LINE_LEN)
+}
+
+// ============================================================================
+// LINE CLASSIFICATION
+// ============================================================================
+
+/// Counts code, comment and blank lines in a source file
+///
+/// A line with any code counts as code; a line with only comments counts as a
+/// comment. Comment openers inside double-quoted strings are ignored.
+pub fn count_lines(source: &str, syntax: &LanguageSyntax) -> LineCounts {
+    let mut counts = LineCounts::default();
+    let mut open_block: Option<&'static str> = None;
+
+    for line in source.lines() {
+        if line.trim().is_empty() {
+            counts.blanks += 1;
+            continue;
+        }
+
+        let (has_code, has_comment) = classify_line(line, syntax, &mut open_block);
+        if has_code {
+            counts.code += 1;
+        } else if has_comment {
+            counts.comments += 1;
+        }
+    }
+    counts
+}
+
+/// Returns (has_code, has_comment), updating the open block comment state
+fn classify_line(
+    line: &str,
+    syntax: &LanguageSyntax,
+    open_block: &mut Option<&'static str>,
+) -> (bool, bool) {
+    let mut rest = line;
+    let mut has_code = false;
+    let mut has_comment = false;
+
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+
+        if let Some(close) = *open_block {
+            has_comment = true;
+            match rest.find(close) {
+                Some(idx) => {
+                    rest = &rest[idx + close.len()..];
+                    *open_block = None;
+                }
+                None => break,
+            }
+            continue;
+        }
+
+        // Block openers first: Lua's `--[[` also starts with `--`
+        if let Some((open, close)) = syntax
+            .block_comments
+            .iter()
+            .find(|(o, _)| rest.starts_with(o))
+        {
+            has_comment = true;
+            rest = &rest[open.len()..];
+            *open_block = Some(close);
+            continue;
+        }
+
+        if syntax.line_comments.iter().any(|p| rest.starts_with(p)) {
+            has_comment = true;
+            break;
+        }
+
+        has_code = true;
+        match find_comment_start(rest, syntax) {
+            Some(idx) if idx > 0 => rest = &rest[idx..],
+            _ => break,
+        }
+    }
+    (has_code, has_comment)
+}
+
+/// Byte offset of the first comment opener outside a string literal
+fn find_comment_start(code: &str, syntax: &LanguageSyntax) -> Option<usize> {
+    let openers = syntax
+        .line_comments
+        .iter()
+        .chain(syntax.block_comments.iter().map(|(open, _)| open));
+
+    let mut in_string = false;
+    let mut escaped = false;
+    for (idx, c) in code.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        if c == '"' {
+            in_string = true;
+        } else if openers.clone().any(|o| code[idx..].starts_with(o)) {
+            return Some(idx);
+        }
+    }
+    None
+}
+
+// ============================================================================
+// REPOSITORY WALK
+// ============================================================================
+
+/// Counts every recognized source file under `root`
+///
+/// `exclude` holds paths relative to `root` (files or directories) to skip.
+pub fn count_repo(repo: &str, root: &Path, exclude: &[String]) -> std::io::Result<RepoLocStats> {
+    let excluded: Vec<PathBuf> = exclude.iter().map(|e| root.join(e)).collect();
+    let mut per_file = Vec::new();
+    walk(root, &excluded, &mut per_file)?;
+
+    Ok(RepoLocStats {
+        repo: repo.to_string(),
+        languages: sum_languages(per_file.iter()),
+    })
+}
+
+fn walk(dir: &Path, excluded: &[PathBuf], out: &mut Vec<LanguageLoc>) -> std::io::Result<()> {
+    let mut entries: Vec<_> = fs::read_dir(dir)?.filter_map(Result::ok).collect();
+    entries.sort_by_key(|e| e.file_name());
+
+    for entry in entries {
+        let path = entry.path();
+        if excluded.iter().any(|e| path.starts_with(e)) {
+            continue;
+        }
+
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            let name = entry.file_name();
+            if !SKIPPED_DIRS.iter().any(|s| name == *s) {
+                walk(&path, excluded, out)?;
+            }
+        } else if file_type.is_file() {
+            let Some(syntax) = detect_language(&path) else {
+                continue;
+            };
+            // Non-UTF-8 files are not source we want to count
+            let Ok(source) = fs::read_to_string(&path) else {
+                continue;
+            };
+            if is_generated(&path, &source) {
+                continue;
+            }
+            out.push(LanguageLoc {
+                language: syntax.name.to_string(),
+                files: 1,
+                lines: count_lines(&source, syntax),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ============================================================================
+// CONFIG
+// ============================================================================
+
+/// Default config file path (relative to project root)
+pub const CONFIG_FILE_PATH: &str = "loc_stats.toml";
+
+/// Contents of `loc_stats.toml`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LocConfig {
+    #[serde(default, rename = "repo")]
+    pub repos: Vec<RepoCheckout>,
+}
+
+/// A local checkout of a tracked repository
+#[derive(Debug, Clone, Deserialize)]
+pub struct RepoCheckout {
+    /// Full name as listed in `TRACKED_REPOS`
+    pub name: String,
+    /// Checkout directory, relative to the config file
+    pub path: PathBuf,
+    /// Paths inside the checkout to skip
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+impl LocConfig {
+    /// Parses a config file, resolving checkout paths relative to its directory
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let mut config: LocConfig =
+            toml::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        let base = path.parent().unwrap_or(Path::new(""));
+        for repo in &mut config.repos {
+            repo.path = base.join(&repo.path);
+        }
+        Ok(config)
+    }
+
+    /// Finds the checkout configured for a repo (case-insensitive)
+    pub fn checkout(&self, repo: &str) -> Option<&RepoCheckout> {
+        self.repos
+            .iter()
+            .find(|r| r.name.eq_ignore_ascii_case(repo))
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn syntax(name: &str) -> &'static LanguageSyntax {
+        LANGUAGES.iter().find(|l| l.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_detect_language() {
+        assert_eq!(
+            detect_language(Path::new("src/main.rs")).unwrap().name,
+            "Rust"
+        );
+        assert_eq!(detect_language(Path::new("App.VUE")).unwrap().name, "Vue");
+        assert_eq!(detect_language(Path::new("init.lua")).unwrap().name, "Lua");
+        assert!(detect_language(Path::new("README.md")).is_none());
+        assert!(detect_language(Path::new("Makefile")).is_none());
+    }
+
+    #[test]
+    fn test_count_lines_rust() {
+        let source = r#"//! Module docs
+
+/* block
+   comment */
+fn main() {
+    let glob = "src/**/*.rs"; // trailing comment
+    /* inline */ let x = 1;
+}
+"#;
+        let counts = count_lines(source, syntax("Rust"));
+
+        assert_eq!(counts.code, 4);
+        assert_eq!(counts.comments, 3);
+        assert_eq!(counts.blanks, 1);
+    }
+
+    #[test]
+    fn test_count_lines_lua_block_before_line_comment() {
+        let source = "--[[\nlong comment\n]]\nlocal x = 1 -- note\n-- single\n";
+        let counts = count_lines(source, syntax("Lua"));
+
+        assert_eq!(counts.code, 1);
+        assert_eq!(counts.comments, 4);
+    }
+
+    #[test]
+    fn test_count_lines_html() {
+        let source = "<!-- header -->\n<div>\n\n</div> <!-- end\n-->\n";
+        let counts = count_lines(source, syntax("HTML"));
+
+        assert_eq!(counts.code, 2);
+        assert_eq!(counts.comments, 2);
+        assert_eq!(counts.blanks, 1);
+    }
+
+    #[test]
+    fn test_is_generated() {
+        assert!(is_generated(Path::new("app.min.js"), "var a=1;"));
+        assert!(is_generated(
+            Path::new("schema.rs"),
+            "// @generated by tool\nfn x() {}"
+        ));
+        assert!(is_generated(Path::new("out.css"), &"a{}".repeat(400)));
+        assert!(!is_generated(Path::new("lib.rs"), "fn x() {}\n"));
+    }
+
+    #[test]
+    fn test_count_repo_skips_vendored_generated_and_excluded() {
+        let root = std::env::temp_dir().join(format!("loc_counter_test_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for dir in ["src", "node_modules/dep", "target/debug", "assets"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        fs::write(root.join("src/main.rs"), "fn main() {}\n// done\n").unwrap();
+        fs::write(root.join("src/style.css"), "a { color: red; }\n").unwrap();
+        fs::write(root.join("src/bundle.min.js"), "var a=1;\n").unwrap();
+        fs::write(
+            root.join("node_modules/dep/index.js"),
+            "module.exports = 1;\n",
+        )
+        .unwrap();
+        fs::write(root.join("target/debug/build.rs"), "fn x() {}\n").unwrap();
+        fs::write(root.join("assets/app.css"), "b { color: blue; }\n").unwrap();
+        fs::write(root.join("README.md"), "# Title\n").unwrap();
+
+        let stats = count_repo("owner/repo", &root, &["assets".to_string()]).unwrap();
+        let _ = fs::remove_dir_all(&root);
+
+        let names: Vec<&str> = stats
+            .languages
+            .iter()
+            .map(|l| l.language.as_str())
+            .collect();
+        assert_eq!(names, vec!["CSS", "Rust"]);
+        assert_eq!(stats.totals().code, 2);
+        assert_eq!(stats.totals().comments, 1);
+    }
+
+    #[test]
+    fn test_config_parses_and_resolves_paths() {
+        let dir = std::env::temp_dir().join(format!("loc_config_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("loc_stats.toml");
+        fs::write(
+            &path,
+            "[[repo]]\nname = \"Owner/Repo\"\npath = \"../repo\"\nexclude = [\"gen\"]\n",
+        )
+        .unwrap();
+
+        let config = LocConfig::load(&path).unwrap();
+        let _ = fs::remove_dir_all(&dir);
+
+        let checkout = config
+            .checkout("owner/repo")
+            .expect("case-insensitive lookup");
+        assert_eq!(checkout.path, dir.join("../repo"));
+        assert_eq!(checkout.exclude, vec!["gen"]);
+        assert!(config.checkout("owner/other").is_none());
+    }
+
+    #[test]
+    fn test_repo_config_lists_only_tracked_repos() {
+        use crate::utils::github_stats::TRACKED_REPOS;
+
+        let config = LocConfig::load(Path::new(CONFIG_FILE_PATH)).expect("loc_stats.toml parses");
+        for repo in &config.repos {
+            assert!(
+                TRACKED_REPOS
+                    .iter()
+                    .any(|t| t.eq_ignore_ascii_case(&repo.name)),
+                "{} is not in TRACKED_REPOS",
+                repo.name
+            );
+        }
+    }
+}
@@ -1,17 +1,35 @@
 //! Utils Module - Helper functions
 //! Common utilities for formatting, parsing, etc.
 
+pub mod cache;
+pub mod case_studies;
+pub mod clock;
+pub mod contact;
+pub mod forge;
 pub mod github_api;
 pub mod github_cache;
+#[cfg(target_arch = "wasm32")]
+pub mod github_fetch;
 pub mod github_stats;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod github_sync;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod image_pipeline;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod loc_counter;
 pub mod markdown_loader;
 pub mod project_images;
+pub mod repo_history;
+pub mod responsive_images;
+pub mod screenshots;
+pub mod spam;
+pub mod validation;
 
 // Re-export project_images public API
 pub use project_images::{get_project_image_url, ImageSource};
 
 // Re-export github_stats public API
-pub use github_stats::{format_loc, get_github_stats};
+pub use github_stats::{format_loc, get_github_stats, language_shares};
 
 // Re-exports of github_api and github_cache removed to clear unused import warnings.
 // Consumers should import directly from submodules or we only re-export what is actively used.
@@ -1,28 +1,25 @@
 //! Project Images Module
-//! Generates image URLs for project cards using GitHub OpenGraph and ThumbIO APIs
+//! Generates image URLs for project cards from local screenshots, a screenshot
+//! service and forge preview images
 //!
 //! ## Strategy
 //! 1. image_override (manual) → Highest priority
-//! 2. demo_url → ThumbIO screenshot
-//! 3. github_url → GitHub OpenGraph
-//! 4. image_fallback → Emoji placeholder
+//! 2. assets/screenshots/<id>.webp → Committed screenshot
+//! 3. demo_url → Remote screenshot (`config::SCREENSHOT_SERVICE`; see `screenshots`)
+//! 4. github_url → forge preview image (GitHub OpenGraph; see `forge`)
+//! 5. image_fallback → Emoji placeholder
 
-use crate::components::projects::Project;
+use super::forge::{Forge, RepoRef};
+use super::screenshots::{self, SCREENSHOT_HEIGHT, SCREENSHOT_WIDTH, ScreenshotProvider};
+use crate::domain::Project;
 
 // =============================================================================
 // CONSTANTS
 // =============================================================================
 
-/// ThumbIO API base URL (free, no API key required)
-const THUMBIO_BASE: &str = "https://image.thum.io/get";
-
 /// GitHub OpenGraph base URL
 const GITHUB_OG_BASE: &str = "https://opengraph.githubassets.com";
 
-/// Default viewport dimensions for screenshots
-const SCREENSHOT_WIDTH: u32 = 1200;
-const SCREENSHOT_HEIGHT: u32 = 630;
-
 // =============================================================================
 // TYPES
 // =============================================================================
@@ -32,10 +29,12 @@ const SCREENSHOT_HEIGHT: u32 = 630;
 pub enum ImageSource {
     /// Manual URL override (highest priority)
     Override(String),
-    /// ThumbIO screenshot of demo URL
-    DemoScreenshot(String),
-    /// GitHub OpenGraph preview image
-    GitHubOpenGraph(String),
+    /// Committed screenshot from `assets/screenshots`
+    LocalScreenshot(String),
+    /// Remote screenshot of the demo URL, with the provider that renders it
+    DemoScreenshot { provider: &'static str, url: String },
+    /// Preview image published by the repository's forge
+    RepoPreview { forge: Forge, url: String },
     /// Emoji fallback (lowest priority)
     Fallback(String),
 }
@@ -43,10 +42,8 @@ pub enum ImageSource {
 impl std::fmt::Display for ImageSource {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Override(url) => write!(f, "{}", url),
-            Self::DemoScreenshot(url) => write!(f, "{}", url),
-            Self::GitHubOpenGraph(url) => write!(f, "{}", url),
             Self::Fallback(emoji) => write!(f, "{}", emoji),
+            _ => write!(f, "{}", self.url().unwrap_or_default()),
         }
     }
 }
@@ -60,53 +57,30 @@ impl ImageSource {
     /// Returns the URL string if this is a real image, None otherwise
     pub fn url(&self) -> Option<&str> {
         match self {
-            Self::Override(url) | Self::DemoScreenshot(url) | Self::GitHubOpenGraph(url) => {
-                Some(url)
-            }
+            Self::Override(url)
+            | Self::LocalScreenshot(url)
+            | Self::DemoScreenshot { url, .. }
+            | Self::RepoPreview { url, .. } => Some(url),
             Self::Fallback(_) => None,
         }
     }
+
+    /// Name of whatever produced the image
+    pub fn provider(&self) -> &'static str {
+        match self {
+            Self::Override(_) => "override",
+            Self::LocalScreenshot(_) => "local",
+            Self::DemoScreenshot { provider, .. } => provider,
+            Self::RepoPreview { forge, .. } => forge.name(),
+            Self::Fallback(_) => "fallback",
+        }
+    }
 }
 
 // =============================================================================
 // URL GENERATORS
 // =============================================================================
 
-/// Parses a GitHub URL to extract owner and repository name
-///
-/// # Arguments
-/// * `url` - GitHub repository URL (e.g., "https://github.com/owner/repo")
-///
-/// # Returns
-/// * `Some((owner, repo))` if parsing succeeds
-/// * `None` if URL format is invalid
-///
-/// # Examples
-/// ```
-/// let result = parse_github_url("https://github.com/enerBydev/enerby.dev");
-/// assert_eq!(result, Some(("enerBydev".to_string(), "enerby.dev".to_string())));
-/// ```
-pub fn parse_github_url(url: &str) -> Option<(String, String)> {
-    // Normalize URL: remove trailing slash and .git suffix
-    let normalized = url.trim_end_matches('/').trim_end_matches(".git");
-
-    // Split by '/' and get last two segments
-    let parts: Vec<&str> = normalized.split('/').collect();
-
-    // Need at least: https:, "", github.com, owner, repo
-    if parts.len() >= 5 {
-        let repo = parts[parts.len() - 1].to_string();
-        let owner = parts[parts.len() - 2].to_string();
-
-        // Validate non-empty
-        if !owner.is_empty() && !repo.is_empty() {
-            return Some((owner, repo));
-        }
-    }
-
-    None
-}
-
 /// Generates a GitHub OpenGraph image URL for a repository
 ///
 /// Uses the pattern: https://opengraph.githubassets.com/{hash}/{owner}/{repo}
@@ -122,35 +96,9 @@ pub fn generate_github_opengraph_url(owner: &str, repo: &str) -> String {
     format!("{}/1/{}/{}", GITHUB_OG_BASE, owner, repo)
 }
 
-/// Gets GitHub OpenGraph URL from a github_url if valid
-pub fn get_github_preview_from_url(github_url: &str) -> Option<String> {
-    parse_github_url(github_url).map(|(owner, repo)| generate_github_opengraph_url(&owner, &repo))
-}
-
-/// Generates a ThumbIO screenshot URL for any website
-///
-/// Uses ThumbIO's free API (no API key required)
-/// Pattern: https://image.thum.io/get/width/{width}/crop/{height}/{url}
-///
-/// # Arguments
-/// * `demo_url` - URL of the website to screenshot
-///
-/// # Returns
-/// * Full ThumbIO URL that will return a screenshot
-pub fn generate_thumbio_url(demo_url: &str) -> String {
-    format!(
-        "{}/width/{}/crop/{}/{}",
-        THUMBIO_BASE, SCREENSHOT_WIDTH, SCREENSHOT_HEIGHT, demo_url
-    )
-}
-
-/// Generates ThumbIO URL with custom dimensions
-#[allow(dead_code)]
-pub fn generate_thumbio_url_custom(demo_url: &str, width: u32, height: u32) -> String {
-    format!(
-        "{}/width/{}/crop/{}/{}",
-        THUMBIO_BASE, width, height, demo_url
-    )
+/// Gets the forge's preview image for a repository URL, if it has one
+pub fn get_repo_preview_from_url(repo_url: &str) -> Option<String> {
+    RepoRef::parse(repo_url).and_then(|repo| repo.provider().preview_image_url(&repo))
 }
 
 // =============================================================================
@@ -161,9 +109,10 @@ pub fn generate_thumbio_url_custom(demo_url: &str, width: u32, height: u32) -> S
 ///
 /// # Priority Order:
 /// 1. `image_override` - Manual override (highest priority)
-/// 2. `demo_url` - ThumbIO screenshot of live demo
-/// 3. `github_url` - GitHub OpenGraph preview
-/// 4. `image_fallback` - Emoji placeholder (lowest priority)
+/// 2. `assets/screenshots/<id>.webp` - Committed screenshot
+/// 3. `demo_url` - Screenshot from the configured service
+/// 4. `github_url` - Forge preview image (GitHub OpenGraph)
+/// 5. `image_fallback` - Emoji placeholder (lowest priority)
 ///
 /// # Arguments
 /// * `project` - Reference to the Project struct
@@ -171,27 +120,100 @@ pub fn generate_thumbio_url_custom(demo_url: &str, width: u32, height: u32) -> S
 /// # Returns
 /// * `ImageSource` enum containing the URL or fallback
 pub fn get_project_image_url(project: &Project) -> ImageSource {
+    resolve_image_source(
+        project,
+        screenshots::local_screenshot(&project.id),
+        screenshots::configured_provider(),
+    )
+}
+
+/// `get_project_image_url` with the local screenshot and provider supplied
+pub fn resolve_image_source(
+    project: &Project,
+    local_screenshot: Option<String>,
+    screenshot_provider: &dyn ScreenshotProvider,
+) -> ImageSource {
     // Priority 1: Manual override
     if let Some(override_url) = &project.image_override {
         return ImageSource::Override(override_url.to_string());
     }
 
-    // Priority 2: Demo URL → ThumbIO screenshot
+    // Priority 2: Committed screenshot
+    if let Some(url) = local_screenshot {
+        return ImageSource::LocalScreenshot(url);
+    }
+
+    // Priority 3: Demo URL → remote screenshot
     if let Some(demo) = &project.demo_url {
-        return ImageSource::DemoScreenshot(generate_thumbio_url(demo));
+        return ImageSource::DemoScreenshot {
+            provider: screenshot_provider.name(),
+            url: screenshot_provider.screenshot_url(demo, SCREENSHOT_WIDTH, SCREENSHOT_HEIGHT),
+        };
     }
 
-    // Priority 3: GitHub URL → OpenGraph
-    if let Some(github) = &project.github_url {
-        if let Some(og_url) = get_github_preview_from_url(github) {
-            return ImageSource::GitHubOpenGraph(og_url);
-        }
+    // Priority 4: Repository URL → forge preview
+    if let Some(repo) = project.github_url.as_deref().and_then(RepoRef::parse)
+        && let Some(url) = repo.provider().preview_image_url(&repo)
+    {
+        return ImageSource::RepoPreview {
+            forge: repo.forge,
+            url,
+        };
     }
 
-    // Priority 4: Fallback emoji
+    // Priority 5: Fallback emoji
     ImageSource::Fallback(project.image_fallback.to_string())
 }
 
+/// Every real image of a project, for the detail page gallery
+///
+/// Same sources as `get_project_image_url`, but all of them rather than the
+/// best one, with the hand-picked `gallery` screenshots after the override.
+pub fn get_project_gallery(project: &Project) -> Vec<ImageSource> {
+    resolve_gallery(
+        project,
+        screenshots::local_screenshot(&project.id),
+        screenshots::configured_provider(),
+    )
+}
+
+/// `get_project_gallery` with the local screenshot and provider supplied
+pub fn resolve_gallery(
+    project: &Project,
+    local_screenshot: Option<String>,
+    screenshot_provider: &dyn ScreenshotProvider,
+) -> Vec<ImageSource> {
+    let mut images: Vec<ImageSource> = project
+        .image_override
+        .iter()
+        .chain(&project.gallery)
+        .map(|url| ImageSource::Override(url.clone()))
+        .collect();
+
+    images.extend(local_screenshot.map(ImageSource::LocalScreenshot));
+
+    if let Some(demo) = &project.demo_url {
+        images.push(ImageSource::DemoScreenshot {
+            provider: screenshot_provider.name(),
+            url: screenshot_provider.screenshot_url(demo, SCREENSHOT_WIDTH, SCREENSHOT_HEIGHT),
+        });
+    }
+
+    if let Some(repo) = project.github_url.as_deref().and_then(RepoRef::parse)
+        && let Some(url) = repo.provider().preview_image_url(&repo)
+    {
+        images.push(ImageSource::RepoPreview {
+            forge: repo.forge,
+            url,
+        });
+    }
+
+    // The override is often one of the gallery screenshots
+    let mut seen = std::collections::HashSet::new();
+    images.retain(|image| seen.insert(image.url().unwrap_or_default().to_string()));
+    images
+}
+
 // =============================================================================
 // TESTS
 // =============================================================================
@@ -199,7 +221,8 @@ pub fn get_project_image_url(project: &Project) -> ImageSource {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::components::projects::ProjectStatus;
+    use crate::domain::LocalizedText;
+    use crate::utils::screenshots::{Microlink, ThumbIo};
 
     /// Helper to create mock projects for testing
     fn create_mock_project(
@@ -208,51 +231,54 @@ mod tests {
         image_override: Option<&'static str>,
     ) -> Project {
         Project {
-            id: "test-project",
-            title: "Test Project",
-            description_en: "Test description",
-            description_es: "Test description",
-            long_description_en: "Test long description",
-            long_description_es: "Test long description",
-            technologies: vec!["Rust"],
-            status: ProjectStatus::Active,
-            github_url,
-            demo_url,
-            image_override,
-            image_fallback: "🧪",
+            id: "test-project".to_string(),
+            title: "Test Project".to_string(),
+            description: LocalizedText::untranslated("Test description"),
+            long_description: LocalizedText::untranslated("Test long description"),
+            technologies: vec!["Rust".to_string()],
+            github_url: github_url.map(str::to_string),
+            demo_url: demo_url.map(str::to_string),
+            image_override: image_override.map(str::to_string),
+            image_fallback: "🧪".to_string(),
+            ..Default::default()
         }
     }
 
     // =========================================================================
-    // GitHub URL Parser Tests
+    // Gallery Tests
     // =========================================================================
 
     #[test]
-    fn test_parse_github_url_basic() {
-        let result = parse_github_url("https://github.com/enerBydev/enerby.dev");
-        assert_eq!(
-            result,
-            Some(("enerBydev".to_string(), "enerby.dev".to_string()))
+    fn test_gallery_lists_every_source_in_priority_order() {
+        let mut project = create_mock_project(
+            Some("https://github.com/owner/repo"),
+            Some("https://demo.com"),
+            Some("https://custom.com/cover.png"),
         );
-    }
-
-    #[test]
-    fn test_parse_github_url_with_trailing_slash() {
-        let result = parse_github_url("https://github.com/owner/repo/");
-        assert_eq!(result, Some(("owner".to_string(), "repo".to_string())));
-    }
+        project.gallery = vec![
+            "https://custom.com/cover.png".to_string(),
+            "https://custom.com/detail.png".to_string(),
+        ];
+
+        let gallery = resolve_gallery(
+            &project,
+            Some("/assets/screenshots/test-project.webp".to_string()),
+            &ThumbIo,
+        );
+        let providers: Vec<&str> = gallery.iter().map(ImageSource::provider).collect();
 
-    #[test]
-    fn test_parse_github_url_with_git_extension() {
-        let result = parse_github_url("https://github.com/owner/repo.git");
-        assert_eq!(result, Some(("owner".to_string(), "repo".to_string())));
+        // The duplicated cover is listed once
+        assert_eq!(
+            providers,
+            vec!["override", "override", "local", "thum.io", "GitHub"]
+        );
+        assert_eq!(gallery[1].url(), Some("https://custom.com/detail.png"));
     }
 
     #[test]
-    fn test_parse_github_url_invalid() {
-        assert!(parse_github_url("not-a-url").is_none());
-        assert!(parse_github_url("https://example.com").is_none());
-        assert!(parse_github_url("").is_none());
+    fn test_gallery_empty_without_images() {
+        let project = create_mock_project(None, None, None);
+        assert!(resolve_gallery(&project, None, &ThumbIo).is_empty());
     }
 
     // =========================================================================
@@ -269,30 +295,17 @@ mod tests {
     }
 
     #[test]
-    fn test_get_github_preview_from_url() {
-        let result = get_github_preview_from_url("https://github.com/owner/repo");
+    fn test_get_repo_preview_from_url() {
+        let result = get_repo_preview_from_url("https://github.com/owner/repo");
         assert!(result.is_some());
         assert!(result.unwrap().contains("owner/repo"));
-    }
 
-    // =========================================================================
-    // ThumbIO URL Generator Tests
-    // =========================================================================
-
-    #[test]
-    fn test_generate_thumbio_url() {
-        let url = generate_thumbio_url("https://enerby.dev");
-        assert!(url.starts_with("https://image.thum.io/get/"));
-        assert!(url.contains("width/1200"));
-        assert!(url.contains("crop/630"));
-        assert!(url.ends_with("https://enerby.dev"));
-    }
-
-    #[test]
-    fn test_generate_thumbio_url_custom() {
-        let url = generate_thumbio_url_custom("https://example.com", 800, 600);
-        assert!(url.contains("width/800"));
-        assert!(url.contains("crop/600"));
+        // Only GitHub publishes preview images
+        assert_eq!(
+            get_repo_preview_from_url("https://gitlab.com/owner/repo"),
+            None
+        );
+        assert_eq!(get_repo_preview_from_url("not-a-url"), None);
     }
 
     // =========================================================================
@@ -318,19 +331,66 @@ mod tests {
             Some("https://demo.com"),
             None,
         );
-        let result = get_project_image_url(&project);
-        assert!(matches!(result, ImageSource::DemoScreenshot(_)));
+        let result = resolve_image_source(&project, None, &ThumbIo);
+        assert!(matches!(result, ImageSource::DemoScreenshot { .. }));
+        assert_eq!(result.provider(), "thum.io");
         assert!(result.to_string().contains("image.thum.io"));
+        assert!(result.to_string().contains("width/1200"));
+        assert!(result.to_string().contains("crop/630"));
+    }
+
+    #[test]
+    fn test_priority_demo_url_uses_given_provider() {
+        let project = create_mock_project(None, Some("https://demo.com"), None);
+        let result = resolve_image_source(&project, None, &Microlink);
+        assert_eq!(result.provider(), "microlink");
+        assert!(result.to_string().starts_with("https://api.microlink.io/"));
+    }
+
+    #[test]
+    fn test_priority_local_screenshot() {
+        // A committed screenshot beats every remote source, but not a manual override
+        let project = create_mock_project(
+            Some("https://github.com/owner/repo"),
+            Some("https://demo.com"),
+            None,
+        );
+        let local = Some("/assets/screenshots/test-project.webp".to_string());
+        let result = resolve_image_source(&project, local.clone(), &ThumbIo);
+        assert_eq!(
+            result,
+            ImageSource::LocalScreenshot("/assets/screenshots/test-project.webp".to_string())
+        );
+        assert_eq!(result.provider(), "local");
+
+        let project = create_mock_project(None, None, Some("https://custom.com/image.png"));
+        let result = resolve_image_source(&project, local, &ThumbIo);
+        assert!(matches!(result, ImageSource::Override(_)));
     }
 
     #[test]
     fn test_priority_github_url() {
         let project = create_mock_project(Some("https://github.com/owner/repo"), None, None);
         let result = get_project_image_url(&project);
-        assert!(matches!(result, ImageSource::GitHubOpenGraph(_)));
+        assert!(matches!(
+            result,
+            ImageSource::RepoPreview {
+                forge: Forge::GitHub,
+                ..
+            }
+        ));
+        assert_eq!(result.provider(), "GitHub");
         assert!(result.to_string().contains("opengraph.githubassets.com"));
     }
 
+    #[test]
+    fn test_priority_non_github_repo_falls_back() {
+        // A GitLab URL must not produce a GitHub OpenGraph image
+        let project = create_mock_project(Some("https://gitlab.com/owner/repo"), None, None);
+        let result = get_project_image_url(&project);
+        assert_eq!(result, ImageSource::Fallback("🧪".to_string()));
+    }
+
     #[test]
     fn test_priority_fallback() {
         let project = create_mock_project(None, None, None);
@@ -343,13 +403,24 @@ mod tests {
     // Helper Function Tests
     // =========================================================================
 
-
-
     #[test]
     fn test_image_source_is_real_image() {
         assert!(ImageSource::Override("url".to_string()).is_real_image());
-        assert!(ImageSource::DemoScreenshot("url".to_string()).is_real_image());
-        assert!(ImageSource::GitHubOpenGraph("url".to_string()).is_real_image());
+        assert!(ImageSource::LocalScreenshot("url".to_string()).is_real_image());
+        assert!(
+            ImageSource::DemoScreenshot {
+                provider: "thum.io",
+                url: "url".to_string()
+            }
+            .is_real_image()
+        );
+        assert!(
+            ImageSource::RepoPreview {
+                forge: Forge::GitHub,
+                url: "url".to_string()
+            }
+            .is_real_image()
+        );
         assert!(!ImageSource::Fallback("🧪".to_string()).is_real_image());
     }
 }
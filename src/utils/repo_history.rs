@@ -0,0 +1,261 @@
+//! Repository History Module
+//!
+//! Dated star, fork and open issue counts per repository, appended by every
+//! `github-sync` run to `.cache/github_history.json` and embedded at build
+//! time. The snapshot in `github_api` only keeps the latest numbers; this file
+//! keeps how they got there.
+//!
+//! Repositories are keyed by `RepoRef` display (`host/owner/name`, lowercase),
+//! so every forge shares one file.
+//!
+//! # Example
+//! ```
+//! use enerby_dev::utils::repo_history::sparkline_points;
+//!
+//! assert_eq!(sparkline_points(&[1, 3, 2], 100, 20), "0,20 50,0 100,10");
+//! ```
+
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+
+use serde::{Deserialize, Serialize};
+
+use super::forge::RepoRef;
+use super::github_api::GitHubRepoInfo;
+
+/// History file path (relative to project root)
+pub const HISTORY_FILE_PATH: &str = ".cache/github_history.json";
+
+/// History written by the `github-sync` binary
+const EMBEDDED_HISTORY: &str = include_str!("../../.cache/github_history.json");
+
+static HISTORY: LazyLock<RepoHistory> =
+    LazyLock::new(|| RepoHistory::from_json(EMBEDDED_HISTORY).unwrap_or_default());
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+/// Counts for one repository on one day
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryPoint {
+    /// Sync date (YYYY-MM-DD, UTC)
+    pub date: String,
+    pub stars: u32,
+    pub forks: u32,
+    pub open_issues: u32,
+}
+
+impl HistoryPoint {
+    /// Point for a repository as fetched on `date`
+    pub fn from_repo(date: &str, info: &GitHubRepoInfo) -> Self {
+        Self {
+            date: date.to_string(),
+            stars: info.stargazers_count,
+            forks: info.forks_count,
+            open_issues: info.open_issues_count,
+        }
+    }
+}
+
+/// Contents of `.cache/github_history.json`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepoHistory {
+    /// Points per repository, oldest first
+    #[serde(default)]
+    pub repos: BTreeMap<String, Vec<HistoryPoint>>,
+}
+
+impl RepoHistory {
+    /// Deserializes a history from JSON
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Pretty JSON with a trailing newline
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self).map(|json| json + "\n")
+    }
+
+    /// Appends a point for a repository
+    ///
+    /// A second sync on the same day replaces that day's point instead of
+    /// adding another, so the series has at most one point per day.
+    pub fn record(&mut self, repo: &RepoRef, point: HistoryPoint) {
+        let points = self.repos.entry(history_key(repo)).or_default();
+        match points.last_mut() {
+            Some(last) if last.date == point.date => *last = point,
+            _ => points.push(point),
+        }
+    }
+
+    /// Records every fetched repository, keyed by its canonical `html_url`
+    ///
+    /// Returns the number of points recorded.
+    pub fn record_all<'a>(
+        &mut self,
+        date: &str,
+        repos: impl IntoIterator<Item = &'a GitHubRepoInfo>,
+    ) -> usize {
+        let mut recorded = 0;
+        for info in repos {
+            if let Some(repo) = RepoRef::parse(&info.html_url) {
+                self.record(&repo, HistoryPoint::from_repo(date, info));
+                recorded += 1;
+            }
+        }
+        recorded
+    }
+
+    /// Points for a repository, oldest first (empty if never synced)
+    pub fn points(&self, repo: &RepoRef) -> &[HistoryPoint] {
+        self.repos
+            .get(&history_key(repo))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
+/// Key of a repository in the history file
+pub fn history_key(repo: &RepoRef) -> String {
+    repo.to_string().to_lowercase()
+}
+
+// ============================================================================
+// PUBLIC API
+// ============================================================================
+
+/// Embedded history for a repository URL, oldest first
+pub fn get_repo_history(repo_url: &str) -> &'static [HistoryPoint] {
+    RepoRef::parse(repo_url)
+        .map(|repo| HISTORY.points(&repo))
+        .unwrap_or_default()
+}
+
+/// SVG `polyline` points for a series scaled to `width`×`height`
+///
+/// The minimum maps to the bottom edge and the maximum to the top; a flat
+/// series is drawn along the bottom. Empty for fewer than two values.
+pub fn sparkline_points(values: &[u32], width: u32, height: u32) -> String {
+    if values.len() < 2 {
+        return String::new();
+    }
+    let min = values.iter().copied().min().unwrap_or(0);
+    let max = values.iter().copied().max().unwrap_or(0);
+    let range = (max - min).max(1) as f64;
+    let step = width as f64 / (values.len() - 1) as f64;
+
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let x = i as f64 * step;
+            let y = height as f64 - (value - min) as f64 / range * height as f64;
+            format!("{},{}", round2(x), round2(y))
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Rounds to two decimals, dropping trailing zeros when printed
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(date: &str, stars: u32) -> HistoryPoint {
+        HistoryPoint {
+            date: date.to_string(),
+            stars,
+            forks: 0,
+            open_issues: 0,
+        }
+    }
+
+    // -------------------------
+    // Recording Tests
+    // -------------------------
+
+    #[test]
+    fn test_record_appends_and_replaces_same_day() {
+        let repo = RepoRef::github("Owner", "Repo");
+        let mut history = RepoHistory::default();
+
+        history.record(&repo, point("2026-01-01", 1));
+        history.record(&repo, point("2026-01-02", 2));
+        history.record(&repo, point("2026-01-02", 3));
+
+        assert_eq!(
+            history.points(&repo),
+            &[point("2026-01-01", 1), point("2026-01-02", 3)]
+        );
+        assert_eq!(
+            history.repos.keys().collect::<Vec<_>>(),
+            vec!["github.com/owner/repo"]
+        );
+    }
+
+    #[test]
+    fn test_record_all_uses_canonical_url() {
+        let mut info = GitHubRepoInfo::minimal("owner", "renamed");
+        info.stargazers_count = 7;
+        info.forks_count = 2;
+        info.open_issues_count = 1;
+        let mut broken = GitHubRepoInfo::minimal("owner", "broken");
+        broken.html_url = String::new();
+
+        let mut history = RepoHistory::default();
+        assert_eq!(history.record_all("2026-01-01", [&info, &broken]), 1);
+
+        let points = history.points(&RepoRef::github("owner", "renamed"));
+        assert_eq!(
+            points,
+            &[HistoryPoint {
+                date: "2026-01-01".to_string(),
+                stars: 7,
+                forks: 2,
+                open_issues: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_history_roundtrip() {
+        let mut history = RepoHistory::default();
+        history.record(&RepoRef::github("owner", "repo"), point("2026-01-01", 1));
+
+        let json = history.to_json().unwrap();
+        assert!(json.ends_with("}\n"));
+        assert_eq!(RepoHistory::from_json(&json).unwrap(), history);
+    }
+
+    #[test]
+    fn test_embedded_history_parses() {
+        RepoHistory::from_json(EMBEDDED_HISTORY).expect("valid github_history.json");
+        assert!(get_repo_history("not a url").is_empty());
+    }
+
+    // -------------------------
+    // Sparkline Tests
+    // -------------------------
+
+    #[test]
+    fn test_sparkline_points() {
+        assert_eq!(sparkline_points(&[0, 10], 100, 20), "0,20 100,0");
+        assert_eq!(sparkline_points(&[5, 5, 5], 90, 30), "0,30 45,30 90,30");
+        assert_eq!(sparkline_points(&[1, 2, 4], 10, 3), "0,3 5,2 10,0");
+    }
+
+    #[test]
+    fn test_sparkline_needs_two_points() {
+        assert_eq!(sparkline_points(&[], 100, 20), "");
+        assert_eq!(sparkline_points(&[3], 100, 20), "");
+    }
+}
@@ -0,0 +1,314 @@
+//! Responsive Images Module
+//!
+//! Resized project images produced offline by `cargo run --bin image-pipeline`
+//! (see `utils::image_pipeline`):
+//!
+//! - `assets/projects/<project-id>-<width>.<avif|webp>`: one file per width in
+//!   `IMAGE_WIDTHS` (up to the source width) and per format, bundled by `build.rs`
+//! - `.cache/project_images.json`: intrinsic size and blurred placeholder per
+//!   project, embedded at build time
+//!
+//! Projects without variants keep using the remote `ImageSource` URL.
+
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Variant widths in pixels, smallest first
+pub const IMAGE_WIDTHS: [u32; 3] = [400, 800, 1200];
+
+/// Manifest file path (relative to project root)
+pub const MANIFEST_FILE_PATH: &str = ".cache/project_images.json";
+
+/// Directory the variants are written to (relative to project root)
+pub const VARIANT_DIR: &str = "assets/projects";
+
+/// `sizes` for featured cards (image takes a third of the row on desktop)
+pub const FEATURED_SIZES: &str = "(min-width: 1024px) 33vw, 100vw";
+
+/// `sizes` for grid cards (two columns from tablet up)
+pub const CARD_SIZES: &str = "(min-width: 768px) 50vw, 100vw";
+
+/// Manifest written by the `image-pipeline` binary
+const EMBEDDED_MANIFEST: &str = include_str!("../../.cache/project_images.json");
+
+/// Bundled variants as (project id, width, format, asset), generated by `build.rs`
+const VARIANTS: &[(&str, u32, &str, Asset)] =
+    include!(concat!(env!("OUT_DIR"), "/project_image_variants.rs"));
+
+static MANIFEST: LazyLock<ImageManifest> =
+    LazyLock::new(|| ImageManifest::from_json(EMBEDDED_MANIFEST).unwrap_or_default());
+
+// ============================================================================
+// MANIFEST TYPES
+// ============================================================================
+
+/// Output formats, in order of preference for `<picture>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VariantFormat {
+    Avif,
+    Webp,
+}
+
+impl VariantFormat {
+    /// All formats, preferred first
+    pub const ALL: [VariantFormat; 2] = [VariantFormat::Avif, VariantFormat::Webp];
+
+    /// File extension
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Avif => "avif",
+            Self::Webp => "webp",
+        }
+    }
+
+    /// MIME type for `<source type>`
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Avif => "image/avif",
+            Self::Webp => "image/webp",
+        }
+    }
+
+    /// Parses a file extension
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|f| f.extension() == ext)
+    }
+}
+
+/// Variant file name for a project, width and format
+pub fn variant_file_name(project_id: &str, width: u32, format: VariantFormat) -> String {
+    format!("{}-{}.{}", project_id, width, format.extension())
+}
+
+/// Processed image for one project
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectImage {
+    /// URL or path the source image came from
+    pub source: String,
+    /// Size of the largest variant
+    pub width: u32,
+    pub height: u32,
+    /// Generated widths, smallest first
+    pub widths: Vec<u32>,
+    /// Blurhash of the source image
+    pub blurhash: String,
+    /// Tiny PNG decoded from the blurhash, as a data URI
+    pub placeholder: String,
+}
+
+/// Contents of `.cache/project_images.json`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageManifest {
+    /// Date the manifest was generated (YYYY-MM-DD)
+    #[serde(default)]
+    pub generated_at: String,
+    /// Images keyed by project id
+    #[serde(default)]
+    pub images: BTreeMap<String, ProjectImage>,
+}
+
+impl ImageManifest {
+    /// Deserializes a manifest from JSON
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Pretty JSON with a trailing newline
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self).map(|json| json + "\n")
+    }
+}
+
+// ============================================================================
+// PUBLIC API
+// ============================================================================
+
+/// `srcset` for one format
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageSourceSet {
+    pub format: VariantFormat,
+    /// e.g. "/assets/p-400.avif 400w, /assets/p-800.avif 800w"
+    pub srcset: String,
+}
+
+/// Everything a card needs to render a responsive `<picture>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponsiveImage {
+    /// Largest WebP variant, for `<img src>`
+    pub src: String,
+    /// One entry per available format, preferred first
+    pub sources: Vec<ImageSourceSet>,
+    pub width: u32,
+    pub height: u32,
+    /// Blurred placeholder data URI
+    pub placeholder: String,
+}
+
+impl ResponsiveImage {
+    /// `srcset` for a format, if it was generated
+    pub fn srcset(&self, format: VariantFormat) -> Option<&str> {
+        self.sources
+            .iter()
+            .find(|s| s.format == format)
+            .map(|s| s.srcset.as_str())
+    }
+}
+
+/// Responsive variants for a project, if the pipeline has processed it
+pub fn responsive_image(project_id: &str) -> Option<ResponsiveImage> {
+    let variants: Vec<(u32, VariantFormat, String)> = VARIANTS
+        .iter()
+        .filter(|(id, ..)| *id == project_id)
+        .filter_map(|(_, width, ext, asset)| {
+            Some((
+                *width,
+                VariantFormat::from_extension(ext)?,
+                asset.to_string(),
+            ))
+        })
+        .collect();
+    build_responsive_image(MANIFEST.images.get(project_id)?, &variants)
+}
+
+/// Combines a manifest entry with the bundled `(width, format, url)` variants
+///
+/// Needs at least one WebP variant for the `<img>` fallback.
+pub fn build_responsive_image(
+    image: &ProjectImage,
+    variants: &[(u32, VariantFormat, String)],
+) -> Option<ResponsiveImage> {
+    let mut sources = Vec::new();
+    let mut src = None;
+
+    for format in VariantFormat::ALL {
+        let mut matching: Vec<&(u32, VariantFormat, String)> =
+            variants.iter().filter(|(_, f, _)| *f == format).collect();
+        if matching.is_empty() {
+            continue;
+        }
+        matching.sort_by_key(|(width, ..)| *width);
+
+        if format == VariantFormat::Webp {
+            src = matching.last().map(|(_, _, url)| url.clone());
+        }
+        sources.push(ImageSourceSet {
+            format,
+            srcset: matching
+                .iter()
+                .map(|(width, _, url)| format!("{} {}w", url, width))
+                .collect::<Vec<_>>()
+                .join(", "),
+        });
+    }
+
+    Some(ResponsiveImage {
+        src: src?,
+        sources,
+        width: image.width,
+        height: image.height,
+        placeholder: image.placeholder.clone(),
+    })
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image() -> ProjectImage {
+        ProjectImage {
+            source: "https://example.com/shot.png".to_string(),
+            width: 800,
+            height: 420,
+            widths: vec![400, 800],
+            blurhash: "LEHV6nWB2yk8pyo0adR*.7kCMdnj".to_string(),
+            placeholder: "data:image/png;base64,AAAA".to_string(),
+        }
+    }
+
+    fn variant(width: u32, format: VariantFormat) -> (u32, VariantFormat, String) {
+        (
+            width,
+            format,
+            format!("/assets/{}", variant_file_name("p", width, format)),
+        )
+    }
+
+    #[test]
+    fn test_build_responsive_image() {
+        let variants = vec![
+            variant(800, VariantFormat::Webp),
+            variant(400, VariantFormat::Webp),
+            variant(400, VariantFormat::Avif),
+            variant(800, VariantFormat::Avif),
+        ];
+        let responsive = build_responsive_image(&image(), &variants).unwrap();
+
+        assert_eq!(responsive.src, "/assets/p-800.webp");
+        assert_eq!(responsive.sources[0].format, VariantFormat::Avif);
+        assert_eq!(
+            responsive.srcset(VariantFormat::Avif),
+            Some("/assets/p-400.avif 400w, /assets/p-800.avif 800w")
+        );
+        assert_eq!(
+            responsive.srcset(VariantFormat::Webp),
+            Some("/assets/p-400.webp 400w, /assets/p-800.webp 800w")
+        );
+        assert_eq!((responsive.width, responsive.height), (800, 420));
+    }
+
+    #[test]
+    fn test_build_responsive_image_needs_webp() {
+        let variants = vec![variant(400, VariantFormat::Avif)];
+        assert_eq!(build_responsive_image(&image(), &variants), None);
+
+        let variants = vec![variant(400, VariantFormat::Webp)];
+        let responsive = build_responsive_image(&image(), &variants).unwrap();
+        assert_eq!(responsive.srcset(VariantFormat::Avif), None);
+    }
+
+    #[test]
+    fn test_manifest_roundtrip() {
+        let mut manifest = ImageManifest {
+            generated_at: "2026-01-01".to_string(),
+            ..Default::default()
+        };
+        manifest.images.insert("p".to_string(), image());
+
+        let json = manifest.to_json().unwrap();
+        assert!(json.ends_with("}\n"));
+        assert_eq!(ImageManifest::from_json(&json).unwrap(), manifest);
+    }
+
+    #[test]
+    fn test_embedded_manifest_matches_variants() {
+        let manifest =
+            ImageManifest::from_json(EMBEDDED_MANIFEST).expect("valid project_images.json");
+
+        // Every manifest entry must have its variants committed, and vice versa
+        for (id, image) in &manifest.images {
+            for width in &image.widths {
+                for format in VariantFormat::ALL {
+                    assert!(
+                        VARIANTS.iter().any(|(vid, w, f, _)| vid == id
+                            && w == width
+                            && *f == format.extension()),
+                        "missing {}/{}",
+                        VARIANT_DIR,
+                        variant_file_name(id, *width, format)
+                    );
+                }
+            }
+        }
+        for (id, ..) in VARIANTS {
+            assert!(manifest.images.contains_key(*id), "{} not in manifest", id);
+        }
+    }
+}
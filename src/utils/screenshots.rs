@@ -0,0 +1,262 @@
+//! Screenshots Module
+//!
+//! Screenshot sources for project demo sites:
+//!
+//! - Local overrides: `assets/screenshots/<project-id>.webp`, bundled at build
+//!   time (see `build.rs`) and preferred over any remote service
+//! - `ScreenshotProvider` implementations for remote services: `ThumbIo`,
+//!   `Microlink` and `SelfHosted`
+//!
+//! The active remote service is `config::SCREENSHOT_SERVICE`.
+//!
+//! # Example
+//! ```
+//! use enerby_dev::utils::screenshots::{Microlink, ScreenshotProvider};
+//!
+//! let url = Microlink.screenshot_url("https://enerby.dev", 1200, 630);
+//! assert!(url.starts_with("https://api.microlink.io/?url=https%3A%2F%2Fenerby.dev"));
+//! ```
+
+use dioxus::prelude::*;
+
+use crate::config::{SCREENSHOT_SERVICE, ScreenshotService};
+
+/// Default viewport dimensions for screenshots
+pub const SCREENSHOT_WIDTH: u32 = 1200;
+pub const SCREENSHOT_HEIGHT: u32 = 630;
+
+/// Committed screenshots as (project id, bundled asset), generated by `build.rs`
+const LOCAL_SCREENSHOTS: &[(&str, Asset)] =
+    include!(concat!(env!("OUT_DIR"), "/local_screenshots.rs"));
+
+// =============================================================================
+// PROVIDER TRAIT
+// =============================================================================
+
+/// A remote service that renders a screenshot of a web page
+pub trait ScreenshotProvider: Sync {
+    /// Short identifier recorded in `ImageSource`
+    fn name(&self) -> &'static str;
+
+    /// Image URL for a `width`×`height` screenshot of `page_url`
+    fn screenshot_url(&self, page_url: &str, width: u32, height: u32) -> String;
+}
+
+/// thum.io (free, no API key required)
+///
+/// Pattern: `https://image.thum.io/get/width/{width}/crop/{height}/{url}`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ThumbIo;
+
+impl ScreenshotProvider for ThumbIo {
+    fn name(&self) -> &'static str {
+        "thum.io"
+    }
+
+    fn screenshot_url(&self, page_url: &str, width: u32, height: u32) -> String {
+        format!(
+            "https://image.thum.io/get/width/{}/crop/{}/{}",
+            width, height, page_url
+        )
+    }
+}
+
+/// Microlink screenshot API, embedding the image directly
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Microlink;
+
+impl ScreenshotProvider for Microlink {
+    fn name(&self) -> &'static str {
+        "microlink"
+    }
+
+    fn screenshot_url(&self, page_url: &str, width: u32, height: u32) -> String {
+        format!(
+            "https://api.microlink.io/?url={}&screenshot=true&meta=false&embed=screenshot.url&viewport.width={}&viewport.height={}",
+            encode_query_value(page_url),
+            width,
+            height
+        )
+    }
+}
+
+/// Self-hosted screenshot endpoint
+///
+/// Called as `{base_url}?url={encoded}&width={width}&height={height}`.
+#[derive(Debug, Clone, Copy)]
+pub struct SelfHosted {
+    pub base_url: &'static str,
+}
+
+impl ScreenshotProvider for SelfHosted {
+    fn name(&self) -> &'static str {
+        "self-hosted"
+    }
+
+    fn screenshot_url(&self, page_url: &str, width: u32, height: u32) -> String {
+        format!(
+            "{}?url={}&width={}&height={}",
+            self.base_url.trim_end_matches('/'),
+            encode_query_value(page_url),
+            width,
+            height
+        )
+    }
+}
+
+impl ScreenshotProvider for ScreenshotService {
+    fn name(&self) -> &'static str {
+        match *self {
+            Self::ThumbIo => ThumbIo.name(),
+            Self::Microlink => Microlink.name(),
+            Self::SelfHosted { base_url } => SelfHosted { base_url }.name(),
+        }
+    }
+
+    fn screenshot_url(&self, page_url: &str, width: u32, height: u32) -> String {
+        match *self {
+            Self::ThumbIo => ThumbIo.screenshot_url(page_url, width, height),
+            Self::Microlink => Microlink.screenshot_url(page_url, width, height),
+            Self::SelfHosted { base_url } => {
+                SelfHosted { base_url }.screenshot_url(page_url, width, height)
+            }
+        }
+    }
+}
+
+/// The provider selected in `config::SCREENSHOT_SERVICE`
+pub fn configured_provider() -> &'static dyn ScreenshotProvider {
+    &SCREENSHOT_SERVICE
+}
+
+// =============================================================================
+// LOCAL OVERRIDES
+// =============================================================================
+
+/// URL of the committed screenshot for a project, if there is one
+pub fn local_screenshot(project_id: &str) -> Option<String> {
+    LOCAL_SCREENSHOTS
+        .iter()
+        .find(|(id, _)| *id == project_id)
+        .map(|(_, asset)| asset.to_string())
+}
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+/// Percent-encodes a value for use in a query string (RFC 3986 unreserved set)
+pub(crate) fn encode_query_value(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Reverses `encode_query_value`; malformed escapes are kept as written
+pub(crate) fn decode_query_value(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| value.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thumbio_url() {
+        let url = ThumbIo.screenshot_url("https://enerby.dev", 1200, 630);
+        assert_eq!(
+            url,
+            "https://image.thum.io/get/width/1200/crop/630/https://enerby.dev"
+        );
+    }
+
+    #[test]
+    fn test_microlink_url_encodes_page() {
+        let url = Microlink.screenshot_url("https://example.com/a?b=c", 800, 600);
+        assert!(
+            url.starts_with("https://api.microlink.io/?url=https%3A%2F%2Fexample.com%2Fa%3Fb%3Dc&")
+        );
+        assert!(url.contains("embed=screenshot.url"));
+        assert!(url.contains("viewport.width=800"));
+        assert!(url.contains("viewport.height=600"));
+    }
+
+    #[test]
+    fn test_self_hosted_url() {
+        let provider = SelfHosted {
+            base_url: "https://shots.example.com/render/",
+        };
+        assert_eq!(
+            provider.screenshot_url("https://enerby.dev", 400, 300),
+            "https://shots.example.com/render?url=https%3A%2F%2Fenerby.dev&width=400&height=300"
+        );
+    }
+
+    #[test]
+    fn test_service_dispatches_to_provider() {
+        let service = ScreenshotService::Microlink;
+        assert_eq!(service.name(), "microlink");
+        assert_eq!(
+            service.screenshot_url("https://enerby.dev", 1, 2),
+            Microlink.screenshot_url("https://enerby.dev", 1, 2)
+        );
+        assert_eq!(configured_provider().name(), SCREENSHOT_SERVICE.name());
+    }
+
+    #[test]
+    fn test_encode_query_value() {
+        assert_eq!(encode_query_value("a-b_c.d~e"), "a-b_c.d~e");
+        assert_eq!(encode_query_value("a b/ñ"), "a%20b%2F%C3%B1");
+    }
+
+    #[test]
+    fn test_decode_query_value() {
+        for value in ["a b/ñ", "C#", "a&b=c,d", "100%"] {
+            assert_eq!(decode_query_value(&encode_query_value(value)), value);
+        }
+        assert_eq!(decode_query_value("100%"), "100%");
+        assert_eq!(decode_query_value("%zz%4"), "%zz%4");
+    }
+
+    #[test]
+    fn test_local_screenshots_match_projects() {
+        // A misnamed file would silently never be used
+        for (id, _) in LOCAL_SCREENSHOTS {
+            assert!(
+                crate::components::projects::get_enriched_project_by_id(id).is_some(),
+                "assets/screenshots/{id}.webp has no matching project"
+            );
+        }
+        assert_eq!(local_screenshot("no-such-project"), None);
+    }
+}
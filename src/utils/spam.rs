@@ -0,0 +1,518 @@
+//! Spam Protection Module
+//!
+//! Checks run on the contact form before delivery, without a third-party
+//! captcha:
+//!
+//! - Honeypot: `ContactForm::website` is hidden from people, so only bots fill it
+//! - Minimum fill time: forms sent less than `MIN_FILL_SECONDS` after opening
+//! - Rate limit: at most `MAX_SUBMISSIONS` per `SUBMISSION_WINDOW_SECONDS` per
+//!   browser, tracked in `localStorage`
+//! - Proof of work (optional, `config::CONTACT_PROOF_OF_WORK`): a hashcash
+//!   stamp sent as `ContactForm::proof`, checked by the endpoint with
+//!   `verify_stamp`
+//!
+//! The first three only stop naive bots; the stamp makes bulk sending costly.
+
+use std::ops::Range;
+
+use chrono::{DateTime, NaiveDate};
+use sha1::{Digest, Sha1};
+
+use super::cache::CacheStorage;
+use super::clock::{SharedClock, default_clock};
+use crate::data::ContactForm;
+use crate::i18n::dict::ErrorsConfig;
+
+/// People need at least this long to fill in the form
+pub const MIN_FILL_SECONDS: u64 = 3;
+
+/// Submissions allowed per browser within `SUBMISSION_WINDOW_SECONDS`
+pub const MAX_SUBMISSIONS: usize = 3;
+pub const SUBMISSION_WINDOW_SECONDS: u64 = 60 * 60;
+
+/// `localStorage` key of the submission log
+pub const SUBMISSION_LOG_KEY: &str = "enerby_contact_submissions";
+
+/// Days a hashcash stamp stays valid (covers time zones around midnight)
+const STAMP_MAX_AGE_DAYS: i64 = 2;
+
+/// Why a submission looks automated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpamError {
+    /// The hidden field was filled in
+    Honeypot,
+    /// Sent faster than `MIN_FILL_SECONDS` after opening the form
+    TooFast,
+    /// `MAX_SUBMISSIONS` already sent; the next one is allowed at `retry_at`
+    RateLimited { retry_at: u64 },
+}
+
+impl SpamError {
+    /// Message shown to the visitor; `None` when the form should fake success
+    /// (bots are not told they were caught)
+    pub fn message(&self, i18n: &ErrorsConfig) -> Option<&'static str> {
+        match self {
+            SpamError::Honeypot => None,
+            SpamError::TooFast => Some(i18n.spam_too_fast),
+            SpamError::RateLimited { .. } => Some(i18n.spam_rate_limited),
+        }
+    }
+}
+
+/// Fails if the honeypot field has a value
+pub fn check_honeypot(form: &ContactForm) -> Result<(), SpamError> {
+    if form.website.trim().is_empty() {
+        Ok(())
+    } else {
+        Err(SpamError::Honeypot)
+    }
+}
+
+/// Fails if the form was sent less than `MIN_FILL_SECONDS` after `opened_at`
+pub fn check_fill_time(opened_at: u64, now: u64) -> Result<(), SpamError> {
+    if now.saturating_sub(opened_at) < MIN_FILL_SECONDS {
+        Err(SpamError::TooFast)
+    } else {
+        Ok(())
+    }
+}
+
+// ============================================================================
+// GUARD
+// ============================================================================
+
+/// Spam checks for one opening of the contact form
+pub struct SpamGuard {
+    opened_at: u64,
+    storage: Box<dyn CacheStorage>,
+    clock: SharedClock,
+}
+
+impl SpamGuard {
+    /// Guard for a form opened at `opened_at`, logging to this browser's storage
+    pub fn new(opened_at: u64) -> Self {
+        Self {
+            opened_at,
+            storage: submission_storage(),
+            clock: default_clock(),
+        }
+    }
+
+    /// Keeps the submission log in `storage` instead of `localStorage`
+    pub fn with_storage(mut self, storage: Box<dyn CacheStorage>) -> Self {
+        self.storage = storage;
+        self
+    }
+
+    /// Reads time from `clock` instead of the real clock
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Runs the honeypot, fill time and rate limit checks
+    pub fn check(&self, form: &ContactForm) -> Result<(), SpamError> {
+        let now = self.clock.now_seconds();
+        check_honeypot(form)?;
+        check_fill_time(self.opened_at, now)?;
+
+        let recent = self.recent_submissions(now);
+        if recent.len() >= MAX_SUBMISSIONS {
+            let oldest = recent.iter().min().copied().unwrap_or(now);
+            return Err(SpamError::RateLimited {
+                retry_at: oldest + SUBMISSION_WINDOW_SECONDS,
+            });
+        }
+        Ok(())
+    }
+
+    /// Counts a submission against the rate limit
+    pub fn record_submission(&self) {
+        let now = self.clock.now_seconds();
+        let mut recent = self.recent_submissions(now);
+        recent.push(now);
+
+        let json = serde_json::to_string(&recent).unwrap_or_default();
+        if let Err(e) = self.storage.write(&json) {
+            eprintln!("Warning: could not save contact submission log: {}", e);
+        }
+    }
+
+    /// Submission times within the window (a corrupt log counts as empty)
+    fn recent_submissions(&self, now: u64) -> Vec<u64> {
+        self.storage
+            .read()
+            .and_then(|json| serde_json::from_str::<Vec<u64>>(&json).ok())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|&at| at + SUBMISSION_WINDOW_SECONDS > now)
+            .collect()
+    }
+}
+
+/// `localStorage` in the browser; in-memory elsewhere (tests, SSR)
+fn submission_storage() -> Box<dyn CacheStorage> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        Box::new(super::cache::BrowserStorage::new(SUBMISSION_LOG_KEY))
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        Box::new(super::cache::MemoryStorage::default())
+    }
+}
+
+// ============================================================================
+// PROOF OF WORK
+// ============================================================================
+
+/// Hashes `mint_proof` tries before letting the browser handle events
+const HASHES_PER_BATCH: u64 = 4096;
+
+/// Mints a hashcash v1 stamp for `resource` (the sender's email)
+///
+/// Format: `1:{bits}:{YYMMDD}:{resource}::{rand}:{counter}`, where the SHA-1
+/// of the whole stamp starts with at least `bits` zero bits. Each extra bit
+/// doubles the work (~2^bits hashes); verifying takes a single hash.
+pub fn mint_stamp(resource: &str, bits: u32, date: NaiveDate, rand: &str) -> String {
+    let prefix = stamp_prefix(resource, bits, date, rand);
+    find_stamp(&prefix, bits, 0..u64::MAX).unwrap_or(prefix)
+}
+
+/// Stamp for `email` dated today, with a random part
+///
+/// Hashes in batches of `HASHES_PER_BATCH` and yields to the browser between
+/// them, so the page keeps responding while the work runs.
+pub async fn mint_proof(email: &str, bits: u32) -> String {
+    let today = DateTime::from_timestamp(default_clock().now_seconds() as i64, 0)
+        .map(|now| now.date_naive())
+        .unwrap_or_default();
+    let prefix = stamp_prefix(email, bits, today, &random_token());
+
+    let mut start: u64 = 0;
+    loop {
+        let batch = start..start.saturating_add(HASHES_PER_BATCH);
+        if let Some(stamp) = find_stamp(&prefix, bits, batch) {
+            return stamp;
+        }
+        start += HASHES_PER_BATCH;
+        yield_to_browser().await;
+    }
+}
+
+/// Stamp without its counter
+fn stamp_prefix(resource: &str, bits: u32, date: NaiveDate, rand: &str) -> String {
+    format!(
+        "1:{}:{}:{}::{}:",
+        bits,
+        date.format("%y%m%d"),
+        resource,
+        rand
+    )
+}
+
+/// First stamp with a counter in `counters` that has enough zero bits
+fn find_stamp(prefix: &str, bits: u32, counters: Range<u64>) -> Option<String> {
+    counters
+        .map(|counter| format!("{}{:x}", prefix, counter))
+        .find(|stamp| leading_zero_bits(stamp) >= bits)
+}
+
+/// Lets the browser run pending events and repaint (a zero-delay timeout)
+async fn yield_to_browser() {
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::TimeoutFuture::new(0).await;
+}
+
+/// Checks a stamp from `mint_stamp` (for the receiving endpoint)
+///
+/// The stamp must be for `resource`, claim and have at least `min_bits`
+/// zero bits, and be dated within `STAMP_MAX_AGE_DAYS` of `today`.
+/// Rejecting stamps already seen is up to the endpoint.
+pub fn verify_stamp(stamp: &str, resource: &str, min_bits: u32, today: NaiveDate) -> bool {
+    let fields: Vec<&str> = stamp.split(':').collect();
+    let [version, bits, date, stamp_resource, _ext, _rand, _counter] = fields[..] else {
+        return false;
+    };
+
+    let claimed_bits: u32 = bits.parse().unwrap_or(0);
+    let fresh = NaiveDate::parse_from_str(date, "%y%m%d")
+        .map(|date| (today - date).num_days().abs() <= STAMP_MAX_AGE_DAYS)
+        .unwrap_or(false);
+
+    version == "1"
+        && stamp_resource == resource
+        && claimed_bits >= min_bits
+        && fresh
+        && leading_zero_bits(stamp) >= claimed_bits
+}
+
+/// Random part of a stamp, so two stamps for the same sender differ
+pub fn random_token() -> String {
+    #[cfg(target_arch = "wasm32")]
+    {
+        format!("{:x}", (js_sys::Math::random() * u64::MAX as f64) as u64)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        format!("{:x}", nanos)
+    }
+}
+
+fn leading_zero_bits(stamp: &str) -> u32 {
+    let digest = Sha1::digest(stamp.as_bytes());
+    let mut bits = 0;
+    for byte in digest {
+        bits += byte.leading_zeros();
+        if byte != 0 {
+            break;
+        }
+    }
+    bits
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Language;
+    use crate::utils::cache::MemoryStorage;
+    use crate::utils::clock::MockClock;
+    use std::future::Future;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Waker};
+
+    /// Fixed start time for mock clocks
+    const T0: u64 = 1_700_000_000;
+
+    fn form() -> ContactForm {
+        ContactForm {
+            name: "Ada".to_string(),
+            email: "ada@example.com".to_string(),
+            subject: "Hello".to_string(),
+            message: "A message long enough to send".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Guard for a form opened at `T0`, a clock and the shared log storage
+    fn mock_guard() -> (SpamGuard, MockClock, MemoryStorage) {
+        let clock = MockClock::new(T0);
+        let storage = MemoryStorage::default();
+        let guard = SpamGuard::new(T0)
+            .with_storage(Box::new(storage.clone()))
+            .with_clock(Arc::new(clock.clone()));
+        (guard, clock, storage)
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    // -------------------------
+    // Honeypot & Timing
+    // -------------------------
+
+    #[test]
+    fn test_honeypot() {
+        assert_eq!(check_honeypot(&form()), Ok(()));
+
+        let bot = ContactForm {
+            website: "https://spam.example".to_string(),
+            ..form()
+        };
+        assert_eq!(check_honeypot(&bot), Err(SpamError::Honeypot));
+    }
+
+    #[test]
+    fn test_fill_time() {
+        assert_eq!(check_fill_time(T0, T0), Err(SpamError::TooFast));
+        assert_eq!(
+            check_fill_time(T0, T0 + MIN_FILL_SECONDS - 1),
+            Err(SpamError::TooFast)
+        );
+        assert_eq!(check_fill_time(T0, T0 + MIN_FILL_SECONDS), Ok(()));
+        // A clock going backwards is not a reason to pass
+        assert_eq!(check_fill_time(T0, T0 - 10), Err(SpamError::TooFast));
+    }
+
+    #[test]
+    fn test_guard_runs_every_check() {
+        let (guard, clock, _) = mock_guard();
+        assert_eq!(guard.check(&form()), Err(SpamError::TooFast));
+
+        clock.advance(30);
+        assert_eq!(guard.check(&form()), Ok(()));
+
+        let bot = ContactForm {
+            website: "x".to_string(),
+            ..form()
+        };
+        assert_eq!(guard.check(&bot), Err(SpamError::Honeypot));
+    }
+
+    // -------------------------
+    // Rate Limit
+    // -------------------------
+
+    #[test]
+    fn test_rate_limit_per_window() {
+        let (guard, clock, _) = mock_guard();
+        clock.advance(30);
+
+        for _ in 0..MAX_SUBMISSIONS {
+            assert_eq!(guard.check(&form()), Ok(()));
+            guard.record_submission();
+            clock.advance(60);
+        }
+        let first = T0 + 30;
+        assert_eq!(
+            guard.check(&form()),
+            Err(SpamError::RateLimited {
+                retry_at: first + SUBMISSION_WINDOW_SECONDS
+            })
+        );
+
+        // The oldest submission leaves the window
+        clock.set(first + SUBMISSION_WINDOW_SECONDS);
+        assert_eq!(guard.check(&form()), Ok(()));
+    }
+
+    #[test]
+    fn test_rate_limit_survives_new_guard() {
+        let (guard, clock, storage) = mock_guard();
+        clock.advance(30);
+        for _ in 0..MAX_SUBMISSIONS {
+            guard.record_submission();
+        }
+
+        // Reopening the form (or reloading the page) shares the stored log
+        let reopened = SpamGuard::new(T0)
+            .with_storage(Box::new(storage.clone()))
+            .with_clock(Arc::new(clock.clone()));
+        assert!(matches!(
+            reopened.check(&form()),
+            Err(SpamError::RateLimited { .. })
+        ));
+
+        // Only submissions inside the window are kept
+        clock.advance(SUBMISSION_WINDOW_SECONDS);
+        reopened.record_submission();
+        assert_eq!(
+            storage.read(),
+            Some(format!("[{}]", T0 + 30 + SUBMISSION_WINDOW_SECONDS))
+        );
+    }
+
+    #[test]
+    fn test_corrupt_log_is_ignored() {
+        let (guard, clock, storage) = mock_guard();
+        storage.write("not json").unwrap();
+        clock.advance(30);
+
+        assert_eq!(guard.check(&form()), Ok(()));
+        guard.record_submission();
+        assert_eq!(storage.read(), Some(format!("[{}]", T0 + 30)));
+    }
+
+    // -------------------------
+    // Proof of Work
+    // -------------------------
+
+    #[test]
+    fn test_mint_and_verify_stamp() {
+        let today = date(2026, 10, 18);
+        let stamp = mint_stamp("ada@example.com", 10, today, "abc123");
+
+        assert!(stamp.starts_with("1:10:261018:ada@example.com::abc123:"));
+        assert!(leading_zero_bits(&stamp) >= 10);
+        assert!(verify_stamp(&stamp, "ada@example.com", 10, today));
+        assert!(verify_stamp(
+            &stamp,
+            "ada@example.com",
+            8,
+            date(2026, 10, 19)
+        ));
+    }
+
+    #[test]
+    fn test_verify_stamp_rejects_bad_stamps() {
+        let today = date(2026, 10, 18);
+        let stamp = mint_stamp("ada@example.com", 10, today, "abc123");
+
+        // Other sender, more work required, stale
+        assert!(!verify_stamp(&stamp, "eve@example.com", 10, today));
+        assert!(!verify_stamp(&stamp, "ada@example.com", 12, today));
+        assert!(!verify_stamp(
+            &stamp,
+            "ada@example.com",
+            10,
+            date(2026, 11, 1)
+        ));
+
+        // Claiming more bits than the hash has
+        let inflated = stamp.replacen("1:10:", "1:40:", 1);
+        assert!(!verify_stamp(&inflated, "ada@example.com", 10, today));
+
+        // Garbage
+        assert!(!verify_stamp("", "ada@example.com", 0, today));
+        assert!(!verify_stamp(
+            "2:0:261018:ada@example.com::r:0",
+            "ada@example.com",
+            0,
+            today
+        ));
+    }
+
+    #[test]
+    fn test_stamp_search_resumes_across_batches() {
+        let prefix = stamp_prefix("ada@example.com", 10, date(2026, 10, 18), "abc123");
+        let batched = (0u64..)
+            .map(|start| start * 64)
+            .find_map(|start| find_stamp(&prefix, 10, start..start + 64));
+
+        assert_eq!(
+            batched.as_deref(),
+            Some(mint_stamp("ada@example.com", 10, date(2026, 10, 18), "abc123").as_str())
+        );
+    }
+
+    #[test]
+    fn test_mint_proof_is_verifiable() {
+        // Yielding is a no-op off the browser, so one poll finishes the work
+        let mut minting = std::pin::pin!(mint_proof("ada@example.com", 8));
+        let mut cx = Context::from_waker(Waker::noop());
+        let Poll::Ready(stamp) = minting.as_mut().poll(&mut cx) else {
+            panic!("mint_proof should not wait outside the browser");
+        };
+        let today = DateTime::from_timestamp(default_clock().now_seconds() as i64, 0)
+            .unwrap()
+            .date_naive();
+        assert!(verify_stamp(&stamp, "ada@example.com", 8, today));
+    }
+
+    #[test]
+    fn test_random_tokens_differ() {
+        let a = random_token();
+        std::thread::sleep(std::time::Duration::from_millis(1));
+        assert_ne!(a, random_token());
+    }
+
+    #[test]
+    fn test_spam_messages_come_from_dictionary() {
+        let es = Language::ES.dict().errors;
+        assert_eq!(SpamError::Honeypot.message(&es), None);
+        assert_eq!(SpamError::TooFast.message(&es), Some(es.spam_too_fast));
+        assert_eq!(
+            SpamError::RateLimited { retry_at: T0 }.message(&es),
+            Some(es.spam_rate_limited)
+        );
+    }
+}
@@ -0,0 +1,296 @@
+//! Validation Module
+//! Contact form rules: required fields, address syntax and length limits
+//!
+//! Values are checked trimmed (`ContactForm::trimmed`) and lengths count
+//! characters, not bytes. Messages come from the active dictionary's `errors`
+//! section through `ValidationError::message`.
+
+use crate::data::{ContactField, ContactForm};
+use crate::i18n::Language;
+use crate::i18n::format::format_integer;
+
+/// Longest accepted value of each field, in characters
+pub const MAX_NAME_LEN: usize = 100;
+/// RFC 5321 limit for a whole address
+pub const MAX_EMAIL_LEN: usize = 254;
+pub const MAX_SUBJECT_LEN: usize = 150;
+pub const MAX_MESSAGE_LEN: usize = 5000;
+
+/// Shortest accepted message, in characters
+pub const MIN_MESSAGE_LEN: usize = 20;
+
+/// RFC 5321 limits for the parts of an address
+const MAX_LOCAL_PART_LEN: usize = 64;
+const MAX_DOMAIN_LABEL_LEN: usize = 63;
+
+/// Why a field value is rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationError {
+    Required,
+    InvalidEmail,
+    TooShort { min: usize },
+    TooLong { max: usize },
+}
+
+impl ValidationError {
+    /// Message for `field` from the dictionary of `lang`
+    pub fn message(&self, field: ContactField, lang: &Language) -> String {
+        let i18n = lang.dict().errors;
+        match self {
+            ValidationError::Required => match field {
+                ContactField::Name => i18n.name_required,
+                ContactField::Email => i18n.email_required,
+                ContactField::Subject => i18n.subject_required,
+                ContactField::Message => i18n.message_required,
+            }
+            .to_string(),
+            ValidationError::InvalidEmail => i18n.email_invalid.to_string(),
+            ValidationError::TooShort { .. } => i18n.message_too_short.to_string(),
+            ValidationError::TooLong { max } => format!(
+                "{} {} {}",
+                i18n.too_long,
+                format_integer(*max as u64, lang),
+                i18n.characters
+            ),
+        }
+    }
+}
+
+/// Longest accepted value of `field`, in characters
+pub fn max_length(field: ContactField) -> usize {
+    match field {
+        ContactField::Name => MAX_NAME_LEN,
+        ContactField::Email => MAX_EMAIL_LEN,
+        ContactField::Subject => MAX_SUBJECT_LEN,
+        ContactField::Message => MAX_MESSAGE_LEN,
+    }
+}
+
+/// Checks one field value (trimmed before checking)
+pub fn validate_field(field: ContactField, value: &str) -> Result<(), ValidationError> {
+    let value = value.trim();
+    let length = value.chars().count();
+
+    if value.is_empty() {
+        return Err(ValidationError::Required);
+    }
+    if length > max_length(field) {
+        return Err(ValidationError::TooLong {
+            max: max_length(field),
+        });
+    }
+    match field {
+        ContactField::Email if !is_valid_email(value) => Err(ValidationError::InvalidEmail),
+        ContactField::Message if length < MIN_MESSAGE_LEN => Err(ValidationError::TooShort {
+            min: MIN_MESSAGE_LEN,
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Errors of every field, in `ContactField::ALL` order
+pub fn validate_contact_form(form: &ContactForm) -> Vec<(ContactField, ValidationError)> {
+    ContactField::ALL
+        .into_iter()
+        .filter_map(|field| {
+            validate_field(field, form.value(field))
+                .err()
+                .map(|e| (field, e))
+        })
+        .collect()
+}
+
+/// Address syntax check: `local@domain.tld`
+///
+/// The local part may use letters, digits and ``!#$%&'*+/=?^_`{|}~-``, with
+/// dots between (not around or doubled). The domain needs at least two
+/// labels of letters, digits and inner hyphens, and an alphabetic TLD.
+/// Quoted local parts and IP literals are not accepted.
+pub fn is_valid_email(email: &str) -> bool {
+    if email.chars().count() > MAX_EMAIL_LEN {
+        return false;
+    }
+    let Some((local, domain)) = email.rsplit_once('@') else {
+        return false;
+    };
+
+    let local_ok = !local.is_empty()
+        && local.len() <= MAX_LOCAL_PART_LEN
+        && local.split('.').all(|atom| {
+            !atom.is_empty()
+                && atom
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+/=?^_`{|}~-".contains(c))
+        });
+
+    let labels: Vec<&str> = domain.split('.').collect();
+    let domain_ok = labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && label.len() <= MAX_DOMAIN_LABEL_LEN
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+        && labels
+            .last()
+            .is_some_and(|tld| tld.len() >= 2 && tld.chars().all(|c| c.is_ascii_alphabetic()));
+
+    local_ok && domain_ok
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_emails() {
+        for email in [
+            "ada@example.com",
+            "first.last@sub.example.co",
+            "user+tag@example.io",
+            "o'brien@example.ie",
+            "x@a-b.dev",
+        ] {
+            assert!(is_valid_email(email), "{} should be valid", email);
+        }
+    }
+
+    #[test]
+    fn test_invalid_emails() {
+        let long_local = format!("{}@example.com", "a".repeat(65));
+        let long_label = format!("ada@{}.com", "a".repeat(64));
+        for email in [
+            "",
+            "plainaddress",
+            "@example.com",
+            "ada@",
+            "ada@example",
+            "ada@@example.com",
+            "ada@exa mple.com",
+            "ada smith@example.com",
+            ".ada@example.com",
+            "ada.@example.com",
+            "a..da@example.com",
+            "ada@example..com",
+            "ada@-example.com",
+            "ada@example-.com",
+            "ada@example.c",
+            "ada@example.123",
+            "ada@example.com.",
+            "ada(comment)@example.com",
+            "josé@example.com",
+            &long_local,
+            &long_label,
+        ] {
+            assert!(!is_valid_email(email), "{} should be invalid", email);
+        }
+    }
+
+    #[test]
+    fn test_values_are_trimmed() {
+        assert_eq!(
+            validate_field(ContactField::Name, "   "),
+            Err(ValidationError::Required)
+        );
+        assert_eq!(
+            validate_field(ContactField::Email, "  ada@example.com \n"),
+            Ok(())
+        );
+        // 19 characters plus padding is still too short
+        assert_eq!(
+            validate_field(ContactField::Message, &format!("  {}  ", "x".repeat(19))),
+            Err(ValidationError::TooShort {
+                min: MIN_MESSAGE_LEN
+            })
+        );
+    }
+
+    #[test]
+    fn test_max_lengths_count_characters() {
+        for field in ContactField::ALL {
+            let max = max_length(field);
+            let too_long = "é".repeat(max + 1);
+            assert_eq!(
+                validate_field(field, &too_long),
+                Err(ValidationError::TooLong { max }),
+                "{:?}",
+                field
+            );
+        }
+
+        // Multi-byte characters count once
+        assert_eq!(
+            validate_field(ContactField::Name, &"é".repeat(MAX_NAME_LEN)),
+            Ok(())
+        );
+        assert_eq!(
+            validate_field(ContactField::Message, &"ñ".repeat(MIN_MESSAGE_LEN)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_validate_contact_form() {
+        let form = ContactForm {
+            name: "Ada".to_string(),
+            email: "ada@example".to_string(),
+            message: "short".to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            validate_contact_form(&form),
+            vec![
+                (ContactField::Email, ValidationError::InvalidEmail),
+                (ContactField::Subject, ValidationError::Required),
+                (
+                    ContactField::Message,
+                    ValidationError::TooShort {
+                        min: MIN_MESSAGE_LEN
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_messages_come_from_dictionary() {
+        let es = Language::ES.dict().errors;
+        assert_eq!(
+            ValidationError::Required.message(ContactField::Subject, &Language::ES),
+            es.subject_required
+        );
+        assert_eq!(
+            ValidationError::InvalidEmail.message(ContactField::Email, &Language::ES),
+            es.email_invalid
+        );
+        assert_eq!(
+            ValidationError::TooLong { max: 5000 }.message(ContactField::Message, &Language::EN),
+            "Too long: the limit is 5,000 characters"
+        );
+        assert_eq!(
+            ValidationError::TooLong { max: 5000 }.message(ContactField::Message, &Language::ES),
+            "Demasiado largo: el límite es 5.000 caracteres"
+        );
+    }
+
+    #[test]
+    fn test_too_short_message_matches_minimum() {
+        for lang in Language::ALL {
+            let message = ValidationError::TooShort {
+                min: MIN_MESSAGE_LEN,
+            }
+            .message(ContactField::Message, &lang);
+            assert!(
+                message.contains(&MIN_MESSAGE_LEN.to_string()),
+                "{}",
+                message
+            );
+        }
+    }
+}